@@ -0,0 +1,40 @@
+//! Support for generating arbitrary OIDs using the [`arbitrary`] crate.
+
+use crate::{
+    arcs::{FIRST_ARC_MAX, SECOND_ARC_MAX},
+    Arc, ObjectIdentifier, MIN_ARCS,
+};
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+use core::convert::TryFrom;
+
+/// Maximum number of arcs in an arbitrary OID.
+const MAX_ARBITRARY_ARCS: usize = 12;
+
+impl<'a> Arbitrary<'a> for ObjectIdentifier {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut arcs = [0 as Arc; MAX_ARBITRARY_ARCS];
+        arcs[0] = u.int_in_range(0..=FIRST_ARC_MAX)?;
+        arcs[1] = u.int_in_range(0..=SECOND_ARC_MAX)?;
+
+        let mut len = u.int_in_range(MIN_ARCS..=MAX_ARBITRARY_ARCS)?;
+
+        for arc in &mut arcs[2..len] {
+            // Pick the bit width first so small arcs are as likely as large ones
+            let max = Arc::MAX >> u.int_in_range(0..=127)?;
+            *arc = u.int_in_range(0..=max)?;
+        }
+
+        // Drop trailing arcs until the OID fits within `MAX_LEN`
+        loop {
+            if let Ok(oid) = ObjectIdentifier::try_from(&arcs[..len]) {
+                return Ok(oid);
+            }
+
+            len -= 1;
+        }
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (3, None)
+    }
+}
@@ -1,10 +1,15 @@
 //! Arcs are integer values which exist within an OID's hierarchy.
 
-use crate::{Error, ObjectIdentifier, Result};
+use crate::{Error, Result};
 use core::convert::TryFrom;
 
-/// Type used to represent an "arc" (i.e. integer identifier value)
-pub type Arc = u32;
+/// Type used to represent an "arc" (i.e. integer identifier value).
+///
+/// Arcs are 128-bit in order to support OIDs containing large integers,
+/// such as the UUID-based OIDs under `2.25` defined in [ITU X.667].
+///
+/// [ITU X.667]: https://www.itu.int/rec/T-REC-X.667
+pub type Arc = u128;
 
 /// Maximum value of the first arc in an OID
 pub(crate) const FIRST_ARC_MAX: Arc = 2;
@@ -15,18 +20,36 @@ pub(crate) const SECOND_ARC_MAX: Arc = 39;
 /// [`Iterator`] over arcs (a.k.a. nodes) in an [`ObjectIdentifier`].
 ///
 /// This iterates over all arcs in an OID, including the root.
+///
+/// [`ObjectIdentifier`]: crate::ObjectIdentifier
 pub struct Arcs<'a> {
-    /// OID we're iterating over
-    oid: &'a ObjectIdentifier,
+    /// BER/DER serialization of the OID we're iterating over
+    bytes: &'a [u8],
 
     /// Current position within the serialized DER bytes of this OID
     cursor: Option<usize>,
 }
 
 impl<'a> Arcs<'a> {
-    /// Create a new iterator over the arcs of this OID
-    pub(crate) fn new(oid: &'a ObjectIdentifier) -> Self {
-        Self { oid, cursor: None }
+    /// Create a new iterator over the arcs of the given (validated) BER/DER
+    /// serialization of an OID
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            cursor: None,
+        }
+    }
+
+    /// Create a new iterator over the arcs of the given (validated) BER/DER
+    /// serialization of an OID which begins at the given byte offset within
+    /// its non-root arcs
+    pub(crate) fn new_at(bytes: &'a [u8], offset: usize) -> Self {
+        debug_assert!(offset >= 1);
+
+        Self {
+            bytes,
+            cursor: Some(offset),
+        }
     }
 }
 
@@ -37,46 +60,78 @@ impl<'a> Iterator for Arcs<'a> {
         match self.cursor {
             // Indicates we're on the root OID
             None => {
-                let root = RootArcs(self.oid.as_bytes()[0]);
+                let root = RootArcs(self.bytes[0]);
                 self.cursor = Some(0);
                 Some(root.first_arc())
             }
             Some(0) => {
-                let root = RootArcs(self.oid.as_bytes()[0]);
+                let root = RootArcs(self.bytes[0]);
                 self.cursor = Some(1);
                 Some(root.second_arc())
             }
-            Some(offset) => {
-                let mut result = 0;
-                let mut arc_bytes = 0;
-
-                // TODO(tarcieri): consolidate this with `ObjectIdentifier::from_ber`?
-                loop {
-                    match self.oid.as_bytes().get(offset + arc_bytes).cloned() {
-                        Some(byte) => {
-                            arc_bytes += 1;
-                            assert!(
-                                arc_bytes < 4 || byte & 0b11110000 == 0,
-                                "OID arc overflowed"
-                            );
-                            result = result << 7 | (byte & 0b1111111) as Arc;
-
-                            if byte & 0b10000000 == 0 {
-                                self.cursor = Some(offset + arc_bytes);
-                                return Some(result);
-                            }
-                        }
-                        None => {
-                            assert_eq!(arc_bytes, 0, "truncated OID");
-                            return None;
-                        }
-                    }
-                }
+            Some(offset) if offset < self.bytes.len() => {
+                let (arc, arc_bytes) =
+                    decode_arc(&self.bytes[offset..], offset).expect("malformed OID");
+                self.cursor = Some(offset + arc_bytes);
+                Some(arc)
             }
+            Some(_) => None,
         }
     }
 }
 
+/// Validate the BER/DER serialization of an OID (without a header).
+pub(crate) fn validate(ber_bytes: &[u8]) -> Result<()> {
+    let (&root, mut remaining) = ber_bytes.split_first().ok_or(Error::Empty)?;
+
+    // Validate root arcs are in range
+    RootArcs::try_from(root)?;
+
+    // Minimum 3 arcs
+    if remaining.is_empty() {
+        return Err(Error::TooShort);
+    }
+
+    // Validate lower arcs are well-formed
+    let mut offset = 1;
+
+    while !remaining.is_empty() {
+        let (_, arc_bytes) = decode_arc(remaining, offset)?;
+        remaining = &remaining[arc_bytes..];
+        offset += arc_bytes;
+    }
+
+    Ok(())
+}
+
+/// Decode a base 128 arc from the start of the given bytes, returning it
+/// along with the number of bytes it occupied.
+///
+/// The `position` of the arc within the OID is used for error reporting.
+fn decode_arc(bytes: &[u8], position: usize) -> Result<(Arc, usize)> {
+    // A leading `0x80` byte means the arc isn't minimally encoded
+    if bytes.first() == Some(&0b10000000) {
+        return Err(Error::NonMinimal { position });
+    }
+
+    let mut result: Arc = 0;
+
+    for (i, &byte) in bytes.iter().enumerate() {
+        // Overflowed `Arc`
+        if result.leading_zeros() < 7 {
+            return Err(Error::ArcTooBig { position });
+        }
+
+        result = result << 7 | (byte & 0b1111111) as Arc;
+
+        if byte & 0b10000000 == 0 {
+            return Ok((result, i + 1));
+        }
+    }
+
+    Err(Error::Truncated { position })
+}
+
 /// Byte containing the first and second arcs of an OID.
 ///
 /// This is represented this way in order to reduce the overall size of the
@@ -88,8 +143,12 @@ impl RootArcs {
     /// Create [`RootArcs`] from the first and second arc values represented
     /// as `Arc` integers.
     pub(crate) fn new(first_arc: Arc, second_arc: Arc) -> Result<Self> {
-        if first_arc > FIRST_ARC_MAX || second_arc > SECOND_ARC_MAX {
-            return Err(Error);
+        if first_arc > FIRST_ARC_MAX {
+            return Err(Error::RootArc { position: 0 });
+        }
+
+        if second_arc > SECOND_ARC_MAX {
+            return Err(Error::RootArc { position: 1 });
         }
 
         let byte = (first_arc * (SECOND_ARC_MAX + 1)) as u8 + second_arc as u8;
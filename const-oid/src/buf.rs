@@ -0,0 +1,221 @@
+//! Heap-backed object identifiers.
+
+use crate::{
+    arcs::{self, RootArcs},
+    encoder::{base128_len, write_base128},
+    notation, Arc, Arcs, Error, Iri, ObjectIdentifier, Result, Urn, ValueNotation, MIN_ARCS,
+};
+use alloc::vec::Vec;
+use core::{cmp::Ordering, convert::TryFrom, fmt, str::FromStr};
+
+/// Heap-backed object identifier (OID).
+///
+/// Unlike [`ObjectIdentifier`], the BER/DER encoding of this type isn't
+/// limited to [`MAX_LEN`][`crate::MAX_LEN`] bytes, which makes it suitable
+/// for OIDs with many (or very large) arcs, e.g. long vendor-specific OIDs.
+///
+/// Like [`ObjectIdentifier`], OIDs are ordered by comparing their arcs.
+#[derive(Clone, Eq, Hash, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct ObjectIdentifierBuf {
+    /// BER/DER-serialized bytes (no header)
+    bytes: Vec<u8>,
+}
+
+#[allow(clippy::len_without_is_empty)]
+impl ObjectIdentifierBuf {
+    /// Parse an OID from from its BER/DER encoding.
+    pub fn from_ber(ber_bytes: &[u8]) -> Result<Self> {
+        arcs::validate(ber_bytes)?;
+
+        Ok(Self {
+            bytes: ber_bytes.to_vec(),
+        })
+    }
+
+    /// Get the BER/DER serialization of this OID as bytes
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Return the arc with the given index, if it exists.
+    pub fn arc(&self, index: usize) -> Option<Arc> {
+        self.arcs().nth(index)
+    }
+
+    /// Iterate over the arcs (a.k.a. nodes) in an [`ObjectIdentifierBuf`].
+    pub fn arcs(&self) -> Arcs<'_> {
+        Arcs::new(self.as_bytes())
+    }
+
+    /// Number of arcs in this [`ObjectIdentifierBuf`].
+    pub fn len(&self) -> usize {
+        self.arcs().count()
+    }
+
+    /// Parse an OID from a URN, e.g. `urn:oid:1.2.840.113549`.
+    ///
+    /// See [`ObjectIdentifier::from_urn`].
+    pub fn from_urn(s: &str) -> Result<Self> {
+        Self::from_notation(|f| notation::parse_urn(s, f))
+    }
+
+    /// Parse an OID from ASN.1 value notation, e.g.
+    /// `{ iso(1) member-body(2) us(840) 113549 }`.
+    ///
+    /// See [`ObjectIdentifier::from_value_notation`].
+    pub fn from_value_notation(s: &str) -> Result<Self> {
+        Self::from_notation(|f| notation::parse_value_notation(s, f))
+    }
+
+    /// Parse an OID from an OID-IRI, e.g. `/ISO/Member-Body/US/113549`.
+    ///
+    /// See [`ObjectIdentifier::from_iri`].
+    pub fn from_iri(s: &str) -> Result<Self> {
+        Self::from_notation(|f| notation::parse_iri(s, f))
+    }
+
+    /// Format this OID as a URN, e.g. `urn:oid:1.2.840.113549`.
+    pub fn urn(&self) -> Urn<'_> {
+        Urn {
+            bytes: self.as_bytes(),
+        }
+    }
+
+    /// Format this OID in ASN.1 value notation, e.g.
+    /// `{ iso(1) member-body(2) us(840) rsadsi(113549) }`.
+    pub fn value_notation(&self) -> ValueNotation<'_> {
+        ValueNotation {
+            bytes: self.as_bytes(),
+        }
+    }
+
+    /// Format this OID as an OID-IRI, e.g. `/ISO/Member-Body/US/113549`.
+    pub fn iri(&self) -> Iri<'_> {
+        Iri {
+            bytes: self.as_bytes(),
+        }
+    }
+
+    /// Create an OID from the arcs produced by the given parser.
+    fn from_notation<P>(parse: P) -> Result<Self>
+    where
+        P: FnOnce(&mut dyn FnMut(Arc) -> Result<()>) -> Result<()>,
+    {
+        let mut arcs = Vec::new();
+
+        parse(&mut |arc| {
+            arcs.push(arc);
+            Ok(())
+        })?;
+
+        Self::try_from(arcs.as_slice())
+    }
+
+    /// Create an OID from its root arcs followed by the remaining ones.
+    fn from_arcs(first_arc: Arc, second_arc: Arc, arcs: impl Iterator<Item = Arc>) -> Result<Self> {
+        let mut bytes = Vec::new();
+        bytes.push(RootArcs::new(first_arc, second_arc)?.into());
+
+        for arc in arcs {
+            let offset = bytes.len();
+            bytes.resize(offset + base128_len(arc) + 1, 0);
+            write_base128(&mut bytes[offset..], arc)?;
+        }
+
+        if bytes.len() > 1 {
+            Ok(Self { bytes })
+        } else {
+            // Minimum 3 arcs
+            Err(Error::TooShort)
+        }
+    }
+}
+
+impl AsRef<[u8]> for ObjectIdentifierBuf {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl FromStr for ObjectIdentifierBuf {
+    type Err = Error;
+
+    fn from_str(string: &str) -> Result<Self> {
+        Self::from_notation(|f| notation::parse_dotted(string, f))
+    }
+}
+
+impl TryFrom<&[Arc]> for ObjectIdentifierBuf {
+    type Error = Error;
+
+    fn try_from(arcs: &[Arc]) -> Result<Self> {
+        if arcs.len() < MIN_ARCS {
+            return Err(Error::TooShort);
+        }
+
+        Self::from_arcs(arcs[0], arcs[1], arcs[2..].iter().cloned())
+    }
+}
+
+impl From<ObjectIdentifier> for ObjectIdentifierBuf {
+    fn from(oid: ObjectIdentifier) -> ObjectIdentifierBuf {
+        Self {
+            bytes: oid.as_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&ObjectIdentifier> for ObjectIdentifierBuf {
+    fn from(oid: &ObjectIdentifier) -> ObjectIdentifierBuf {
+        Self::from(*oid)
+    }
+}
+
+impl TryFrom<&ObjectIdentifierBuf> for ObjectIdentifier {
+    type Error = Error;
+
+    fn try_from(oid: &ObjectIdentifierBuf) -> Result<ObjectIdentifier> {
+        ObjectIdentifier::from_ber(oid.as_bytes())
+    }
+}
+
+impl TryFrom<ObjectIdentifierBuf> for ObjectIdentifier {
+    type Error = Error;
+
+    fn try_from(oid: ObjectIdentifierBuf) -> Result<ObjectIdentifier> {
+        ObjectIdentifier::try_from(&oid)
+    }
+}
+
+impl PartialOrd for ObjectIdentifierBuf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ObjectIdentifierBuf {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.arcs().cmp(other.arcs())
+    }
+}
+
+impl fmt::Debug for ObjectIdentifierBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ObjectIdentifierBuf({})", self)
+    }
+}
+
+impl fmt::Display for ObjectIdentifierBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, arc) in self.arcs().enumerate() {
+            if i > 0 {
+                write!(f, ".")?;
+            }
+
+            write!(f, "{}", arc)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,438 @@
+//! Database of well-known OIDs and their names.
+//!
+//! OIDs are grouped into modules by the specification which defines them,
+//! with each one exposed as an [`ObjectIdentifier`] constant. Names are the
+//! ASN.1 value names used in those specifications, e.g. `id-ecPublicKey`.
+//!
+//! Constant names are derived from the ASN.1 names by separating words as
+//! well as letters and digits with underscores, e.g. `ECDSA_WITH_SHA_256`
+//! for `ecdsa-with-SHA256` and `SECP_256_R_1` for `secp256r1`.
+//!
+//! The database is made up of `const` tables which don't require a heap, and
+//! can be searched in either direction by binary search using [`by_name`]
+//! and [`name_of`]:
+//!
+//! ```
+//! use const_oid::{db, ObjectIdentifier};
+//!
+//! let oid = ObjectIdentifier::parse("1.2.840.113549.1.1.11");
+//! assert_eq!(db::name_of(&oid), Some("sha256WithRSAEncryption"));
+//! assert_eq!(db::by_name("sha256WithRSAEncryption"), Some(&oid));
+//! assert_eq!(oid, db::rfc8017::SHA_256_WITH_RSA_ENCRYPTION);
+//! ```
+
+use crate::{ObjectIdentifier, OidMap};
+use core::cmp::Ordering;
+
+/// Look up an OID by its name, e.g. `id-ecPublicKey`.
+pub fn by_name(name: &str) -> Option<&'static ObjectIdentifier> {
+    BY_NAME
+        .binary_search_by(|(entry_name, _)| entry_name.cmp(&name))
+        .ok()
+        .map(|i| BY_NAME[i].1)
+}
+
+/// Look up the name of an OID, e.g. `id-ecPublicKey`.
+pub fn name_of(oid: &ObjectIdentifier) -> Option<&'static str> {
+    BY_OID.get(oid).copied()
+}
+
+/// Define the OIDs in the database, generating a module of constants for
+/// each specification along with the [`ENTRIES`] table and the lookup tables
+/// used by [`by_name`] and [`name_of`].
+macro_rules! oid_db {
+    ($(
+        $(#[$meta:meta])*
+        $module:ident {
+            $($constant:ident = $name:literal, $oid:literal;)+
+        }
+    )+) => {
+        $(
+            $(#[$meta])*
+            pub mod $module {
+                use crate::ObjectIdentifier;
+
+                $(
+                    #[doc = $name]
+                    pub const $constant: ObjectIdentifier = ObjectIdentifier::parse($oid);
+                )+
+            }
+        )+
+
+        /// All OIDs in the database along with their names.
+        pub const ENTRIES: &[(&ObjectIdentifier, &str)] = &[
+            $($((&$module::$constant, $name),)+)+
+        ];
+
+        crate::oid_map! {
+            /// Names of the OIDs in the database, sorted by OID.
+            const BY_OID: OidMap<&'static str> = {
+                $($($module::$constant => $name),+),+
+            };
+        }
+
+        /// OIDs in the database, sorted by name.
+        const BY_NAME: &[(&str, &ObjectIdentifier)] = &{
+            let mut entries = [$($(($name, &$module::$constant)),+),+];
+            let mut i = 1;
+
+            // Insertion sort, as `const` contexts don't support `&mut`
+            while i < entries.len() {
+                let mut j = i;
+
+                while j > 0 && (cmp_name(entries[j - 1].0, entries[j].0) as i8) > 0 {
+                    let tmp = entries[j];
+                    entries[j] = entries[j - 1];
+                    entries[j - 1] = tmp;
+                    j -= 1;
+                }
+
+                i += 1;
+            }
+
+            entries
+        };
+    };
+}
+
+/// Compare names in a `const` context, consistent with the [`Ord`] impl on
+/// [`str`].
+const fn cmp_name(a: &str, b: &str) -> Ordering {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut i = 0;
+
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return if a[i] < b[i] {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+
+        i += 1;
+    }
+
+    if a.len() < b.len() {
+        Ordering::Less
+    } else if a.len() > b.len() {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+oid_db! {
+    /// [RFC 5280]: Internet X.509 Public Key Infrastructure Certificate and
+    /// CRL Profile.
+    ///
+    /// [RFC 5280]: https://tools.ietf.org/html/rfc5280
+    rfc5280 {
+        ID_PKIX = "id-pkix", "1.3.6.1.5.5.7";
+        ID_PE = "id-pe", "1.3.6.1.5.5.7.1";
+        ID_QT = "id-qt", "1.3.6.1.5.5.7.2";
+        ID_KP = "id-kp", "1.3.6.1.5.5.7.3";
+        ID_AD = "id-ad", "1.3.6.1.5.5.7.48";
+        ID_QT_CPS = "id-qt-cps", "1.3.6.1.5.5.7.2.1";
+        ID_QT_UNOTICE = "id-qt-unotice", "1.3.6.1.5.5.7.2.2";
+        ID_AD_OCSP = "id-ad-ocsp", "1.3.6.1.5.5.7.48.1";
+        ID_AD_CA_ISSUERS = "id-ad-caIssuers", "1.3.6.1.5.5.7.48.2";
+        ID_AD_TIME_STAMPING = "id-ad-timeStamping", "1.3.6.1.5.5.7.48.3";
+        ID_AD_CA_REPOSITORY = "id-ad-caRepository", "1.3.6.1.5.5.7.48.5";
+        ID_PE_AUTHORITY_INFO_ACCESS = "id-pe-authorityInfoAccess", "1.3.6.1.5.5.7.1.1";
+        ID_PE_SUBJECT_INFO_ACCESS = "id-pe-subjectInfoAccess", "1.3.6.1.5.5.7.1.11";
+        ID_AT = "id-at", "2.5.4";
+        ID_AT_NAME = "id-at-name", "2.5.4.41";
+        ID_AT_SURNAME = "id-at-surname", "2.5.4.4";
+        ID_AT_GIVEN_NAME = "id-at-givenName", "2.5.4.42";
+        ID_AT_INITIALS = "id-at-initials", "2.5.4.43";
+        ID_AT_GENERATION_QUALIFIER = "id-at-generationQualifier", "2.5.4.44";
+        ID_AT_COMMON_NAME = "id-at-commonName", "2.5.4.3";
+        ID_AT_LOCALITY_NAME = "id-at-localityName", "2.5.4.7";
+        ID_AT_STATE_OR_PROVINCE_NAME = "id-at-stateOrProvinceName", "2.5.4.8";
+        ID_AT_ORGANIZATION_NAME = "id-at-organizationName", "2.5.4.10";
+        ID_AT_ORGANIZATIONAL_UNIT_NAME = "id-at-organizationalUnitName", "2.5.4.11";
+        ID_AT_TITLE = "id-at-title", "2.5.4.12";
+        ID_AT_DN_QUALIFIER = "id-at-dnQualifier", "2.5.4.46";
+        ID_AT_COUNTRY_NAME = "id-at-countryName", "2.5.4.6";
+        ID_AT_SERIAL_NUMBER = "id-at-serialNumber", "2.5.4.5";
+        ID_AT_PSEUDONYM = "id-at-pseudonym", "2.5.4.65";
+        ID_DOMAIN_COMPONENT = "id-domainComponent", "0.9.2342.19200300.100.1.25";
+        ID_EMAIL_ADDRESS = "id-emailAddress", "1.2.840.113549.1.9.1";
+        ID_CE = "id-ce", "2.5.29";
+        ID_CE_SUBJECT_DIRECTORY_ATTRIBUTES = "id-ce-subjectDirectoryAttributes", "2.5.29.9";
+        ID_CE_SUBJECT_KEY_IDENTIFIER = "id-ce-subjectKeyIdentifier", "2.5.29.14";
+        ID_CE_KEY_USAGE = "id-ce-keyUsage", "2.5.29.15";
+        ID_CE_PRIVATE_KEY_USAGE_PERIOD = "id-ce-privateKeyUsagePeriod", "2.5.29.16";
+        ID_CE_SUBJECT_ALT_NAME = "id-ce-subjectAltName", "2.5.29.17";
+        ID_CE_ISSUER_ALT_NAME = "id-ce-issuerAltName", "2.5.29.18";
+        ID_CE_BASIC_CONSTRAINTS = "id-ce-basicConstraints", "2.5.29.19";
+        ID_CE_CRL_NUMBER = "id-ce-cRLNumber", "2.5.29.20";
+        ID_CE_CRL_REASONS = "id-ce-cRLReasons", "2.5.29.21";
+        ID_CE_HOLD_INSTRUCTION_CODE = "id-ce-holdInstructionCode", "2.5.29.23";
+        ID_CE_INVALIDITY_DATE = "id-ce-invalidityDate", "2.5.29.24";
+        ID_CE_DELTA_CRL_INDICATOR = "id-ce-deltaCRLIndicator", "2.5.29.27";
+        ID_CE_ISSUING_DISTRIBUTION_POINT = "id-ce-issuingDistributionPoint", "2.5.29.28";
+        ID_CE_CERTIFICATE_ISSUER = "id-ce-certificateIssuer", "2.5.29.29";
+        ID_CE_NAME_CONSTRAINTS = "id-ce-nameConstraints", "2.5.29.30";
+        ID_CE_CRL_DISTRIBUTION_POINTS = "id-ce-cRLDistributionPoints", "2.5.29.31";
+        ID_CE_CERTIFICATE_POLICIES = "id-ce-certificatePolicies", "2.5.29.32";
+        ANY_POLICY = "anyPolicy", "2.5.29.32.0";
+        ID_CE_POLICY_MAPPINGS = "id-ce-policyMappings", "2.5.29.33";
+        ID_CE_AUTHORITY_KEY_IDENTIFIER = "id-ce-authorityKeyIdentifier", "2.5.29.35";
+        ID_CE_POLICY_CONSTRAINTS = "id-ce-policyConstraints", "2.5.29.36";
+        ID_CE_EXT_KEY_USAGE = "id-ce-extKeyUsage", "2.5.29.37";
+        ANY_EXTENDED_KEY_USAGE = "anyExtendedKeyUsage", "2.5.29.37.0";
+        ID_CE_FRESHEST_CRL = "id-ce-freshestCRL", "2.5.29.46";
+        ID_CE_INHIBIT_ANY_POLICY = "id-ce-inhibitAnyPolicy", "2.5.29.54";
+        ID_KP_SERVER_AUTH = "id-kp-serverAuth", "1.3.6.1.5.5.7.3.1";
+        ID_KP_CLIENT_AUTH = "id-kp-clientAuth", "1.3.6.1.5.5.7.3.2";
+        ID_KP_CODE_SIGNING = "id-kp-codeSigning", "1.3.6.1.5.5.7.3.3";
+        ID_KP_EMAIL_PROTECTION = "id-kp-emailProtection", "1.3.6.1.5.5.7.3.4";
+        ID_KP_TIME_STAMPING = "id-kp-timeStamping", "1.3.6.1.5.5.7.3.8";
+        ID_KP_OCSP_SIGNING = "id-kp-OCSPSigning", "1.3.6.1.5.5.7.3.9";
+        HOLD_INSTRUCTION = "holdInstruction", "1.2.840.10040.2";
+        ID_HOLDINSTRUCTION_NONE = "id-holdinstruction-none", "1.2.840.10040.2.1";
+        ID_HOLDINSTRUCTION_CALLISSUER = "id-holdinstruction-callissuer", "1.2.840.10040.2.2";
+        ID_HOLDINSTRUCTION_REJECT = "id-holdinstruction-reject", "1.2.840.10040.2.3";
+    }
+
+    /// [RFC 5912]: New ASN.1 Modules for the Public Key Infrastructure Using
+    /// X.509 (PKIX).
+    ///
+    /// Includes the algorithm identifiers used by PKIX which aren't defined
+    /// by PKCS or NIST (see the other modules for those).
+    ///
+    /// [RFC 5912]: https://tools.ietf.org/html/rfc5912
+    rfc5912 {
+        ID_DSA = "id-dsa", "1.2.840.10040.4.1";
+        ID_DSA_WITH_SHA_1 = "id-dsa-with-sha1", "1.2.840.10040.4.3";
+        DHPUBLICNUMBER = "dhpublicnumber", "1.2.840.10046.2.1";
+        ID_EC_PUBLIC_KEY = "id-ecPublicKey", "1.2.840.10045.2.1";
+        ID_EC_DH = "id-ecDH", "1.3.132.1.12";
+        ID_EC_MQV = "id-ecMQV", "1.3.132.1.13";
+        ECDSA_WITH_SHA_1 = "ecdsa-with-SHA1", "1.2.840.10045.4.1";
+        ECDSA_WITH_SHA_224 = "ecdsa-with-SHA224", "1.2.840.10045.4.3.1";
+        ECDSA_WITH_SHA_256 = "ecdsa-with-SHA256", "1.2.840.10045.4.3.2";
+        ECDSA_WITH_SHA_384 = "ecdsa-with-SHA384", "1.2.840.10045.4.3.3";
+        ECDSA_WITH_SHA_512 = "ecdsa-with-SHA512", "1.2.840.10045.4.3.4";
+        SECP_192_R_1 = "secp192r1", "1.2.840.10045.3.1.1";
+        SECP_224_R_1 = "secp224r1", "1.3.132.0.33";
+        SECP_256_R_1 = "secp256r1", "1.2.840.10045.3.1.7";
+        SECP_384_R_1 = "secp384r1", "1.3.132.0.34";
+        SECP_521_R_1 = "secp521r1", "1.3.132.0.35";
+        ID_MD_2 = "id-md2", "1.2.840.113549.2.2";
+        ID_MD_5 = "id-md5", "1.2.840.113549.2.5";
+        ID_SHA_1 = "id-sha1", "1.3.14.3.2.26";
+    }
+
+    /// [RFC 8410]: Algorithm Identifiers for Ed25519, Ed448, X25519, and X448
+    /// for Use in the Internet X.509 Public Key Infrastructure.
+    ///
+    /// [RFC 8410]: https://tools.ietf.org/html/rfc8410
+    rfc8410 {
+        ID_X_25519 = "id-X25519", "1.3.101.110";
+        ID_X_448 = "id-X448", "1.3.101.111";
+        ID_ED_25519 = "id-Ed25519", "1.3.101.112";
+        ID_ED_448 = "id-Ed448", "1.3.101.113";
+    }
+
+    /// [RFC 8017]: PKCS #1: RSA Cryptography Specifications Version 2.2.
+    ///
+    /// [RFC 8017]: https://tools.ietf.org/html/rfc8017
+    rfc8017 {
+        PKCS_1 = "pkcs-1", "1.2.840.113549.1.1";
+        RSA_ENCRYPTION = "rsaEncryption", "1.2.840.113549.1.1.1";
+        MD_2_WITH_RSA_ENCRYPTION = "md2WithRSAEncryption", "1.2.840.113549.1.1.2";
+        MD_5_WITH_RSA_ENCRYPTION = "md5WithRSAEncryption", "1.2.840.113549.1.1.4";
+        SHA_1_WITH_RSA_ENCRYPTION = "sha1WithRSAEncryption", "1.2.840.113549.1.1.5";
+        ID_RSAES_OAEP = "id-RSAES-OAEP", "1.2.840.113549.1.1.7";
+        ID_MGF_1 = "id-mgf1", "1.2.840.113549.1.1.8";
+        ID_P_SPECIFIED = "id-pSpecified", "1.2.840.113549.1.1.9";
+        ID_RSASSA_PSS = "id-RSASSA-PSS", "1.2.840.113549.1.1.10";
+        SHA_256_WITH_RSA_ENCRYPTION = "sha256WithRSAEncryption", "1.2.840.113549.1.1.11";
+        SHA_384_WITH_RSA_ENCRYPTION = "sha384WithRSAEncryption", "1.2.840.113549.1.1.12";
+        SHA_512_WITH_RSA_ENCRYPTION = "sha512WithRSAEncryption", "1.2.840.113549.1.1.13";
+        SHA_224_WITH_RSA_ENCRYPTION = "sha224WithRSAEncryption", "1.2.840.113549.1.1.14";
+        SHA_512_224_WITH_RSA_ENCRYPTION = "sha512-224WithRSAEncryption", "1.2.840.113549.1.1.15";
+        SHA_512_256_WITH_RSA_ENCRYPTION = "sha512-256WithRSAEncryption", "1.2.840.113549.1.1.16";
+    }
+
+    /// [RFC 8018]: PKCS #5: Password-Based Cryptography Specification
+    /// Version 2.1.
+    ///
+    /// [RFC 8018]: https://tools.ietf.org/html/rfc8018
+    rfc8018 {
+        PKCS_5 = "pkcs-5", "1.2.840.113549.1.5";
+        PBE_WITH_MD_2_AND_DES_CBC = "pbeWithMD2AndDES-CBC", "1.2.840.113549.1.5.1";
+        PBE_WITH_MD_2_AND_RC_2_CBC = "pbeWithMD2AndRC2-CBC", "1.2.840.113549.1.5.4";
+        PBE_WITH_MD_5_AND_DES_CBC = "pbeWithMD5AndDES-CBC", "1.2.840.113549.1.5.3";
+        PBE_WITH_MD_5_AND_RC_2_CBC = "pbeWithMD5AndRC2-CBC", "1.2.840.113549.1.5.6";
+        PBE_WITH_SHA_1_AND_DES_CBC = "pbeWithSHA1AndDES-CBC", "1.2.840.113549.1.5.10";
+        PBE_WITH_SHA_1_AND_RC_2_CBC = "pbeWithSHA1AndRC2-CBC", "1.2.840.113549.1.5.11";
+        ID_PBKDF_2 = "id-PBKDF2", "1.2.840.113549.1.5.12";
+        ID_PBES_2 = "id-PBES2", "1.2.840.113549.1.5.13";
+        ID_PBMAC_1 = "id-PBMAC1", "1.2.840.113549.1.5.14";
+        DIGEST_ALGORITHM = "digestAlgorithm", "1.2.840.113549.2";
+        ID_HMAC_WITH_SHA_1 = "id-hmacWithSHA1", "1.2.840.113549.2.7";
+        ID_HMAC_WITH_SHA_224 = "id-hmacWithSHA224", "1.2.840.113549.2.8";
+        ID_HMAC_WITH_SHA_256 = "id-hmacWithSHA256", "1.2.840.113549.2.9";
+        ID_HMAC_WITH_SHA_384 = "id-hmacWithSHA384", "1.2.840.113549.2.10";
+        ID_HMAC_WITH_SHA_512 = "id-hmacWithSHA512", "1.2.840.113549.2.11";
+        ID_HMAC_WITH_SHA_512_224 = "id-hmacWithSHA512-224", "1.2.840.113549.2.12";
+        ID_HMAC_WITH_SHA_512_256 = "id-hmacWithSHA512-256", "1.2.840.113549.2.13";
+        ENCRYPTION_ALGORITHM = "encryptionAlgorithm", "1.2.840.113549.3";
+        RC_2_CBC = "rc2CBC", "1.2.840.113549.3.2";
+        DES_EDE_3_CBC = "des-EDE3-CBC", "1.2.840.113549.3.7";
+        RC_5_CBC_PAD = "rc5-CBC-PAD", "1.2.840.113549.3.9";
+        DES_CBC = "desCBC", "1.3.14.3.2.7";
+    }
+
+    /// [RFC 5958]: Asymmetric Key Packages (PKCS #8).
+    ///
+    /// [RFC 5958]: https://tools.ietf.org/html/rfc5958
+    rfc5958 {
+        ID_CT_KP_A_KEY_PACKAGE = "id-ct-KP-aKeyPackage", "2.16.840.1.101.2.1.2.78.5";
+    }
+
+    /// [RFC 2985]: PKCS #9: Selected Object Classes and Attribute Types
+    /// Version 2.0.
+    ///
+    /// [RFC 2985]: https://tools.ietf.org/html/rfc2985
+    rfc2985 {
+        PKCS_9 = "pkcs-9", "1.2.840.113549.1.9";
+        PKCS_9_AT_UNSTRUCTURED_NAME = "pkcs-9-at-unstructuredName", "1.2.840.113549.1.9.2";
+        PKCS_9_AT_CONTENT_TYPE = "pkcs-9-at-contentType", "1.2.840.113549.1.9.3";
+        PKCS_9_AT_MESSAGE_DIGEST = "pkcs-9-at-messageDigest", "1.2.840.113549.1.9.4";
+        PKCS_9_AT_SIGNING_TIME = "pkcs-9-at-signingTime", "1.2.840.113549.1.9.5";
+        PKCS_9_AT_COUNTER_SIGNATURE = "pkcs-9-at-counterSignature", "1.2.840.113549.1.9.6";
+        PKCS_9_AT_CHALLENGE_PASSWORD = "pkcs-9-at-challengePassword", "1.2.840.113549.1.9.7";
+        PKCS_9_AT_UNSTRUCTURED_ADDRESS = "pkcs-9-at-unstructuredAddress", "1.2.840.113549.1.9.8";
+        PKCS_9_AT_EXTENDED_CERTIFICATE_ATTRIBUTES = "pkcs-9-at-extendedCertificateAttributes", "1.2.840.113549.1.9.9";
+        PKCS_9_AT_SIGNING_DESCRIPTION = "pkcs-9-at-signingDescription", "1.2.840.113549.1.9.13";
+        PKCS_9_AT_EXTENSION_REQUEST = "pkcs-9-at-extensionRequest", "1.2.840.113549.1.9.14";
+        PKCS_9_AT_SMIME_CAPABILITIES = "pkcs-9-at-smimeCapabilities", "1.2.840.113549.1.9.15";
+        PKCS_9_AT_FRIENDLY_NAME = "pkcs-9-at-friendlyName", "1.2.840.113549.1.9.20";
+        PKCS_9_AT_LOCAL_KEY_ID = "pkcs-9-at-localKeyId", "1.2.840.113549.1.9.21";
+        CERT_TYPES = "certTypes", "1.2.840.113549.1.9.22";
+        X_509_CERTIFICATE = "x509Certificate", "1.2.840.113549.1.9.22.1";
+        SDSI_CERTIFICATE = "sdsiCertificate", "1.2.840.113549.1.9.22.2";
+        CRL_TYPES = "crlTypes", "1.2.840.113549.1.9.23";
+        X_509_CRL = "x509Crl", "1.2.840.113549.1.9.23.1";
+        PKCS_9_OC_PKCS_ENTITY = "pkcs-9-oc-pkcsEntity", "1.2.840.113549.1.9.24.1";
+        PKCS_9_OC_NATURAL_PERSON = "pkcs-9-oc-naturalPerson", "1.2.840.113549.1.9.24.2";
+    }
+
+    /// [RFC 7292]: PKCS #12: Personal Information Exchange Syntax v1.1.
+    ///
+    /// [RFC 7292]: https://tools.ietf.org/html/rfc7292
+    rfc7292 {
+        PKCS_12 = "pkcs-12", "1.2.840.113549.1.12";
+        PKCS_12_PBE_IDS = "pkcs-12PbeIds", "1.2.840.113549.1.12.1";
+        PBE_WITH_SHA_AND_128_BIT_RC_4 = "pbeWithSHAAnd128BitRC4", "1.2.840.113549.1.12.1.1";
+        PBE_WITH_SHA_AND_40_BIT_RC_4 = "pbeWithSHAAnd40BitRC4", "1.2.840.113549.1.12.1.2";
+        PBE_WITH_SHA_AND_3_KEY_TRIPLE_DES_CBC = "pbeWithSHAAnd3-KeyTripleDES-CBC", "1.2.840.113549.1.12.1.3";
+        PBE_WITH_SHA_AND_2_KEY_TRIPLE_DES_CBC = "pbeWithSHAAnd2-KeyTripleDES-CBC", "1.2.840.113549.1.12.1.4";
+        PBE_WITH_SHA_AND_128_BIT_RC_2_CBC = "pbeWithSHAAnd128BitRC2-CBC", "1.2.840.113549.1.12.1.5";
+        PBE_WITH_SHA_AND_40_BIT_RC_2_CBC = "pbewithSHAAnd40BitRC2-CBC", "1.2.840.113549.1.12.1.6";
+        BAG_TYPES = "bagtypes", "1.2.840.113549.1.12.10.1";
+        KEY_BAG = "keyBag", "1.2.840.113549.1.12.10.1.1";
+        PKCS_8_SHROUDED_KEY_BAG = "pkcs8ShroudedKeyBag", "1.2.840.113549.1.12.10.1.2";
+        CERT_BAG = "certBag", "1.2.840.113549.1.12.10.1.3";
+        CRL_BAG = "crlBag", "1.2.840.113549.1.12.10.1.4";
+        SECRET_BAG = "secretBag", "1.2.840.113549.1.12.10.1.5";
+        SAFE_CONTENTS_BAG = "safeContentsBag", "1.2.840.113549.1.12.10.1.6";
+    }
+
+    /// NIST algorithm OIDs registered in the [Computer Security Objects
+    /// Register] (CSOR).
+    ///
+    /// [Computer Security Objects Register]: https://csrc.nist.gov/projects/computer-security-objects-register/algorithm-registration
+    nist {
+        ID_AES_128_ECB = "id-aes128-ECB", "2.16.840.1.101.3.4.1.1";
+        ID_AES_128_CBC = "id-aes128-CBC", "2.16.840.1.101.3.4.1.2";
+        ID_AES_128_OFB = "id-aes128-OFB", "2.16.840.1.101.3.4.1.3";
+        ID_AES_128_CFB = "id-aes128-CFB", "2.16.840.1.101.3.4.1.4";
+        ID_AES_128_WRAP = "id-aes128-wrap", "2.16.840.1.101.3.4.1.5";
+        ID_AES_128_GCM = "id-aes128-GCM", "2.16.840.1.101.3.4.1.6";
+        ID_AES_128_CCM = "id-aes128-CCM", "2.16.840.1.101.3.4.1.7";
+        ID_AES_128_WRAP_PAD = "id-aes128-wrap-pad", "2.16.840.1.101.3.4.1.8";
+        ID_AES_192_ECB = "id-aes192-ECB", "2.16.840.1.101.3.4.1.21";
+        ID_AES_192_CBC = "id-aes192-CBC", "2.16.840.1.101.3.4.1.22";
+        ID_AES_192_OFB = "id-aes192-OFB", "2.16.840.1.101.3.4.1.23";
+        ID_AES_192_CFB = "id-aes192-CFB", "2.16.840.1.101.3.4.1.24";
+        ID_AES_192_WRAP = "id-aes192-wrap", "2.16.840.1.101.3.4.1.25";
+        ID_AES_192_GCM = "id-aes192-GCM", "2.16.840.1.101.3.4.1.26";
+        ID_AES_192_CCM = "id-aes192-CCM", "2.16.840.1.101.3.4.1.27";
+        ID_AES_192_WRAP_PAD = "id-aes192-wrap-pad", "2.16.840.1.101.3.4.1.28";
+        ID_AES_256_ECB = "id-aes256-ECB", "2.16.840.1.101.3.4.1.41";
+        ID_AES_256_CBC = "id-aes256-CBC", "2.16.840.1.101.3.4.1.42";
+        ID_AES_256_OFB = "id-aes256-OFB", "2.16.840.1.101.3.4.1.43";
+        ID_AES_256_CFB = "id-aes256-CFB", "2.16.840.1.101.3.4.1.44";
+        ID_AES_256_WRAP = "id-aes256-wrap", "2.16.840.1.101.3.4.1.45";
+        ID_AES_256_GCM = "id-aes256-GCM", "2.16.840.1.101.3.4.1.46";
+        ID_AES_256_CCM = "id-aes256-CCM", "2.16.840.1.101.3.4.1.47";
+        ID_AES_256_WRAP_PAD = "id-aes256-wrap-pad", "2.16.840.1.101.3.4.1.48";
+        ID_SHA_256 = "id-sha256", "2.16.840.1.101.3.4.2.1";
+        ID_SHA_384 = "id-sha384", "2.16.840.1.101.3.4.2.2";
+        ID_SHA_512 = "id-sha512", "2.16.840.1.101.3.4.2.3";
+        ID_SHA_224 = "id-sha224", "2.16.840.1.101.3.4.2.4";
+        ID_SHA_512_224 = "id-sha512-224", "2.16.840.1.101.3.4.2.5";
+        ID_SHA_512_256 = "id-sha512-256", "2.16.840.1.101.3.4.2.6";
+        ID_SHA_3_224 = "id-sha3-224", "2.16.840.1.101.3.4.2.7";
+        ID_SHA_3_256 = "id-sha3-256", "2.16.840.1.101.3.4.2.8";
+        ID_SHA_3_384 = "id-sha3-384", "2.16.840.1.101.3.4.2.9";
+        ID_SHA_3_512 = "id-sha3-512", "2.16.840.1.101.3.4.2.10";
+        ID_SHAKE_128 = "id-shake128", "2.16.840.1.101.3.4.2.11";
+        ID_SHAKE_256 = "id-shake256", "2.16.840.1.101.3.4.2.12";
+        ID_HMAC_WITH_SHA_3_224 = "id-hmacWithSHA3-224", "2.16.840.1.101.3.4.2.13";
+        ID_HMAC_WITH_SHA_3_256 = "id-hmacWithSHA3-256", "2.16.840.1.101.3.4.2.14";
+        ID_HMAC_WITH_SHA_3_384 = "id-hmacWithSHA3-384", "2.16.840.1.101.3.4.2.15";
+        ID_HMAC_WITH_SHA_3_512 = "id-hmacWithSHA3-512", "2.16.840.1.101.3.4.2.16";
+        ID_DSA_WITH_SHA_224 = "id-dsa-with-sha224", "2.16.840.1.101.3.4.3.1";
+        ID_DSA_WITH_SHA_256 = "id-dsa-with-sha256", "2.16.840.1.101.3.4.3.2";
+        ID_DSA_WITH_SHA_384 = "id-dsa-with-sha384", "2.16.840.1.101.3.4.3.3";
+        ID_DSA_WITH_SHA_512 = "id-dsa-with-sha512", "2.16.840.1.101.3.4.3.4";
+        ID_DSA_WITH_SHA_3_224 = "id-dsa-with-sha3-224", "2.16.840.1.101.3.4.3.5";
+        ID_DSA_WITH_SHA_3_256 = "id-dsa-with-sha3-256", "2.16.840.1.101.3.4.3.6";
+        ID_DSA_WITH_SHA_3_384 = "id-dsa-with-sha3-384", "2.16.840.1.101.3.4.3.7";
+        ID_DSA_WITH_SHA_3_512 = "id-dsa-with-sha3-512", "2.16.840.1.101.3.4.3.8";
+        ID_ECDSA_WITH_SHA_3_224 = "id-ecdsa-with-sha3-224", "2.16.840.1.101.3.4.3.9";
+        ID_ECDSA_WITH_SHA_3_256 = "id-ecdsa-with-sha3-256", "2.16.840.1.101.3.4.3.10";
+        ID_ECDSA_WITH_SHA_3_384 = "id-ecdsa-with-sha3-384", "2.16.840.1.101.3.4.3.11";
+        ID_ECDSA_WITH_SHA_3_512 = "id-ecdsa-with-sha3-512", "2.16.840.1.101.3.4.3.12";
+        ID_RSASSA_PKCS_1_V_1_5_WITH_SHA_3_224 = "id-rsassa-pkcs1-v1_5-with-sha3-224", "2.16.840.1.101.3.4.3.13";
+        ID_RSASSA_PKCS_1_V_1_5_WITH_SHA_3_256 = "id-rsassa-pkcs1-v1_5-with-sha3-256", "2.16.840.1.101.3.4.3.14";
+        ID_RSASSA_PKCS_1_V_1_5_WITH_SHA_3_384 = "id-rsassa-pkcs1-v1_5-with-sha3-384", "2.16.840.1.101.3.4.3.15";
+        ID_RSASSA_PKCS_1_V_1_5_WITH_SHA_3_512 = "id-rsassa-pkcs1-v1_5-with-sha3-512", "2.16.840.1.101.3.4.3.16";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{by_name, name_of, rfc8018, ENTRIES};
+
+    #[test]
+    fn entries_are_unique() {
+        for (i, (oid, name)) in ENTRIES.iter().enumerate() {
+            for (other_oid, other_name) in &ENTRIES[..i] {
+                assert_ne!(oid, other_oid, "duplicate OID: {}", oid);
+                assert_ne!(name, other_name, "duplicate name: {}", name);
+            }
+        }
+    }
+
+    #[test]
+    fn lookups_match_entries() {
+        for (oid, name) in ENTRIES {
+            assert_eq!(by_name(name), Some(*oid), "{}", name);
+            assert_eq!(name_of(oid), Some(*name), "{}", oid);
+        }
+
+        assert_eq!(
+            by_name("pbeWithSHA1AndRC2-CBC"),
+            Some(&rfc8018::PBE_WITH_SHA_1_AND_RC_2_CBC)
+        );
+        assert_eq!(by_name("pbeWithSHA1AndRC2"), None);
+    }
+}
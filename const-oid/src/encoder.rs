@@ -39,125 +39,166 @@ impl Encoder {
         }
     }
 
-    /// Encode an [`Arc`] as base 128 into the internal buffer
-    pub(crate) const fn encode(mut self, arc: Arc) -> Self {
+    /// Create a new encoder which appends arcs to the given OID
+    pub(crate) const fn extend(oid: ObjectIdentifier) -> Self {
+        Self {
+            state: State::Body,
+            bytes: oid.bytes,
+            cursor: oid.length as usize,
+        }
+    }
+
+    /// Encode an [`Arc`] as base 128 into the internal buffer.
+    ///
+    /// The `position` of the arc within the input is used for error reporting.
+    pub(crate) const fn encode(mut self, arc: Arc, position: usize) -> Result<Self> {
         match self.state {
             State::Initial => {
-                const_assert!(arc <= FIRST_ARC_MAX, "invalid first arc (must be 0-2)");
+                if arc > FIRST_ARC_MAX {
+                    return Err(Error::RootArc { position });
+                }
+
                 self.state = State::FirstArc(arc);
-                self
+                Ok(self)
             }
             State::FirstArc(first_arc) => {
-                const_assert!(arc <= SECOND_ARC_MAX, "invalid second arc (must be 0-39)");
+                if arc > SECOND_ARC_MAX {
+                    return Err(Error::RootArc { position });
+                }
+
                 self.state = State::Body;
                 self.bytes[0] = (first_arc * (SECOND_ARC_MAX + 1)) as u8 + arc as u8;
                 self.cursor = 1;
-                self
+                Ok(self)
             }
             State::Body => {
                 // Total number of bytes in encoded arc - 1
                 let nbytes = base128_len(arc);
 
-                const_assert!(
-                    self.cursor + nbytes + 1 < MAX_LEN,
-                    "OID too long (exceeded max DER bytes)"
-                );
                 let new_cursor = self.cursor + nbytes + 1;
 
-                let mut result = self.encode_base128_byte(arc, nbytes, false);
+                if new_cursor > MAX_LEN {
+                    return Err(Error::TooLong);
+                }
+
+                let mut result = self.encode_base128(arc, nbytes);
                 result.cursor = new_cursor;
-                result
+                Ok(result)
             }
         }
     }
 
     /// Finish encoding an OID
-    pub(crate) const fn finish(self) -> ObjectIdentifier {
-        const_assert!(self.cursor >= 2, "OID too short (minimum 3 arcs)");
-        ObjectIdentifier {
+    pub(crate) const fn finish(self) -> Result<ObjectIdentifier> {
+        if self.cursor < 2 {
+            return Err(Error::TooShort);
+        }
+
+        Ok(ObjectIdentifier {
             bytes: self.bytes,
             length: self.cursor as u8,
-        }
+        })
     }
 
-    /// Encode a single byte of a base128 value
-    const fn encode_base128_byte(mut self, mut n: u32, i: usize, continued: bool) -> Self {
-        let mask = if continued { 0b10000000 } else { 0 };
+    /// Encode a base 128 value which occupies `nbytes + 1` bytes at the
+    /// current cursor position
+    const fn encode_base128(mut self, mut n: Arc, nbytes: usize) -> Self {
+        let mut i = nbytes;
+        let mut mask = 0;
 
-        if n > 0x80 {
+        loop {
             self.bytes[self.cursor + i] = (n & 0b1111111) as u8 | mask;
             n >>= 7;
+            mask = 0b10000000;
 
-            const_assert!(i > 0, "Base 128 offset miscalculation");
-            self.encode_base128_byte(n, index_sub(i), true)
-        } else {
-            self.bytes[self.cursor] = n as u8 | mask;
-            self
-        }
-    }
-}
+            if i == 0 {
+                break;
+            }
 
-// TODO(tarcieri): replace this with `saturating_sub` when MSRV is 1.47+
-const fn index_sub(index: usize) -> usize {
-    match index {
-        4 => 3,
-        3 => 2,
-        2 => 1,
-        1 => 0,
-        _ => {
-            const_assert!(index <= 4, "index too large");
-            0
+            i -= 1;
         }
+
+        self
     }
 }
 
 /// Compute the length - 1 of an arc when encoded in base 128
-const fn base128_len(arc: Arc) -> usize {
-    match arc {
-        0..=0x7f => 0,
-        0x80..=0x3fff => 1,
-        0x4000..=0x1fffff => 2,
-        0x200000..=0x1fffffff => 3,
-        _ => 4,
+pub(crate) const fn base128_len(arc: Arc) -> usize {
+    let mut len = 0;
+    let mut n = arc >> 7;
+
+    while n != 0 {
+        len += 1;
+        n >>= 7;
     }
+
+    len
 }
 
 /// Write the given unsigned integer in base 128
-// TODO(tarcieri): consolidate encoding logic with `encode_base128_byte`
+// TODO(tarcieri): consolidate encoding logic with `Encoder::encode_base128`
 pub(crate) fn write_base128(bytes: &mut [u8], mut n: Arc) -> Result<usize> {
     let nbytes = base128_len(n);
     let mut i = nbytes;
     let mut mask = 0;
 
-    while n > 0x80 {
-        let byte = bytes.get_mut(i).ok_or(Error)?;
+    while n >= 0x80 {
+        let byte = bytes.get_mut(i).ok_or(Error::TooLong)?;
         *byte = (n & 0b1111111 | mask) as u8;
         n >>= 7;
         i = i.checked_sub(1).unwrap();
         mask = 0b10000000;
     }
 
-    *bytes.get_mut(0).unwrap() = (n | mask) as u8;
+    *bytes.get_mut(0).ok_or(Error::TooLong)? = (n | mask) as u8;
     Ok(nbytes + 1)
 }
 
 #[cfg(test)]
 mod tests {
     use super::Encoder;
+    use crate::Error;
     use hex_literal::hex;
 
     /// OID `1.2.840.10045.2.1` encoded as ASN.1 BER/DER
     const EXAMPLE_OID_BER: &[u8] = &hex!("2A8648CE3D0201");
 
+    #[test]
+    fn base128_len() {
+        assert_eq!(super::base128_len(0), 0);
+        assert_eq!(super::base128_len(0x7f), 0);
+        assert_eq!(super::base128_len(0x80), 1);
+        assert_eq!(super::base128_len(0xffff_ffff), 4);
+        assert_eq!(super::base128_len(u64::MAX as u128), 9);
+        assert_eq!(super::base128_len(u128::MAX), 18);
+    }
+
     #[test]
     fn encode() {
-        let encoder = Encoder::new();
-        let encoder = encoder.encode(1);
-        let encoder = encoder.encode(2);
-        let encoder = encoder.encode(840);
-        let encoder = encoder.encode(10045);
-        let encoder = encoder.encode(2);
-        let encoder = encoder.encode(1);
+        let mut encoder = Encoder::new();
+
+        for (i, &arc) in [1, 2, 840, 10045, 2, 1].iter().enumerate() {
+            encoder = encoder.encode(arc, i).unwrap();
+        }
+
         assert_eq!(&encoder.bytes[..encoder.cursor], EXAMPLE_OID_BER);
     }
+
+    #[test]
+    fn encode_errors() {
+        let encoder = Encoder::new();
+        assert_eq!(
+            encoder.encode(3, 0).err(),
+            Some(Error::RootArc { position: 0 })
+        );
+
+        let encoder = Encoder::new().encode(1, 0).unwrap();
+        assert_eq!(
+            encoder.encode(40, 1).err(),
+            Some(Error::RootArc { position: 1 })
+        );
+
+        let encoder = Encoder::new().encode(1, 0).unwrap().encode(2, 1).unwrap();
+        assert_eq!(encoder.finish().err(), Some(Error::TooShort));
+    }
 }
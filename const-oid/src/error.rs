@@ -2,13 +2,99 @@
 
 use core::fmt;
 
-/// Error type
+/// Error type.
+///
+/// Positions are byte offsets of the offending arc or character within the
+/// input when parsing strings or BER, and the index of the offending arc
+/// when parsing a slice of [`Arc`][`crate::Arc`] values.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub struct Error;
+#[non_exhaustive]
+pub enum Error {
+    /// Input is empty.
+    Empty,
+
+    /// First arc is not in the range 0-2, or the second arc is not in the
+    /// range 0-39.
+    RootArc {
+        /// Position of the invalid arc.
+        position: usize,
+    },
+
+    /// Arc is too large to be represented as an [`Arc`][`crate::Arc`].
+    ArcTooBig {
+        /// Position of the arc.
+        position: usize,
+    },
+
+    /// Arc is not minimally encoded in base 128, i.e. it begins with a
+    /// `0x80` byte.
+    NonMinimal {
+        /// Position of the arc.
+        position: usize,
+    },
+
+    /// Base 128 encoding of an arc is truncated.
+    Truncated {
+        /// Position of the arc.
+        position: usize,
+    },
+
+    /// OID has fewer than [`MIN_ARCS`][`crate::MIN_ARCS`] arcs.
+    TooShort,
+
+    /// BER/DER encoding of the OID is longer than [`MAX_LEN`][`crate::MAX_LEN`]
+    /// (or the provided buffer).
+    TooLong,
+
+    /// OID string ends with a `.`.
+    TrailingDot {
+        /// Position of the trailing `.`.
+        position: usize,
+    },
+
+    /// Unexpected character, missing arc, or unknown name in an OID string.
+    Syntax {
+        /// Position of the unexpected input.
+        position: usize,
+    },
+}
+
+impl Error {
+    /// Get the position in the input where the error occurred, if known.
+    pub fn position(self) -> Option<usize> {
+        match self {
+            Error::RootArc { position }
+            | Error::ArcTooBig { position }
+            | Error::NonMinimal { position }
+            | Error::Truncated { position }
+            | Error::TrailingDot { position }
+            | Error::Syntax { position } => Some(position),
+            Error::Empty | Error::TooShort | Error::TooLong => None,
+        }
+    }
+}
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("OID error")
+        match self {
+            Error::Empty => f.write_str("empty input"),
+            Error::RootArc { position } => write!(
+                f,
+                "invalid root arc at position {} (first arc must be 0-2, second 0-39)",
+                position
+            ),
+            Error::ArcTooBig { position } => write!(f, "arc too large at position {}", position),
+            Error::NonMinimal { position } => write!(
+                f,
+                "non-minimal base 128 encoding of arc at position {}",
+                position
+            ),
+            Error::Truncated { position } => write!(f, "truncated arc at position {}", position),
+            Error::TooShort => f.write_str("too few arcs (minimum 3)"),
+            Error::TooLong => f.write_str("too long (exceeded max DER bytes)"),
+            Error::TrailingDot { position } => write!(f, "trailing '.' at position {}", position),
+            Error::Syntax { position } => write!(f, "unexpected input at position {}", position),
+        }
     }
 }
 
@@ -20,8 +20,20 @@
 //!
 //! # Limits
 //!
-//! The BER/DER encoding of OIDs supported by this library MUST be shorter than
-//! the [`MAX_LEN`] constant.
+//! The BER/DER encoding of an [`ObjectIdentifier`] MUST be no longer than the
+//! [`MAX_LEN`] constant, which allows it to be stored inline (and constructed
+//! in `const` contexts) without a heap.
+//!
+//! When the `alloc` feature is enabled, `ObjectIdentifierBuf` can be used
+//! for OIDs which exceed this length. Arcs of either type can be as large as
+//! 128 bits (see [`Arc`]).
+//!
+//! # Serde support
+//!
+//! When the `serde` feature is enabled, OIDs impl serde's `Serialize` and
+//! `Deserialize` traits. They're serialized as dotted strings when using
+//! human-readable formats (e.g. JSON, TOML) and as their BER encoding when
+//! using binary formats, and are validated when deserialized.
 //!
 //! # Minimum Supported Rust Version
 //!
@@ -37,7 +49,7 @@
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
     html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
-    html_root_url = "https://docs.rs/const-oid/0.4.5"
+    html_root_url = "https://docs.rs/const-oid/0.5.0"
 )]
 #![forbid(unsafe_code)]
 #![warn(missing_docs, rust_2018_idioms)]
@@ -51,18 +63,36 @@ extern crate std;
 #[macro_use]
 mod macros;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod arcs;
+#[cfg(feature = "alloc")]
+mod buf;
+#[cfg(feature = "db")]
+#[cfg_attr(docsrs, doc(cfg(feature = "db")))]
+pub mod db;
 mod encoder;
 mod error;
+mod map;
+mod notation;
 mod parser;
+#[cfg(feature = "serde")]
+mod serde;
 
 pub use crate::{
     arcs::{Arc, Arcs},
     error::{Error, Result},
+    map::{OidMap, OidSet},
+    notation::{Iri, Urn, ValueNotation},
 };
 
-use crate::arcs::RootArcs;
-use core::{convert::TryFrom, fmt, str::FromStr};
+#[doc(hidden)]
+pub use crate::map::__cmp;
+
+#[cfg(feature = "alloc")]
+pub use crate::buf::ObjectIdentifierBuf;
+
+use core::{cmp::Ordering, convert::TryFrom, fmt, str::FromStr};
 
 /// Minimum number of arcs in an OID.
 ///
@@ -80,7 +110,10 @@ pub const MAX_LEN: usize = 23;
 ///
 /// OIDs are hierarchical structures consisting of "arcs", i.e. integer
 /// identifiers.
-#[derive(Copy, Clone, Eq, Hash, PartialEq, PartialOrd, Ord)]
+///
+/// OIDs are ordered by comparing their arcs in turn, so that e.g. an OID
+/// sorts immediately before its children.
+#[derive(Copy, Clone, Eq, Hash, PartialEq)]
 pub struct ObjectIdentifier {
     /// Array containing BER/DER-serialized bytes (no header)
     bytes: [u8; MAX_LEN],
@@ -113,25 +146,23 @@ impl ObjectIdentifier {
     /// - The OID MUST have at least 3 arcs
     /// - The first arc MUST be within the range 0-2
     /// - The second arc MUST be within the range 0-39
-    /// - The BER/DER encoding of the OID MUST be shorter than the [`MAX_LEN`] constant
+    /// - The BER/DER encoding of the OID MUST be no longer than the [`MAX_LEN`] constant
     ///
     /// [1]: ./struct.ObjectIdentifier.html#impl-TryFrom%3C%26%27_%20%5BArc%5D%3E
     pub const fn new(arcs: &[Arc]) -> Self {
-        const_assert!(arcs.len() >= MIN_ARCS, "OID too short (minimum 3 arcs)");
         let mut encoder = encoder::Encoder::new();
+        let mut i = 0;
 
-        macro_rules! encode_arc {
-            ($($n:expr),+) => {
-                $(
-                    if arcs.len() > $n {
-                        encoder = encoder.encode(arcs[$n]);
-                    }
-                )+
-             };
+        while i < arcs.len() {
+            encoder = match encoder.encode(arcs[i], i) {
+                Ok(encoder) => encoder,
+                Err(err) => return unwrap_const(Err(err)),
+            };
+
+            i += 1;
         }
 
-        encode_arc!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
-        encoder.finish()
+        unwrap_const(encoder.finish())
     }
 
     /// Parse an [`ObjectIdentifier`] from the dot-delimited string form, e.g.:
@@ -148,9 +179,10 @@ impl ObjectIdentifier {
     ///
     /// This method is *NOT* intended for use outside of const contexts, as it
     /// will panic with a bad error message. However, this type also has a
-    /// [`FromStr`] impl that can be used for fallible parsing.
+    /// [`FromStr`] impl that can be used for fallible parsing, which returns
+    /// an [`Error`] describing where parsing failed.
     pub const fn parse(s: &str) -> Self {
-        parser::Parser::parse(s).finish()
+        unwrap_const(parser::Parser::parse(s))
     }
 
     /// Get the BER/DER serialization of this OID as bytes
@@ -168,7 +200,7 @@ impl ObjectIdentifier {
     /// Returns [`Arcs`], an iterator over `Arc` values representing the value
     /// of each arc/node.
     pub fn arcs(&self) -> Arcs<'_> {
-        Arcs::new(self)
+        Arcs::new(self.as_bytes())
     }
 
     /// Number of arcs in this [`ObjectIdentifier`].
@@ -176,44 +208,203 @@ impl ObjectIdentifier {
         self.arcs().count()
     }
 
+    /// Parse an OID from a URN as defined in [RFC 3061], e.g.
+    /// `urn:oid:1.2.840.113549`.
+    ///
+    /// [RFC 3061]: https://tools.ietf.org/html/rfc3061
+    pub fn from_urn(s: &str) -> Result<Self> {
+        Self::from_notation(|f| notation::parse_urn(s, f))
+    }
+
+    /// Parse an OID from ASN.1 value notation as used in ASN.1 modules,
+    /// e.g.:
+    ///
+    /// ```
+    /// use const_oid::ObjectIdentifier;
+    ///
+    /// let oid = ObjectIdentifier::from_value_notation(
+    ///     "{ iso(1) member-body(2) us(840) rsadsi(113549) pkcs(1) 1 }"
+    /// )?;
+    /// assert_eq!(oid, ObjectIdentifier::parse("1.2.840.113549.1.1"));
+    /// # Ok::<(), const_oid::Error>(())
+    /// ```
+    ///
+    /// Components may be a number, a name and number (e.g. `iso(1)`), or the
+    /// name of a well-known arc (e.g. `iso`). When the `db` feature is
+    /// enabled, the first component may also be the name of an OID in the
+    /// `db` module, e.g. `{ id-pkix 3 }`.
+    pub fn from_value_notation(s: &str) -> Result<Self> {
+        Self::from_notation(|f| notation::parse_value_notation(s, f))
+    }
+
+    /// Parse an OID from an OID-IRI as defined in [ITU X.660], e.g.
+    /// `/ISO/Member-Body/US/113549`.
+    ///
+    /// Each label must either be an integer or the Unicode label of a
+    /// well-known arc (matched case-insensitively).
+    ///
+    /// [ITU X.660]: https://www.itu.int/rec/T-REC-X.660
+    pub fn from_iri(s: &str) -> Result<Self> {
+        Self::from_notation(|f| notation::parse_iri(s, f))
+    }
+
+    /// Format this OID as a URN, e.g. `urn:oid:1.2.840.113549`.
+    pub fn urn(&self) -> Urn<'_> {
+        Urn {
+            bytes: self.as_bytes(),
+        }
+    }
+
+    /// Format this OID in ASN.1 value notation, e.g.
+    /// `{ iso(1) member-body(2) us(840) rsadsi(113549) }`.
+    pub fn value_notation(&self) -> ValueNotation<'_> {
+        ValueNotation {
+            bytes: self.as_bytes(),
+        }
+    }
+
+    /// Format this OID as an OID-IRI, e.g. `/ISO/Member-Body/US/113549`.
+    pub fn iri(&self) -> Iri<'_> {
+        Iri {
+            bytes: self.as_bytes(),
+        }
+    }
+
+    /// Create an OID from the arcs produced by the given parser.
+    fn from_notation<P>(parse: P) -> Result<Self>
+    where
+        P: FnOnce(&mut dyn FnMut(Arc) -> Result<()>) -> Result<()>,
+    {
+        // The root arcs share a byte, and every other arc needs at least one
+        let mut arcs = [0 as Arc; MAX_LEN + 1];
+        let mut len = 0;
+
+        parse(&mut |arc| {
+            *arcs.get_mut(len).ok_or(Error::TooLong)? = arc;
+            len += 1;
+            Ok(())
+        })?;
+
+        Self::try_from(&arcs[..len])
+    }
+
+    /// Get the parent of this OID, i.e. this OID with its last arc removed.
+    ///
+    /// Returns `None` if the parent would have fewer than [`MIN_ARCS`] arcs.
+    pub const fn parent(&self) -> Option<Self> {
+        let length = self.length as usize;
+
+        // Find the start of the last arc
+        let mut start = length - 1;
+
+        while start > 1 && self.bytes[start - 1] & 0b10000000 != 0 {
+            start -= 1;
+        }
+
+        if start <= 1 {
+            return None;
+        }
+
+        // Unused bytes are always zero, which derived impls rely upon
+        let mut bytes = self.bytes;
+        let mut i = start;
+
+        while i < length {
+            bytes[i] = 0;
+            i += 1;
+        }
+
+        Some(Self {
+            bytes,
+            length: start as u8,
+        })
+    }
+
+    /// Create a child of this OID by appending the given arc, e.g.:
+    ///
+    /// ```
+    /// use const_oid::ObjectIdentifier;
+    ///
+    /// const ID_CE: ObjectIdentifier = ObjectIdentifier::parse("2.5.29");
+    /// const ID_CE_KEY_USAGE: ObjectIdentifier = ID_CE.child(15);
+    ///
+    /// assert_eq!(ID_CE_KEY_USAGE, ObjectIdentifier::parse("2.5.29.15"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Like [`ObjectIdentifier::new`], this method is intended for use in
+    /// constants and panics if the resulting OID exceeds [`MAX_LEN`]. Use
+    /// [`ObjectIdentifier::push_arc`] to append arcs fallibly.
+    pub const fn child(&self, arc: Arc) -> Self {
+        match encoder::Encoder::extend(*self).encode(arc, self.length as usize) {
+            Ok(encoder) => unwrap_const(encoder.finish()),
+            Err(err) => unwrap_const(Err(err)),
+        }
+    }
+
+    /// Append the given arc to this OID.
+    ///
+    /// Returns an error (leaving this OID unchanged) if the resulting OID
+    /// would exceed [`MAX_LEN`].
+    pub fn push_arc(&mut self, arc: Arc) -> Result<()> {
+        let length = self.length as usize;
+        let arc_len = encoder::write_base128(&mut self.bytes[length..], arc)?;
+        self.length = (length + arc_len) as u8;
+        Ok(())
+    }
+
+    /// Is the given OID a prefix of this one (i.e. this OID or one of its
+    /// ancestors)?
+    pub const fn starts_with(&self, prefix: &ObjectIdentifier) -> bool {
+        if prefix.length > self.length {
+            return false;
+        }
+
+        // Since the last byte of an arc is the only one without its high bit
+        // set, matching bytes means all of the prefix's arcs match
+        let mut i = 0;
+
+        while i < prefix.length as usize {
+            if self.bytes[i] != prefix.bytes[i] {
+                return false;
+            }
+
+            i += 1;
+        }
+
+        true
+    }
+
+    /// Get the arcs of this OID which follow the given prefix, or `None` if
+    /// it isn't a prefix of this OID (see [`ObjectIdentifier::starts_with`]).
+    ///
+    /// ```
+    /// use const_oid::ObjectIdentifier;
+    ///
+    /// let pkcs9 = ObjectIdentifier::parse("1.2.840.113549.1.9");
+    /// let oid = ObjectIdentifier::parse("1.2.840.113549.1.9.16.2.14");
+    ///
+    /// let arcs = oid.strip_prefix(&pkcs9).unwrap();
+    /// assert_eq!(arcs.collect::<Vec<_>>(), [16, 2, 14]);
+    /// ```
+    pub fn strip_prefix(&self, prefix: &ObjectIdentifier) -> Option<Arcs<'_>> {
+        if self.starts_with(prefix) {
+            Some(Arcs::new_at(self.as_bytes(), prefix.length as usize))
+        } else {
+            None
+        }
+    }
+
     /// Parse an OID from from its BER/DER encoding.
     pub fn from_ber(ber_bytes: &[u8]) -> Result<Self> {
         let len = ber_bytes.len();
 
-        if !(2..=MAX_LEN).contains(&len) {
-            return Err(Error);
+        if len > MAX_LEN {
+            return Err(Error::TooLong);
         }
 
-        // Validate root arcs are in range
-        ber_bytes
-            .get(0)
-            .cloned()
-            .ok_or(Error)
-            .and_then(RootArcs::try_from)?;
-
-        // Validate lower arcs are well-formed
-        let mut arc_offset = 1;
-        let mut arc_bytes = 0;
-
-        // TODO(tarcieri): consolidate this with `Arcs::next`?
-        while arc_offset < len {
-            match ber_bytes.get(arc_offset + arc_bytes).cloned() {
-                Some(byte) => {
-                    arc_bytes += 1;
-
-                    if arc_bytes == 4 && byte & 0b11110000 != 0 {
-                        // Overflowed `Arc` (u32)
-                        return Err(Error);
-                    }
-
-                    if byte & 0b10000000 == 0 {
-                        arc_offset += arc_bytes;
-                        arc_bytes = 0;
-                    }
-                }
-                None => return Err(Error), // truncated OID
-            }
-        }
+        arcs::validate(ber_bytes)?;
 
         let mut bytes = [0u8; MAX_LEN];
         bytes[..len].copy_from_slice(ber_bytes);
@@ -237,7 +428,7 @@ impl ObjectIdentifier {
         let len = self.as_bytes().len();
 
         if bytes.len() < len {
-            return Err(Error);
+            return Err(Error::TooLong);
         }
 
         bytes[..len].copy_from_slice(self.as_bytes());
@@ -263,29 +454,7 @@ impl FromStr for ObjectIdentifier {
     type Err = Error;
 
     fn from_str(string: &str) -> Result<Self> {
-        let mut split = string.split('.');
-        let first_arc = split.next().and_then(|s| s.parse().ok()).ok_or(Error)?;
-        let second_arc = split.next().and_then(|s| s.parse().ok()).ok_or(Error)?;
-
-        let mut bytes = [0u8; MAX_LEN];
-        bytes[0] = RootArcs::new(first_arc, second_arc)?.into();
-
-        let mut offset = 1;
-
-        for s in split {
-            let arc = s.parse().map_err(|_| Error)?;
-            offset += encoder::write_base128(&mut bytes[offset..], arc)?;
-        }
-
-        if offset > 1 {
-            Ok(Self {
-                bytes,
-                length: offset as u8,
-            })
-        } else {
-            // Minimum 3 arcs
-            Err(Error)
-        }
+        parser::Parser::parse(string)
     }
 }
 
@@ -293,23 +462,13 @@ impl TryFrom<&[Arc]> for ObjectIdentifier {
     type Error = Error;
 
     fn try_from(arcs: &[Arc]) -> Result<Self> {
-        if arcs.len() < MIN_ARCS {
-            return Err(Error);
-        }
-
-        let mut bytes = [0u8; MAX_LEN];
-        bytes[0] = RootArcs::new(arcs[0], arcs[1])?.into();
-
-        let mut offset = 1;
+        let mut encoder = encoder::Encoder::new();
 
-        for &arc in &arcs[2..] {
-            offset += encoder::write_base128(&mut bytes[offset..], arc)?;
+        for (i, &arc) in arcs.iter().enumerate() {
+            encoder = encoder.encode(arc, i)?;
         }
 
-        Ok(Self {
-            bytes,
-            length: offset as u8,
-        })
+        encoder.finish()
     }
 }
 
@@ -319,6 +478,64 @@ impl From<&ObjectIdentifier> for ObjectIdentifier {
     }
 }
 
+impl PartialOrd for ObjectIdentifier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ObjectIdentifier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.arcs().cmp(other.arcs())
+    }
+}
+
+/// Unwrap the result of encoding an OID in a `const` context, panicking with
+/// a message describing the error (which is displayed as a compile error).
+const fn unwrap_const(result: Result<ObjectIdentifier>) -> ObjectIdentifier {
+    const_assert!(!matches!(result, Err(Error::Empty)), "OID string is empty");
+    const_assert!(
+        !matches!(result, Err(Error::RootArc { .. })),
+        "invalid root arc in OID (first arc must be 0-2, second 0-39)"
+    );
+    const_assert!(
+        !matches!(result, Err(Error::ArcTooBig { .. })),
+        "OID arc too large (exceeded 128 bits)"
+    );
+    const_assert!(
+        !matches!(result, Err(Error::TooShort)),
+        "OID too short (minimum 3 arcs)"
+    );
+    const_assert!(
+        !matches!(result, Err(Error::TooLong)),
+        "OID too long (exceeded max DER bytes)"
+    );
+    const_assert!(
+        !matches!(result, Err(Error::TrailingDot { .. })),
+        "invalid trailing '.' in OID"
+    );
+    const_assert!(
+        !matches!(result, Err(Error::Syntax { .. })),
+        "invalid character or empty arc in OID"
+    );
+    const_assert!(
+        !matches!(
+            result,
+            Err(Error::NonMinimal { .. }) | Err(Error::Truncated { .. })
+        ),
+        "malformed OID"
+    );
+
+    match result {
+        Ok(oid) => oid,
+        // Unreachable (checked by above `const_assert!`)
+        Err(_) => ObjectIdentifier {
+            bytes: [0; MAX_LEN],
+            length: 0,
+        },
+    }
+}
+
 impl fmt::Debug for ObjectIdentifier {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "ObjectIdentifier({})", self)
@@ -0,0 +1,393 @@
+//! Maps and sets keyed by OIDs which can be constructed in `const` contexts.
+
+use crate::ObjectIdentifier;
+use core::{cmp::Ordering, fmt};
+
+/// Map from OIDs to values which can be constructed in `const` contexts.
+///
+/// Entries are sorted by OID, which allows them to be looked up by binary
+/// search. Maps are most easily declared using the
+/// [`oid_map!`][`crate::oid_map`] macro, which sorts the entries at compile
+/// time:
+///
+/// ```
+/// use const_oid::{oid_map, ObjectIdentifier, OidMap};
+///
+/// #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+/// enum Digest {
+///     Sha256,
+///     Sha384,
+/// }
+///
+/// oid_map! {
+///     /// Digest algorithms.
+///     const DIGESTS: OidMap<Digest> = {
+///         ObjectIdentifier::parse("2.16.840.1.101.3.4.2.2") => Digest::Sha384,
+///         ObjectIdentifier::parse("2.16.840.1.101.3.4.2.1") => Digest::Sha256,
+///     };
+/// }
+///
+/// let oid = ObjectIdentifier::parse("2.16.840.1.101.3.4.2.1");
+/// assert_eq!(DIGESTS.get(&oid), Some(&Digest::Sha256));
+/// assert_eq!(DIGESTS.key_of(&Digest::Sha256), Some(&oid));
+/// ```
+pub struct OidMap<V: 'static> {
+    /// Entries sorted by OID
+    entries: &'static [(ObjectIdentifier, V)],
+}
+
+impl<V: 'static> OidMap<V> {
+    /// Create a new [`OidMap`] from entries which are sorted by OID.
+    ///
+    /// # Panics
+    ///
+    /// Like [`ObjectIdentifier::new`], this method is intended for use in
+    /// constants and panics (generating a compiler error) if the entries
+    /// aren't sorted in ascending order of OID, or contain duplicate OIDs.
+    pub const fn new(entries: &'static [(ObjectIdentifier, V)]) -> Self {
+        let mut i = 1;
+
+        while i < entries.len() {
+            const_assert!(
+                (__cmp(&entries[i - 1].0, &entries[i].0) as i8) < 0,
+                "OidMap entries must be sorted by OID and unique"
+            );
+
+            i += 1;
+        }
+
+        Self { entries }
+    }
+
+    /// Get the value associated with the given OID, if any.
+    pub fn get(&self, oid: &ObjectIdentifier) -> Option<&'static V> {
+        let entries = self.entries;
+
+        entries
+            .binary_search_by(|(key, _)| __cmp(key, oid))
+            .ok()
+            .map(|i| &entries[i].1)
+    }
+
+    /// Does this map contain an entry for the given OID?
+    pub fn contains_key(&self, oid: &ObjectIdentifier) -> bool {
+        self.get(oid).is_some()
+    }
+
+    /// Find the OID associated with the given value, if any.
+    ///
+    /// Unlike [`OidMap::get`], this performs a linear search.
+    pub fn key_of(&self, value: &V) -> Option<&'static ObjectIdentifier>
+    where
+        V: PartialEq,
+    {
+        self.entries
+            .iter()
+            .find(|(_, v)| v == value)
+            .map(|(key, _)| key)
+    }
+
+    /// Get the entries in this map, sorted by OID.
+    pub const fn entries(&self) -> &'static [(ObjectIdentifier, V)] {
+        self.entries
+    }
+
+    /// Iterate over the entries in this map, sorted by OID.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static ObjectIdentifier, &'static V)> {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+
+    /// Number of entries in this map.
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Is this map empty?
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<V: 'static> Clone for OidMap<V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V: 'static> Copy for OidMap<V> {}
+
+impl<V: fmt::Debug + 'static> fmt::Debug for OidMap<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Set of OIDs which can be constructed in `const` contexts.
+///
+/// OIDs are sorted, which allows membership to be checked by binary search.
+/// Sets are most easily declared using the [`oid_set!`][`crate::oid_set`]
+/// macro, which sorts the OIDs at compile time:
+///
+/// ```
+/// use const_oid::{oid_set, ObjectIdentifier, OidSet};
+///
+/// oid_set! {
+///     /// Edwards curve algorithms.
+///     const EDWARDS: OidSet = {
+///         ObjectIdentifier::parse("1.3.101.113"),
+///         ObjectIdentifier::parse("1.3.101.112"),
+///     };
+/// }
+///
+/// assert!(EDWARDS.contains(&ObjectIdentifier::parse("1.3.101.112")));
+/// assert!(!EDWARDS.contains(&ObjectIdentifier::parse("1.3.101.110")));
+/// ```
+#[derive(Copy, Clone)]
+pub struct OidSet {
+    /// Sorted OIDs
+    oids: &'static [ObjectIdentifier],
+}
+
+impl OidSet {
+    /// Create a new [`OidSet`] from OIDs which are sorted.
+    ///
+    /// # Panics
+    ///
+    /// Like [`ObjectIdentifier::new`], this method is intended for use in
+    /// constants and panics (generating a compiler error) if the OIDs
+    /// aren't sorted in ascending order, or contain duplicates.
+    pub const fn new(oids: &'static [ObjectIdentifier]) -> Self {
+        let mut i = 1;
+
+        while i < oids.len() {
+            const_assert!(
+                (__cmp(&oids[i - 1], &oids[i]) as i8) < 0,
+                "OidSet entries must be sorted and unique"
+            );
+
+            i += 1;
+        }
+
+        Self { oids }
+    }
+
+    /// Does this set contain the given OID?
+    pub fn contains(&self, oid: &ObjectIdentifier) -> bool {
+        self.oids.binary_search_by(|key| __cmp(key, oid)).is_ok()
+    }
+
+    /// Get the OIDs in this set, in sorted order.
+    pub const fn as_slice(&self) -> &'static [ObjectIdentifier] {
+        self.oids
+    }
+
+    /// Iterate over the OIDs in this set, in sorted order.
+    pub fn iter(&self) -> impl Iterator<Item = &'static ObjectIdentifier> {
+        self.oids.iter()
+    }
+
+    /// Number of OIDs in this set.
+    pub const fn len(&self) -> usize {
+        self.oids.len()
+    }
+
+    /// Is this set empty?
+    pub const fn is_empty(&self) -> bool {
+        self.oids.is_empty()
+    }
+}
+
+impl fmt::Debug for OidSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Compare OIDs by their arcs in a `const` context, consistent with the
+/// [`Ord`] impl on [`ObjectIdentifier`].
+///
+/// Used by the [`oid_map!`][`crate::oid_map`] and
+/// [`oid_set!`][`crate::oid_set`] macros. Not a public API.
+#[doc(hidden)]
+pub const fn __cmp(a: &ObjectIdentifier, b: &ObjectIdentifier) -> Ordering {
+    // The root arcs share the first byte, which is ordered accordingly
+    if a.bytes[0] != b.bytes[0] {
+        return cmp_usize(a.bytes[0] as usize, b.bytes[0] as usize);
+    }
+
+    let mut i = 1;
+
+    loop {
+        let a_remaining = a.length as usize - i;
+        let b_remaining = b.length as usize - i;
+
+        // An OID sorts before its children
+        if a_remaining == 0 || b_remaining == 0 {
+            return cmp_usize(a_remaining, b_remaining);
+        }
+
+        // Arcs are minimally encoded, so a longer encoding is a larger arc
+        let a_end = arc_end(a, i);
+        let b_end = arc_end(b, i);
+
+        if a_end != b_end {
+            return cmp_usize(a_end, b_end);
+        }
+
+        while i < a_end {
+            if a.bytes[i] != b.bytes[i] {
+                return cmp_usize(a.bytes[i] as usize, b.bytes[i] as usize);
+            }
+
+            i += 1;
+        }
+    }
+}
+
+/// Find the end of the base 128 arc beginning at the given offset.
+const fn arc_end(oid: &ObjectIdentifier, mut offset: usize) -> usize {
+    while oid.bytes[offset] & 0b10000000 != 0 {
+        offset += 1;
+    }
+
+    offset + 1
+}
+
+/// Compare integers in a `const` context.
+// TODO(tarcieri): use `Ord::cmp` when it's usable in `const fn`
+const fn cmp_usize(a: usize, b: usize) -> Ordering {
+    if a < b {
+        Ordering::Less
+    } else if a > b {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Declare an [`OidMap`] constant, sorting its entries at compile time.
+///
+/// Keys are `const` expressions evaluating to an [`ObjectIdentifier`], and
+/// values must be [`Copy`]. Duplicate keys result in a compiler error.
+///
+/// The map type is used as written, so [`OidMap`] must be in scope (or given
+/// by its full path).
+///
+/// See [`OidMap`] for an example.
+#[macro_export]
+macro_rules! oid_map {
+    (
+        $(#[$attr:meta])*
+        $vis:vis const $name:ident: $($map_ty:ident)::+<$value_ty:ty> = {};
+    ) => {
+        $(#[$attr])*
+        $vis const $name: $($map_ty)::+<$value_ty> = $($map_ty)::+::new(&[]);
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis const $name:ident: $($map_ty:ident)::+<$value_ty:ty> = {
+            $($oid:expr => $value:expr),+ $(,)?
+        };
+    ) => {
+        $(#[$attr])*
+        $vis const $name: $($map_ty)::+<$value_ty> = $($map_ty)::+::new({
+            const ENTRIES: &[($crate::ObjectIdentifier, $value_ty)] = &{
+                let mut entries = [$(($oid, $value)),+];
+                $crate::__oid_sort!(entries .0);
+                entries
+            };
+
+            ENTRIES
+        });
+    };
+}
+
+/// Declare an [`OidSet`] constant, sorting its OIDs at compile time.
+///
+/// OIDs are `const` expressions evaluating to an [`ObjectIdentifier`].
+/// Duplicate OIDs result in a compiler error.
+///
+/// The set type is used as written, so [`OidSet`] must be in scope (or given
+/// by its full path).
+///
+/// See [`OidSet`] for an example.
+#[macro_export]
+macro_rules! oid_set {
+    (
+        $(#[$attr:meta])*
+        $vis:vis const $name:ident: $($set_ty:ident)::+ = {};
+    ) => {
+        $(#[$attr])*
+        $vis const $name: $($set_ty)::+ = $($set_ty)::+::new(&[]);
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis const $name:ident: $($set_ty:ident)::+ = {
+            $($oid:expr),+ $(,)?
+        };
+    ) => {
+        $(#[$attr])*
+        $vis const $name: $($set_ty)::+ = $($set_ty)::+::new({
+            const OIDS: &[$crate::ObjectIdentifier] = &{
+                let mut oids = [$($oid),+];
+                $crate::__oid_sort!(oids);
+                oids
+            };
+
+            OIDS
+        });
+    };
+}
+
+/// Sort an array of OIDs (or of entries with an OID in the given field)
+/// in a `const` context.
+///
+/// Uses insertion sort, as `const` contexts don't support `&mut`. Not a
+/// public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __oid_sort {
+    ($array:ident $($field:tt)*) => {
+        let mut i = 1;
+
+        while i < $array.len() {
+            let mut j = i;
+
+            while j > 0 && ($crate::__cmp(&$array[j - 1]$($field)*, &$array[j]$($field)*) as i8) > 0 {
+                let tmp = $array[j];
+                $array[j] = $array[j - 1];
+                $array[j - 1] = tmp;
+                j -= 1;
+            }
+
+            i += 1;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::__cmp;
+    use crate::ObjectIdentifier;
+
+    #[test]
+    fn cmp_matches_ord() {
+        let oids = [
+            ObjectIdentifier::parse("0.9.2342"),
+            ObjectIdentifier::parse("1.2.840"),
+            ObjectIdentifier::parse("1.2.840.113549"),
+            ObjectIdentifier::parse("1.2.840.113549.1"),
+            ObjectIdentifier::parse("1.2.16383"),
+            ObjectIdentifier::parse("1.2.16384"),
+            ObjectIdentifier::parse("1.2.16384.1"),
+            ObjectIdentifier::parse("1.3.6"),
+            ObjectIdentifier::parse("2.25.329800735698586629295641978511506172918"),
+        ];
+
+        for a in &oids {
+            for b in &oids {
+                assert_eq!(__cmp(a, b), a.cmp(b), "{} vs {}", a, b);
+            }
+        }
+    }
+}
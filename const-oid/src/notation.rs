@@ -0,0 +1,522 @@
+//! Alternative notations for OIDs: URNs, ASN.1 value notation and OID-IRIs.
+
+use crate::{
+    arcs::{FIRST_ARC_MAX, SECOND_ARC_MAX},
+    Arc, Arcs, Error, Result,
+};
+use core::fmt;
+
+/// URN namespace for OIDs as defined in [RFC 3061].
+///
+/// [RFC 3061]: https://tools.ietf.org/html/rfc3061
+const URN_PREFIX: &str = "urn:oid:";
+
+/// Maximum depth of the arcs in [`NAMED_ARCS`].
+const MAX_NAMED_DEPTH: usize = 8;
+
+/// Arc with a well-known name.
+struct NamedArc {
+    /// Arcs of the parent of this arc.
+    parent: &'static [Arc],
+
+    /// Value of this arc.
+    arc: Arc,
+
+    /// ASN.1 identifier used in value notation, e.g. `member-body`.
+    name: &'static str,
+
+    /// Unicode label used in OID-IRIs, e.g. `Member-Body`.
+    label: Option<&'static str>,
+}
+
+/// Define the [`NAMED_ARCS`] table.
+macro_rules! named_arcs {
+    ($(($($parent:expr),*) $arc:expr => $name:expr $(, $label:expr)?;)+) => {
+        /// Well-known named arcs.
+        ///
+        /// Where an arc has several names (e.g. `itu-t` and `ccitt`), the
+        /// first one is used when formatting.
+        const NAMED_ARCS: &[NamedArc] = &[
+            $(NamedArc {
+                parent: &[$($parent),*],
+                arc: $arc,
+                name: $name,
+                label: named_arcs!(@label $($label)?),
+            },)+
+        ];
+    };
+    (@label) => { None };
+    (@label $label:expr) => { Some($label) };
+}
+
+named_arcs! {
+    () 0 => "itu-t", "ITU-T";
+    () 0 => "ccitt";
+    () 1 => "iso", "ISO";
+    () 2 => "joint-iso-itu-t", "Joint-ISO-ITU-T";
+    () 2 => "joint-iso-ccitt";
+    (0) 0 => "recommendation";
+    (0) 9 => "data";
+    (0, 9) 2342 => "pss";
+    (0, 9, 2342) 19200300 => "ucl";
+    (0, 9, 2342, 19200300) 100 => "pilot";
+    (1) 0 => "standard", "Standard";
+    (1) 1 => "registration-authority", "Registration-Authority";
+    (1) 2 => "member-body", "Member-Body";
+    (1) 3 => "identified-organization", "Identified-Organization";
+    (1, 2) 840 => "us", "US";
+    (1, 2, 840) 10040 => "x9-57";
+    (1, 2, 840) 10045 => "ansi-X9-62";
+    (1, 2, 840) 10046 => "ansi-x942";
+    (1, 2, 840) 113549 => "rsadsi";
+    (1, 2, 840, 113549) 1 => "pkcs";
+    (1, 2, 840, 113549, 1) 1 => "pkcs-1";
+    (1, 2, 840, 113549, 1) 5 => "pkcs-5";
+    (1, 2, 840, 113549, 1) 7 => "pkcs-7";
+    (1, 2, 840, 113549, 1) 9 => "pkcs-9";
+    (1, 2, 840, 113549, 1) 12 => "pkcs-12";
+    (1, 2, 840, 113549) 2 => "digestAlgorithm";
+    (1, 2, 840, 113549) 3 => "encryptionAlgorithm";
+    (1, 3) 6 => "dod";
+    (1, 3, 6) 1 => "internet";
+    (1, 3, 6, 1) 1 => "directory";
+    (1, 3, 6, 1) 2 => "mgmt";
+    (1, 3, 6, 1) 3 => "experimental";
+    (1, 3, 6, 1) 4 => "private";
+    (1, 3, 6, 1, 4) 1 => "enterprise";
+    (1, 3, 6, 1) 5 => "security";
+    (1, 3, 6, 1, 5) 5 => "mechanisms";
+    (1, 3, 6, 1, 5, 5) 7 => "pkix";
+    (1, 3) 14 => "oiw";
+    (1, 3, 14) 3 => "secsig";
+    (1, 3, 14, 3) 2 => "algorithms";
+    (1, 3) 132 => "certicom";
+    (1, 3, 132) 0 => "curve";
+    (2) 1 => "asn1", "ASN.1";
+    (2) 5 => "ds";
+    (2, 5) 4 => "attributeType";
+    (2, 5) 29 => "certificateExtension";
+    (2) 16 => "country", "Country";
+    (2, 16) 840 => "us", "US";
+    (2, 16, 840) 1 => "organization";
+    (2, 16, 840, 1) 101 => "gov";
+    (2, 16, 840, 1, 101) 3 => "csor";
+    (2, 16, 840, 1, 101, 3) 4 => "nistAlgorithm";
+    (2, 16, 840, 1, 101, 3, 4) 1 => "aes";
+    (2, 16, 840, 1, 101, 3, 4) 2 => "hashAlgs";
+    (2, 16, 840, 1, 101, 3, 4) 3 => "sigAlgs";
+    (2) 23 => "international-organizations", "International-Organizations";
+    (2) 25 => "uuid", "UUID";
+}
+
+/// Parse an OID from its dotted string form, e.g. `1.2.840.113549`.
+#[cfg(feature = "alloc")]
+pub(crate) fn parse_dotted(s: &str, f: impl FnMut(Arc) -> Result<()>) -> Result<()> {
+    if s.is_empty() {
+        return Err(Error::Empty);
+    }
+
+    parse_dotted_at(s, 0, &mut Path::new(f))
+}
+
+/// Parse an OID from a URN, e.g. `urn:oid:1.2.840.113549`.
+pub(crate) fn parse_urn(s: &str, f: impl FnMut(Arc) -> Result<()>) -> Result<()> {
+    if s.is_empty() {
+        return Err(Error::Empty);
+    }
+
+    // The namespace identifier is case-insensitive
+    let prefix_len = s
+        .bytes()
+        .zip(URN_PREFIX.bytes())
+        .take_while(|(a, b)| a.eq_ignore_ascii_case(b))
+        .count();
+
+    if prefix_len < URN_PREFIX.len() || s.len() == prefix_len {
+        return Err(Error::Syntax {
+            position: prefix_len,
+        });
+    }
+
+    parse_dotted_at(&s[prefix_len..], prefix_len, &mut Path::new(f))
+}
+
+/// Parse the dotted string form of an OID which begins at the given
+/// position within the input.
+fn parse_dotted_at<F>(s: &str, offset: usize, path: &mut Path<F>) -> Result<()>
+where
+    F: FnMut(Arc) -> Result<()>,
+{
+    let mut position = offset;
+
+    for arc in s.split('.') {
+        if arc.is_empty() && position == offset + s.len() {
+            return Err(Error::TrailingDot {
+                position: position - 1,
+            });
+        }
+
+        path.push(parse_number(arc, position)?, position)?;
+        position += arc.len() + 1;
+    }
+
+    Ok(())
+}
+
+/// Parse an OID from ASN.1 value notation, e.g.
+/// `{ iso(1) member-body(2) us(840) 113549 }`.
+pub(crate) fn parse_value_notation(s: &str, f: impl FnMut(Arc) -> Result<()>) -> Result<()> {
+    if s.is_empty() {
+        return Err(Error::Empty);
+    }
+
+    let mut path = Path::new(f);
+    let mut tokens = Tokens::new(s);
+    tokens.expect(Token::Open)?;
+
+    loop {
+        match tokens.next_token()? {
+            (position, Token::Number(number)) => {
+                path.push(parse_number(number, position)?, position)?
+            }
+            (position, Token::Identifier(name)) => {
+                // Name and number form, e.g. `iso(1)`
+                if let Some((_, Token::OpenParen)) = tokens.peek() {
+                    tokens.next();
+
+                    let (position, arc) = match tokens.next_token()? {
+                        (position, Token::Number(number)) => {
+                            (position, parse_number(number, position)?)
+                        }
+                        (position, _) => return Err(Error::Syntax { position }),
+                    };
+
+                    tokens.expect(Token::CloseParen)?;
+                    path.push(arc, position)?;
+                } else if let Some(named) = path.find(|named| named.name == name) {
+                    path.push(named.arc, position)?;
+                } else {
+                    path.push_reference(name, position)?;
+                }
+            }
+            (_, Token::Close) => break,
+            (position, _) => return Err(Error::Syntax { position }),
+        }
+    }
+
+    match tokens.next() {
+        Some((position, _)) => Err(Error::Syntax { position }),
+        None => Ok(()),
+    }
+}
+
+/// Parse an OID from an OID-IRI, e.g. `/ISO/Member-Body/US/113549`.
+///
+/// Unicode labels are matched case-insensitively.
+pub(crate) fn parse_iri(s: &str, f: impl FnMut(Arc) -> Result<()>) -> Result<()> {
+    if s.is_empty() {
+        return Err(Error::Empty);
+    }
+
+    if !s.starts_with('/') {
+        return Err(Error::Syntax { position: 0 });
+    }
+
+    let mut path = Path::new(f);
+    let mut position = 1;
+
+    for label in s[1..].split('/') {
+        let arc = if label.bytes().all(|byte| byte.is_ascii_digit()) {
+            parse_number(label, position)?
+        } else {
+            path.find(|named| match named.label {
+                Some(named_label) => named_label.eq_ignore_ascii_case(label),
+                None => false,
+            })
+            .ok_or(Error::Syntax { position })?
+            .arc
+        };
+
+        path.push(arc, position)?;
+        position += label.len() + 1;
+    }
+
+    Ok(())
+}
+
+/// Parse a decimal arc which begins at the given position within the input,
+/// rejecting leading zeros.
+fn parse_number(s: &str, position: usize) -> Result<Arc> {
+    if let Some(i) = s.bytes().position(|byte| !byte.is_ascii_digit()) {
+        return Err(Error::Syntax {
+            position: position + i,
+        });
+    }
+
+    if s.is_empty() {
+        return Err(Error::Syntax { position });
+    }
+
+    if s.len() > 1 && s.starts_with('0') {
+        return Err(Error::Syntax {
+            position: position + 1,
+        });
+    }
+
+    s.parse().map_err(|_| Error::ArcTooBig { position })
+}
+
+/// Arcs parsed so far, tracked in order to look up named arcs.
+struct Path<F> {
+    /// Callback which receives each parsed arc.
+    f: F,
+
+    /// Leading arcs (up to [`MAX_NAMED_DEPTH`]).
+    arcs: [Arc; MAX_NAMED_DEPTH],
+
+    /// Number of arcs parsed so far.
+    depth: usize,
+}
+
+impl<F: FnMut(Arc) -> Result<()>> Path<F> {
+    fn new(f: F) -> Self {
+        Self {
+            f,
+            arcs: [0; MAX_NAMED_DEPTH],
+            depth: 0,
+        }
+    }
+
+    /// Add an arc which begins at the given position within the input to
+    /// the path.
+    fn push(&mut self, arc: Arc, position: usize) -> Result<()> {
+        let max = match self.depth {
+            0 => FIRST_ARC_MAX,
+            1 => SECOND_ARC_MAX,
+            _ => Arc::MAX,
+        };
+
+        if arc > max {
+            return Err(Error::RootArc { position });
+        }
+
+        if let Some(slot) = self.arcs.get_mut(self.depth) {
+            *slot = arc;
+        }
+
+        self.depth += 1;
+        (self.f)(arc)
+    }
+
+    /// Add the arcs of a defined OID value (e.g. `id-pkix`) to the path,
+    /// which is only allowed as the first component.
+    #[cfg(feature = "db")]
+    fn push_reference(&mut self, name: &str, position: usize) -> Result<()> {
+        if self.depth != 0 {
+            return Err(Error::Syntax { position });
+        }
+
+        for arc in crate::db::by_name(name)
+            .ok_or(Error::Syntax { position })?
+            .arcs()
+        {
+            self.push(arc, position)?;
+        }
+
+        Ok(())
+    }
+
+    /// Defined OID values require the `db` feature.
+    #[cfg(not(feature = "db"))]
+    fn push_reference(&mut self, _name: &str, position: usize) -> Result<()> {
+        Err(Error::Syntax { position })
+    }
+
+    /// Find a named child of the current path.
+    fn find(&self, predicate: impl Fn(&NamedArc) -> bool) -> Option<&'static NamedArc> {
+        let parent = self.arcs.get(..self.depth)?;
+
+        NAMED_ARCS
+            .iter()
+            .find(|named| named.parent == parent && predicate(named))
+    }
+}
+
+/// Tokens in ASN.1 value notation.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Token<'a> {
+    Open,
+    Close,
+    OpenParen,
+    CloseParen,
+    Number(&'a str),
+    Identifier(&'a str),
+    Invalid,
+}
+
+/// Tokenizer for ASN.1 value notation.
+#[derive(Copy, Clone)]
+struct Tokens<'a> {
+    /// Input being tokenized.
+    input: &'a str,
+
+    /// Remaining input.
+    rest: &'a str,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, rest: input }
+    }
+
+    fn peek(&self) -> Option<(usize, Token<'a>)> {
+        let mut tokens = *self;
+        tokens.next()
+    }
+
+    /// Get the next token, which must exist.
+    fn next_token(&mut self) -> Result<(usize, Token<'a>)> {
+        let position = self.input.len();
+        self.next().ok_or(Error::Syntax { position })
+    }
+
+    /// Consume the next token, which must be the expected one.
+    fn expect(&mut self, expected: Token<'a>) -> Result<()> {
+        match self.next_token()? {
+            (_, token) if token == expected => Ok(()),
+            (position, _) => Err(Error::Syntax { position }),
+        }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = (usize, Token<'a>);
+
+    /// Get the next token along with its position within the input.
+    fn next(&mut self) -> Option<(usize, Token<'a>)> {
+        let s = self.rest.trim_start();
+        let position = self.input.len() - s.len();
+        let first = *s.as_bytes().first()?;
+
+        let len = match first {
+            b'{' | b'}' | b'(' | b')' => 1,
+            b'0'..=b'9' => s.bytes().take_while(u8::is_ascii_digit).count(),
+            b'a'..=b'z' | b'A'..=b'Z' => s
+                .bytes()
+                .take_while(|&byte| byte.is_ascii_alphanumeric() || byte == b'-')
+                .count(),
+            _ => {
+                self.rest = "";
+                return Some((position, Token::Invalid));
+            }
+        };
+
+        let (token, rest) = s.split_at(len);
+        self.rest = rest;
+
+        let token = match first {
+            b'{' => Token::Open,
+            b'}' => Token::Close,
+            b'(' => Token::OpenParen,
+            b')' => Token::CloseParen,
+            b'0'..=b'9' => Token::Number(token),
+            _ => Token::Identifier(token),
+        };
+
+        Some((position, token))
+    }
+}
+
+/// Find the named arc for the given arc of an OID, given the preceding arcs.
+fn named_arc(parent: &[Arc], arc: Arc) -> Option<&'static NamedArc> {
+    NAMED_ARCS
+        .iter()
+        .find(|named| named.parent == parent && named.arc == arc)
+}
+
+/// Iterate over the arcs of an OID along with their named arc (if any).
+fn with_names<'a>(bytes: &'a [u8]) -> impl Iterator<Item = (Arc, Option<&'static NamedArc>)> + 'a {
+    let mut parent = [0; MAX_NAMED_DEPTH];
+
+    Arcs::new(bytes).enumerate().map(move |(i, arc)| {
+        let named = parent.get(..i).and_then(|parent| named_arc(parent, arc));
+
+        if let Some(slot) = parent.get_mut(i) {
+            *slot = arc;
+        }
+
+        (arc, named)
+    })
+}
+
+/// Display adapter which formats an OID as a URN, e.g.
+/// `urn:oid:1.2.840.113549`.
+#[derive(Copy, Clone, Debug)]
+pub struct Urn<'a> {
+    /// BER/DER serialization of the OID.
+    pub(crate) bytes: &'a [u8],
+}
+
+impl fmt::Display for Urn<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(URN_PREFIX)?;
+
+        for (i, arc) in Arcs::new(self.bytes).enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+
+            write!(f, "{}", arc)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Display adapter which formats an OID in ASN.1 value notation, e.g.
+/// `{ iso(1) member-body(2) us(840) rsadsi(113549) }`.
+///
+/// Well-known arcs are rendered in name and number form.
+#[derive(Copy, Clone, Debug)]
+pub struct ValueNotation<'a> {
+    /// BER/DER serialization of the OID.
+    pub(crate) bytes: &'a [u8],
+}
+
+impl fmt::Display for ValueNotation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+
+        for (arc, named) in with_names(self.bytes) {
+            match named {
+                Some(named) => write!(f, " {}({})", named.name, arc)?,
+                None => write!(f, " {}", arc)?,
+            }
+        }
+
+        f.write_str(" }")
+    }
+}
+
+/// Display adapter which formats an OID as an OID-IRI, e.g.
+/// `/ISO/Member-Body/US/113549`.
+///
+/// Arcs without a well-known Unicode label are rendered as integers.
+#[derive(Copy, Clone, Debug)]
+pub struct Iri<'a> {
+    /// BER/DER serialization of the OID.
+    pub(crate) bytes: &'a [u8],
+}
+
+impl fmt::Display for Iri<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (arc, named) in with_names(self.bytes) {
+            match named.and_then(|named| named.label) {
+                Some(label) => write!(f, "/{}", label)?,
+                None => write!(f, "/{}", arc)?,
+            }
+        }
+
+        Ok(())
+    }
+}
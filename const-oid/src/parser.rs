@@ -1,6 +1,6 @@
 //! OID string parser with `const` support.
 
-use crate::{encoder::Encoder, Arc, ObjectIdentifier};
+use crate::{encoder::Encoder, Arc, Error, ObjectIdentifier, Result};
 
 /// Const-friendly OID string parser.
 ///
@@ -9,115 +9,161 @@ pub(crate) struct Parser {
     /// Current arc in progress
     current_arc: Arc,
 
+    /// Position of the current arc within the input
+    arc_start: usize,
+
     /// BER/DER encoder
     encoder: Encoder,
 }
 
 impl Parser {
     /// Parse an OID from a dot-delimited string e.g. `1.2.840.113549.1.1.1`
-    pub(crate) const fn parse(s: &str) -> Self {
+    pub(crate) const fn parse(s: &str) -> Result<ObjectIdentifier> {
         let bytes = s.as_bytes();
-        const_assert!(!bytes.is_empty(), "OID string is empty");
-        const_assert!(
-            matches!(bytes[0], b'0'..=b'9'),
-            "OID must start with a digit"
-        );
 
-        let current_arc = 0;
-        let encoder = Encoder::new();
-        Self {
-            current_arc,
-            encoder,
+        if bytes.is_empty() {
+            return Err(Error::Empty);
         }
-        .parse_bytes(bytes)
-    }
 
-    /// Finish parsing, returning the result
-    pub(crate) const fn finish(self) -> ObjectIdentifier {
-        self.encoder.finish()
-    }
+        let mut parser = Self {
+            current_arc: 0,
+            arc_start: 0,
+            encoder: Encoder::new(),
+        };
 
-    /// Parse the remaining bytes
-    const fn parse_bytes(mut self, bytes: &[u8]) -> Self {
-        match bytes {
-            [] => {
-                self.encoder = self.encoder.encode(self.current_arc);
-                self
-            }
-            [byte @ b'0'..=b'9', remaining @ ..] => {
-                self.current_arc = self.current_arc * 10 + parse_ascii_digit(*byte);
-                self.parse_bytes(remaining)
-            }
-            [b'.', remaining @ ..] => {
-                const_assert!(!remaining.is_empty(), "invalid trailing '.' in OID");
-                // const_assert!(
-                //     self.cursor < MAX_ARCS,
-                //     "maximum number of OID arcs exceeded"
-                // );
-                self.encoder = self.encoder.encode(self.current_arc);
-                self.current_arc = 0;
-                self.parse_bytes(remaining)
-            }
-            [byte, ..] => {
-                const_assert!(
-                    matches!(byte, b'0'..=b'9' | b'.'),
-                    "invalid character in OID"
-                );
-
-                // Unreachable (checked by above `const_assert!`)
-                // Needed for match exhaustiveness and matching types
-                self
+        let mut i = 0;
+
+        while i < bytes.len() {
+            match bytes[i] {
+                b'0'..=b'9' => {
+                    // Arcs can't have leading zeros
+                    if i > parser.arc_start && bytes[parser.arc_start] == b'0' {
+                        return Err(Error::Syntax { position: i });
+                    }
+
+                    let digit = (bytes[i] - b'0') as Arc;
+
+                    if parser.current_arc > (Arc::MAX - digit) / 10 {
+                        return Err(Error::ArcTooBig {
+                            position: parser.arc_start,
+                        });
+                    }
+
+                    parser.current_arc = parser.current_arc * 10 + digit;
+                }
+                b'.' => {
+                    if i == parser.arc_start {
+                        return Err(Error::Syntax { position: i });
+                    }
+
+                    parser = match parser.finish_arc() {
+                        Ok(parser) => parser,
+                        Err(err) => return Err(err),
+                    };
+
+                    parser.arc_start = i + 1;
+                }
+                _ => return Err(Error::Syntax { position: i }),
             }
+
+            i += 1;
         }
-    }
-}
 
-/// Parse a digit from an ASCII character
-// TODO(tarcieri): replace this with `byte.saturating_sub(b'0')` when MSRV 1.47+
-const fn parse_ascii_digit(char: u8) -> Arc {
-    match char {
-        b'0' => 0,
-        b'1' => 1,
-        b'2' => 2,
-        b'3' => 3,
-        b'4' => 4,
-        b'5' => 5,
-        b'6' => 6,
-        b'7' => 7,
-        b'8' => 8,
-        b'9' => 9,
-        other => {
-            const_assert!(matches!(other, b'0'..=b'9'), "invalid ASCII digit");
-            0 // Unreachable due to above `const_assert`
+        if parser.arc_start == bytes.len() {
+            return Err(Error::TrailingDot { position: i - 1 });
         }
+
+        match parser.finish_arc() {
+            Ok(parser) => parser.encoder.finish(),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Encode the current arc, resetting it for the next one
+    const fn finish_arc(mut self) -> Result<Self> {
+        self.encoder = match self.encoder.encode(self.current_arc, self.arc_start) {
+            Ok(encoder) => encoder,
+            Err(err) => return Err(err),
+        };
+
+        self.current_arc = 0;
+        Ok(self)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Parser;
+    use crate::Error;
 
     #[test]
     fn parse() {
-        let oid = Parser::parse("1.23.456").finish();
+        let oid = Parser::parse("1.23.456").unwrap();
         assert_eq!(oid, "1.23.456".parse().unwrap());
     }
 
     #[test]
-    #[should_panic]
     fn reject_empty_string() {
-        Parser::parse("");
+        assert_eq!(Parser::parse("").err(), Some(Error::Empty));
     }
 
     #[test]
-    #[should_panic]
     fn reject_non_digits() {
-        Parser::parse("X");
+        assert_eq!(
+            Parser::parse("X").err(),
+            Some(Error::Syntax { position: 0 })
+        );
+        assert_eq!(
+            Parser::parse("1.2.3a").err(),
+            Some(Error::Syntax { position: 5 })
+        );
     }
 
     #[test]
-    #[should_panic]
     fn reject_trailing_dot() {
-        Parser::parse("1.23.");
+        assert_eq!(
+            Parser::parse("1.23.").err(),
+            Some(Error::TrailingDot { position: 4 })
+        );
+    }
+
+    #[test]
+    fn reject_empty_arc() {
+        assert_eq!(
+            Parser::parse(".1.2").err(),
+            Some(Error::Syntax { position: 0 })
+        );
+        assert_eq!(
+            Parser::parse("1..2").err(),
+            Some(Error::Syntax { position: 2 })
+        );
+    }
+
+    #[test]
+    fn reject_leading_zero() {
+        assert_eq!(
+            Parser::parse("1.2.0840").err(),
+            Some(Error::Syntax { position: 5 })
+        );
+    }
+
+    #[test]
+    fn reject_invalid_root_arcs() {
+        assert_eq!(
+            Parser::parse("3.2.840").err(),
+            Some(Error::RootArc { position: 0 })
+        );
+        assert_eq!(
+            Parser::parse("1.40.840").err(),
+            Some(Error::RootArc { position: 2 })
+        );
+    }
+
+    #[test]
+    fn reject_arc_too_big() {
+        assert_eq!(
+            Parser::parse("2.25.340282366920938463463374607431768211456").err(),
+            Some(Error::ArcTooBig { position: 5 })
+        );
     }
 }
@@ -0,0 +1,107 @@
+//! Support for serializing and deserializing OIDs using [`serde`].
+//!
+//! OIDs are serialized as dotted strings (e.g. `1.2.840.113549`) when using
+//! human-readable formats like JSON or TOML, and as their BER encoding (as
+//! bytes) when using binary formats.
+
+use crate::{Error, ObjectIdentifier, Result};
+use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use core::{fmt, marker::PhantomData, str::FromStr};
+
+#[cfg(feature = "alloc")]
+use crate::ObjectIdentifierBuf;
+
+/// OID types which can be deserialized.
+trait Oid: FromStr<Err = Error> {
+    /// Parse an OID from its BER encoding.
+    fn from_ber(bytes: &[u8]) -> Result<Self>;
+}
+
+impl Oid for ObjectIdentifier {
+    fn from_ber(bytes: &[u8]) -> Result<Self> {
+        ObjectIdentifier::from_ber(bytes)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Oid for ObjectIdentifierBuf {
+    fn from_ber(bytes: &[u8]) -> Result<Self> {
+        ObjectIdentifierBuf::from_ber(bytes)
+    }
+}
+
+/// Serialize an OID's string or BER form depending on the format.
+fn serialize<S, T>(oid: &T, bytes: &[u8], serializer: S) -> core::result::Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: fmt::Display,
+{
+    if serializer.is_human_readable() {
+        serializer.collect_str(oid)
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+/// Deserialize an OID's string or BER form depending on the format.
+fn deserialize<'de, D, T>(deserializer: D) -> core::result::Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Oid,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(OidVisitor(PhantomData))
+    } else {
+        deserializer.deserialize_bytes(OidVisitor(PhantomData))
+    }
+}
+
+/// Visitor which parses and validates OIDs.
+struct OidVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Oid> de::Visitor<'de> for OidVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an OID as a dotted string or BER-encoded bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> core::result::Result<T, E> {
+        s.parse()
+            .map_err(|err| E::custom(format_args!("invalid OID: {}", err)))
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> core::result::Result<T, E> {
+        T::from_ber(bytes).map_err(|err| E::custom(format_args!("invalid OID: {}", err)))
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for ObjectIdentifier {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        serialize(self, self.as_bytes(), serializer)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ObjectIdentifier {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "serde"))))]
+impl Serialize for ObjectIdentifierBuf {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        serialize(self, self.as_bytes(), serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "serde"))))]
+impl<'de> Deserialize<'de> for ObjectIdentifierBuf {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
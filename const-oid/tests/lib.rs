@@ -3,7 +3,7 @@
 // TODO(tarcieri): test full set of OID encoding constraints specified here:
 // <https://misc.daniel-marschall.de/asn.1/oid_facts.html>
 
-use const_oid::ObjectIdentifier;
+use const_oid::{Error, ObjectIdentifier};
 use hex_literal::hex;
 use std::{convert::TryFrom, string::ToString};
 
@@ -49,6 +49,11 @@ fn from_ber() {
     // Truncated
     assert!(ObjectIdentifier::from_ber(&[42]).is_err());
     assert!(ObjectIdentifier::from_ber(&[42, 134]).is_err());
+
+    // Arcs of 4 and 5 bytes
+    let oid3 = ObjectIdentifier::from_ber(&hex!("2A 81 80 80 7F 8F FF FF FF 7F")).unwrap();
+    assert_eq!(oid3.arc(2).unwrap(), 0x20_007f);
+    assert_eq!(oid3.arc(3).unwrap(), u32::MAX.into());
 }
 
 #[test]
@@ -96,6 +101,25 @@ fn try_from_u32_slice() {
 
     // Invalid second arc
     assert!(ObjectIdentifier::try_from([1, 40, 840, 10045, 3, 1, 7].as_ref()).is_err());
+
+    // Arcs which are multiples of 128
+    let oid3 = ObjectIdentifier::try_from([1, 2, 128, 16384].as_ref()).unwrap();
+    assert_eq!(oid3.as_bytes(), &hex!("2A 81 00 81 80 00"));
+    assert_eq!(oid3, ObjectIdentifier::new(&[1, 2, 128, 16384]));
+
+    // Arcs which need 5 bytes of base 128
+    let oid4 = ObjectIdentifier::try_from([1, 2, 0x1000_0000].as_ref()).unwrap();
+    assert_eq!(oid4.as_bytes(), &hex!("2A 81 80 80 80 00"));
+
+    // Too long
+    assert!(ObjectIdentifier::try_from(
+        [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25,
+        ]
+        .as_ref()
+    )
+    .is_err());
 }
 
 #[test]
@@ -151,3 +175,626 @@ fn parse_invalid_first_arc() {
 fn parse_invalid_second_arc() {
     ObjectIdentifier::parse("1.40.840.10045.3.1.7");
 }
+
+/// UUID-based OID from ITU X.667 (requires 128-bit arcs)
+const UUID_OID: ObjectIdentifier =
+    ObjectIdentifier::parse("2.25.329800735698586629295641978511506172918");
+
+/// UUID-based OID encoded as ASN.1 BER/DER
+const UUID_OID_BER: &[u8] = &hex!("6983F09DA7EBCFDEE0C7A1A7B2C0948CC8F9D776");
+
+#[test]
+fn large_arcs() {
+    assert_eq!(UUID_OID.as_bytes(), UUID_OID_BER);
+    assert_eq!(
+        UUID_OID.arc(2).unwrap(),
+        329800735698586629295641978511506172918
+    );
+    assert_eq!(
+        UUID_OID.to_string(),
+        "2.25.329800735698586629295641978511506172918"
+    );
+
+    assert_eq!(ObjectIdentifier::from_ber(UUID_OID_BER).unwrap(), UUID_OID);
+    assert_eq!(
+        "2.25.329800735698586629295641978511506172918"
+            .parse::<ObjectIdentifier>()
+            .unwrap(),
+        UUID_OID
+    );
+
+    let max_arc = ObjectIdentifier::new(&[2, 25, u128::MAX]);
+    assert_eq!(
+        max_arc.as_bytes(),
+        &hex!("6983FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF7F")
+    );
+    assert_eq!(max_arc.arc(2).unwrap(), u128::MAX);
+    assert_eq!(
+        ObjectIdentifier::from_ber(max_arc.as_bytes()).unwrap(),
+        max_arc
+    );
+
+    // Overflows `u128`
+    assert!(ObjectIdentifier::from_ber(&hex!("6984808080808080808080808080808080808000")).is_err());
+    assert!("2.25.340282366920938463463374607431768211456"
+        .parse::<ObjectIdentifier>()
+        .is_err());
+}
+
+#[test]
+fn from_ber_rejects_non_minimal_arcs() {
+    assert!(ObjectIdentifier::from_ber(&hex!("2A 80 01")).is_err());
+    assert!(ObjectIdentifier::from_ber(&hex!("2A 01 80 7F")).is_err());
+}
+
+#[test]
+fn from_ber_errors() {
+    let err = |ber: &[u8]| ObjectIdentifier::from_ber(ber).err().unwrap();
+
+    assert_eq!(err(&[]), Error::Empty);
+    assert_eq!(err(&[42]), Error::TooShort);
+    assert_eq!(err(&[120, 1]), Error::RootArc { position: 0 });
+    assert_eq!(err(&hex!("2A 01 80 7F")), Error::NonMinimal { position: 2 });
+    assert_eq!(err(&hex!("2A 01 86")), Error::Truncated { position: 2 });
+    assert_eq!(
+        err(&hex!("6984808080808080808080808080808080808000")),
+        Error::ArcTooBig { position: 1 }
+    );
+    assert_eq!(err(&[42; 24]), Error::TooLong);
+}
+
+#[test]
+fn from_str_errors() {
+    let err = |s: &str| s.parse::<ObjectIdentifier>().err().unwrap();
+
+    assert_eq!(err(""), Error::Empty);
+    assert_eq!(err("1.2"), Error::TooShort);
+    assert_eq!(err("3.2.840"), Error::RootArc { position: 0 });
+    assert_eq!(err("1.40.840"), Error::RootArc { position: 2 });
+    assert_eq!(err("1.2.840."), Error::TrailingDot { position: 7 });
+    assert_eq!(err("1.2..840"), Error::Syntax { position: 4 });
+    assert_eq!(err("1.2.8x0"), Error::Syntax { position: 5 });
+    assert_eq!(
+        err("2.25.340282366920938463463374607431768211456"),
+        Error::ArcTooBig { position: 5 }
+    );
+    assert_eq!(
+        err("1.2.3.4.5.6.7.8.9.10.11.12.13.14.15.16.17.18.19.20.21.22.23.24.25"),
+        Error::TooLong
+    );
+
+    assert_eq!(err("1.2.840.").position(), Some(7));
+    assert_eq!(err("1.2").position(), None);
+    assert_eq!(
+        err("1.40.840").to_string(),
+        "invalid root arc at position 2 (first arc must be 0-2, second 0-39)"
+    );
+}
+
+#[test]
+fn try_from_slice_errors() {
+    let err = |arcs: &[u128]| ObjectIdentifier::try_from(arcs).err().unwrap();
+
+    assert_eq!(err(&[1, 2]), Error::TooShort);
+    assert_eq!(err(&[3, 2, 840]), Error::RootArc { position: 0 });
+    assert_eq!(err(&[1, 40, 840]), Error::RootArc { position: 1 });
+}
+
+#[test]
+fn notation_errors() {
+    assert_eq!(
+        ObjectIdentifier::from_urn("urn:uuid:1.2.840").err(),
+        Some(Error::Syntax { position: 4 })
+    );
+    assert_eq!(
+        ObjectIdentifier::from_urn("urn:oid:1.2.840.").err(),
+        Some(Error::TrailingDot { position: 15 })
+    );
+    assert_eq!(
+        ObjectIdentifier::from_urn("urn:oid:1.40.840").err(),
+        Some(Error::RootArc { position: 10 })
+    );
+    assert_eq!(
+        ObjectIdentifier::from_value_notation("{ iso(1) bogus 2 }").err(),
+        Some(Error::Syntax { position: 9 })
+    );
+    assert_eq!(
+        ObjectIdentifier::from_value_notation("{ 1 2 840").err(),
+        Some(Error::Syntax { position: 9 })
+    );
+    assert_eq!(
+        ObjectIdentifier::from_iri("/ISO/Bogus/840").err(),
+        Some(Error::Syntax { position: 5 })
+    );
+    assert_eq!(ObjectIdentifier::from_iri("").err(), Some(Error::Empty));
+}
+
+#[test]
+fn max_len() {
+    let arcs = [
+        1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+    ];
+    let oid = ObjectIdentifier::try_from(arcs.as_ref()).unwrap();
+    assert_eq!(oid.as_bytes().len(), const_oid::MAX_LEN);
+    assert_eq!(oid, ObjectIdentifier::new(&arcs));
+    assert_eq!(ObjectIdentifier::from_ber(oid.as_bytes()).unwrap(), oid);
+}
+
+#[test]
+fn parent() {
+    let parent = EXAMPLE_OID_1.parent().unwrap();
+    assert_eq!(parent, ObjectIdentifier::parse("1.2.840.10045.2"));
+    assert_eq!(parent.as_bytes(), &EXAMPLE_OID_1_BER[..6]);
+
+    let oid = ObjectIdentifier::parse("1.2.840.113549");
+    assert_eq!(oid.parent().unwrap(), ObjectIdentifier::parse("1.2.840"));
+    assert_eq!(ObjectIdentifier::parse("1.2.840").parent(), None);
+
+    // Parent of a child compares (and hashes) equal to the original OID
+    assert_eq!(EXAMPLE_OID_1.child(128).parent().unwrap(), EXAMPLE_OID_1);
+}
+
+#[test]
+fn child() {
+    const ID_CE: ObjectIdentifier = ObjectIdentifier::parse("2.5.29");
+    const ID_CE_KEY_USAGE: ObjectIdentifier = ID_CE.child(15);
+    assert_eq!(ID_CE_KEY_USAGE, ObjectIdentifier::parse("2.5.29.15"));
+
+    assert_eq!(
+        ObjectIdentifier::parse("1.2.840.10045.2").child(1),
+        EXAMPLE_OID_1
+    );
+    assert_eq!(ID_CE.child(16384).as_bytes(), &hex!("55 1D 81 80 00"));
+}
+
+#[test]
+#[should_panic]
+fn child_too_long() {
+    let mut oid = ObjectIdentifier::parse("1.2.3");
+
+    for _ in 0..const_oid::MAX_LEN {
+        oid = oid.child(1);
+    }
+}
+
+#[test]
+fn push_arc() {
+    let mut oid = ObjectIdentifier::parse("1.2.840.10045");
+    oid.push_arc(2).unwrap();
+    oid.push_arc(1).unwrap();
+    assert_eq!(oid, EXAMPLE_OID_1);
+
+    let mut oid = UUID_OID;
+    oid.push_arc(1).unwrap();
+    oid.push_arc(2).unwrap();
+    assert!(oid.push_arc(300).is_err());
+    assert_eq!(oid.to_string(), format!("{}.1.2", UUID_OID));
+    oid.push_arc(3).unwrap();
+    assert_eq!(oid.as_bytes().len(), const_oid::MAX_LEN);
+    assert!(oid.push_arc(0).is_err());
+}
+
+#[test]
+fn starts_with() {
+    let pkcs9 = ObjectIdentifier::parse("1.2.840.113549.1.9");
+    let oid = ObjectIdentifier::parse("1.2.840.113549.1.9.16.2.14");
+
+    assert!(oid.starts_with(&pkcs9));
+    assert!(oid.starts_with(&oid));
+    assert!(!pkcs9.starts_with(&oid));
+
+    // Byte prefix of an arc isn't an arc prefix
+    assert!(!ObjectIdentifier::parse("1.2.840").starts_with(&ObjectIdentifier::parse("1.2.6")));
+    assert!(!ObjectIdentifier::parse("1.2.8").starts_with(&ObjectIdentifier::parse("1.2.840")));
+    assert!(!EXAMPLE_OID_2.starts_with(&EXAMPLE_OID_1));
+}
+
+#[test]
+fn strip_prefix() {
+    let pkcs9 = ObjectIdentifier::parse("1.2.840.113549.1.9");
+    let oid = ObjectIdentifier::parse("1.2.840.113549.1.9.16.2.14");
+
+    let arcs = oid.strip_prefix(&pkcs9).unwrap();
+    assert_eq!(arcs.collect::<Vec<_>>(), [16, 2, 14]);
+    assert_eq!(oid.strip_prefix(&oid).unwrap().count(), 0);
+    assert!(pkcs9.strip_prefix(&oid).is_none());
+    assert!(EXAMPLE_OID_1.strip_prefix(&pkcs9).is_none());
+}
+
+#[test]
+fn ordering() {
+    let mut oids = [
+        ObjectIdentifier::parse("1.2.3.16384"),
+        ObjectIdentifier::parse("2.5.4"),
+        ObjectIdentifier::parse("1.2.3.16383"),
+        ObjectIdentifier::parse("1.2.3.128.1"),
+        ObjectIdentifier::parse("1.2.3"),
+        ObjectIdentifier::parse("1.2.3.127"),
+        ObjectIdentifier::parse("0.9.2342"),
+        ObjectIdentifier::parse("1.2.3.128"),
+    ];
+
+    oids.sort();
+
+    assert_eq!(
+        oids.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        [
+            "0.9.2342",
+            "1.2.3",
+            "1.2.3.127",
+            "1.2.3.128",
+            "1.2.3.128.1",
+            "1.2.3.16383",
+            "1.2.3.16384",
+            "2.5.4",
+        ]
+    );
+}
+
+#[test]
+fn urn() {
+    assert_eq!(EXAMPLE_OID_1.urn().to_string(), "urn:oid:1.2.840.10045.2.1");
+    assert_eq!(
+        ObjectIdentifier::from_urn("urn:oid:1.2.840.10045.2.1").unwrap(),
+        EXAMPLE_OID_1
+    );
+    assert_eq!(
+        ObjectIdentifier::from_urn("URN:OID:2.16.840.1.101.3.4.1.42").unwrap(),
+        EXAMPLE_OID_2
+    );
+
+    assert!(ObjectIdentifier::from_urn("1.2.840.10045.2.1").is_err());
+    assert!(ObjectIdentifier::from_urn("urn:oid:").is_err());
+    assert!(ObjectIdentifier::from_urn("urn:oid:1.2").is_err());
+    assert!(ObjectIdentifier::from_urn("urn:oid:1.2.840.").is_err());
+    assert!(ObjectIdentifier::from_urn("urn:oid:1.2.0840").is_err());
+    assert!(ObjectIdentifier::from_urn("urn:uuid:1.2.840").is_err());
+}
+
+#[test]
+fn value_notation() {
+    let pkcs9 = ObjectIdentifier::parse("1.2.840.113549.1.9.16");
+
+    assert_eq!(
+        pkcs9.value_notation().to_string(),
+        "{ iso(1) member-body(2) us(840) rsadsi(113549) pkcs(1) pkcs-9(9) 16 }"
+    );
+    assert_eq!(
+        EXAMPLE_OID_2.value_notation().to_string(),
+        "{ joint-iso-itu-t(2) country(16) us(840) organization(1) gov(101) csor(3) \
+         nistAlgorithm(4) aes(1) 42 }"
+    );
+
+    for s in &[
+        "{ iso(1) member-body(2) us(840) rsadsi(113549) pkcs(1) pkcs-9(9) 16 }",
+        "{iso(1) member-body(2) us(840) rsadsi(113549) pkcs(1) pkcs-9(9) 16}",
+        "{ 1 2 840 113549 1 9 16 }",
+        "{ iso member-body us rsadsi pkcs pkcs-9 16 }",
+        "{\n  iso (1)\n  member-body ( 2 ) us(840) 113549 1 9 16\n}",
+    ] {
+        assert_eq!(
+            ObjectIdentifier::from_value_notation(s).unwrap(),
+            pkcs9,
+            "{}",
+            s
+        );
+    }
+
+    // Names in name and number form aren't checked against the table
+    assert_eq!(
+        ObjectIdentifier::from_value_notation("{ joint-iso-ccitt(2) ds(5) 29 }").unwrap(),
+        ObjectIdentifier::parse("2.5.29")
+    );
+
+    for s in &[
+        "",
+        "{}",
+        "{ 1 2 }",
+        "1 2 840",
+        "{ 1 2 840",
+        "{ 1 2 840 } 1",
+        "{ iso(1) member-body(2) us(840) unknown }",
+        "{ iso() 2 840 }",
+        "{ iso(1 2 840 }",
+        "{ 1 2 840 ; }",
+        "{ 1 2 0840 }",
+    ] {
+        assert!(ObjectIdentifier::from_value_notation(s).is_err(), "{}", s);
+    }
+}
+
+#[cfg(feature = "db")]
+#[test]
+fn value_notation_with_reference() {
+    assert_eq!(
+        ObjectIdentifier::from_value_notation("{ id-ce 15 }").unwrap(),
+        ObjectIdentifier::parse("2.5.29.15")
+    );
+    assert_eq!(
+        ObjectIdentifier::from_value_notation("{ id-pkix 3 }").unwrap(),
+        ObjectIdentifier::parse("1.3.6.1.5.5.7.3")
+    );
+
+    // References are only allowed as the first component
+    assert!(ObjectIdentifier::from_value_notation("{ 1 id-ce 15 }").is_err());
+}
+
+#[test]
+fn iri() {
+    let oid = ObjectIdentifier::parse("1.2.840.113549");
+    assert_eq!(oid.iri().to_string(), "/ISO/Member-Body/US/113549");
+    assert_eq!(
+        ObjectIdentifier::from_iri("/ISO/Member-Body/US/113549").unwrap(),
+        oid
+    );
+    assert_eq!(
+        ObjectIdentifier::from_iri("/iso/member-body/us/113549").unwrap(),
+        oid
+    );
+
+    assert_eq!(
+        UUID_OID.iri().to_string(),
+        "/Joint-ISO-ITU-T/UUID/329800735698586629295641978511506172918"
+    );
+    assert_eq!(
+        ObjectIdentifier::from_iri("/Joint-ISO-ITU-T/UUID/329800735698586629295641978511506172918")
+            .unwrap(),
+        UUID_OID
+    );
+    assert_eq!(
+        EXAMPLE_OID_2.iri().to_string(),
+        "/Joint-ISO-ITU-T/Country/US/1/101/3/4/1/42"
+    );
+
+    for s in &[
+        "",
+        "/",
+        "/ISO/Member-Body",
+        "ISO/Member-Body/US",
+        "/ISO/Member-Body/US/",
+        "/ISO/Country/US",
+        "/ISO/Member-Body/RSADSI",
+        "/1/2/0840",
+    ] {
+        assert!(ObjectIdentifier::from_iri(s).is_err(), "{}", s);
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod buf {
+    use super::{EXAMPLE_OID_1, EXAMPLE_OID_1_BER, EXAMPLE_OID_1_STRING, UUID_OID};
+    use const_oid::{Error, ObjectIdentifier, ObjectIdentifierBuf};
+    use std::{convert::TryFrom, string::ToString, vec::Vec};
+
+    /// Long OID which exceeds `MAX_LEN` when BER-encoded.
+    const LONG_OID_STRING: &str =
+        "1.3.6.1.4.1.311.21.8.11111111.22222222.33333333.44444444.55555555.66666666";
+
+    #[test]
+    fn from_str() {
+        let oid = EXAMPLE_OID_1_STRING.parse::<ObjectIdentifierBuf>().unwrap();
+        assert_eq!(oid.as_bytes(), EXAMPLE_OID_1_BER);
+        assert_eq!(oid.to_string(), EXAMPLE_OID_1_STRING);
+
+        assert!("1.2".parse::<ObjectIdentifierBuf>().is_err());
+        assert!("1.2.840.".parse::<ObjectIdentifierBuf>().is_err());
+        assert!("3.2.840".parse::<ObjectIdentifierBuf>().is_err());
+        assert_eq!(
+            "1.2.840.".parse::<ObjectIdentifierBuf>().err(),
+            Some(Error::TrailingDot { position: 7 })
+        );
+    }
+
+    #[test]
+    fn long_oid() {
+        let oid = LONG_OID_STRING.parse::<ObjectIdentifierBuf>().unwrap();
+        assert!(oid.as_bytes().len() > const_oid::MAX_LEN);
+        assert_eq!(oid.to_string(), LONG_OID_STRING);
+        assert_eq!(oid.len(), 15);
+        assert_eq!(oid.arc(14).unwrap(), 66666666);
+
+        assert!(ObjectIdentifier::try_from(&oid).is_err());
+        assert!(ObjectIdentifier::from_ber(oid.as_bytes()).is_err());
+        assert_eq!(ObjectIdentifierBuf::from_ber(oid.as_bytes()).unwrap(), oid);
+
+        let arcs = oid.arcs().collect::<Vec<_>>();
+        assert_eq!(ObjectIdentifierBuf::try_from(arcs.as_slice()).unwrap(), oid);
+    }
+
+    #[test]
+    fn notations() {
+        let oid = LONG_OID_STRING.parse::<ObjectIdentifierBuf>().unwrap();
+
+        let urn = oid.urn().to_string();
+        assert_eq!(urn, format!("urn:oid:{}", LONG_OID_STRING));
+        assert_eq!(ObjectIdentifierBuf::from_urn(&urn).unwrap(), oid);
+
+        let value = oid.value_notation().to_string();
+        assert!(value.starts_with("{ iso(1) identified-organization(3) dod(6) internet(1)"));
+        assert_eq!(
+            ObjectIdentifierBuf::from_value_notation(&value).unwrap(),
+            oid
+        );
+
+        let iri = oid.iri().to_string();
+        assert!(iri.starts_with("/ISO/Identified-Organization/6/1/4/1/311/"));
+        assert_eq!(ObjectIdentifierBuf::from_iri(&iri).unwrap(), oid);
+    }
+
+    #[test]
+    fn conversions() {
+        let oid = ObjectIdentifierBuf::from(EXAMPLE_OID_1);
+        assert_eq!(oid.as_bytes(), EXAMPLE_OID_1_BER);
+        assert_eq!(ObjectIdentifier::try_from(oid).unwrap(), EXAMPLE_OID_1);
+
+        let oid = ObjectIdentifierBuf::from(&UUID_OID);
+        assert_eq!(ObjectIdentifier::try_from(&oid).unwrap(), UUID_OID);
+    }
+
+    #[test]
+    fn from_ber_rejects_malformed() {
+        assert!(ObjectIdentifierBuf::from_ber(&[]).is_err());
+        assert!(ObjectIdentifierBuf::from_ber(&[42]).is_err());
+        assert!(ObjectIdentifierBuf::from_ber(&[42, 134]).is_err());
+        assert!(ObjectIdentifierBuf::from_ber(&[42, 0x80, 1]).is_err());
+    }
+}
+
+#[cfg(feature = "db")]
+mod db {
+    use super::{EXAMPLE_OID_1, EXAMPLE_OID_2};
+    use const_oid::{db, ObjectIdentifier};
+
+    #[test]
+    fn by_name() {
+        assert_eq!(db::by_name("id-ecPublicKey"), Some(&EXAMPLE_OID_1));
+        assert_eq!(db::by_name("id-aes256-CBC"), Some(&EXAMPLE_OID_2));
+        assert_eq!(db::by_name("id-ecpublickey"), None);
+        assert_eq!(db::by_name(""), None);
+    }
+
+    #[test]
+    fn name_of() {
+        assert_eq!(db::name_of(&EXAMPLE_OID_1), Some("id-ecPublicKey"));
+        assert_eq!(db::name_of(&EXAMPLE_OID_2), Some("id-aes256-CBC"));
+        assert_eq!(
+            db::name_of(&ObjectIdentifier::parse("1.3.6.1.4.1.311.21.8")),
+            None
+        );
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(db::rfc5912::ID_EC_PUBLIC_KEY, EXAMPLE_OID_1);
+        assert_eq!(db::nist::ID_AES_256_CBC, EXAMPLE_OID_2);
+        assert_eq!(
+            db::rfc8410::ID_ED_25519,
+            ObjectIdentifier::parse("1.3.101.112")
+        );
+    }
+}
+
+mod map {
+    use super::{EXAMPLE_OID_1, EXAMPLE_OID_2};
+    use const_oid::{oid_map, oid_set, ObjectIdentifier, OidMap, OidSet};
+
+    const ID_CE: ObjectIdentifier = ObjectIdentifier::parse("2.5.29");
+
+    oid_map! {
+        const NAMES: OidMap<&str> = {
+            EXAMPLE_OID_2 => "aes256-CBC",
+            ID_CE.child(15) => "keyUsage",
+            EXAMPLE_OID_1 => "ecPublicKey",
+            ID_CE => "id-ce",
+        };
+    }
+
+    oid_map! {
+        const EMPTY_MAP: OidMap<u8> = {};
+    }
+
+    oid_set! {
+        pub(crate) const SET: OidSet = {
+            ObjectIdentifier::parse("1.2.16384"),
+            ObjectIdentifier::parse("1.2.16383"),
+            ObjectIdentifier::parse("1.2.3"),
+        };
+    }
+
+    oid_set! {
+        const EMPTY_SET: OidSet = {};
+    }
+
+    #[test]
+    fn map_get() {
+        assert_eq!(NAMES.len(), 4);
+        assert_eq!(NAMES.get(&EXAMPLE_OID_1), Some(&"ecPublicKey"));
+        assert_eq!(NAMES.get(&EXAMPLE_OID_2), Some(&"aes256-CBC"));
+        assert_eq!(NAMES.get(&ID_CE.child(15)), Some(&"keyUsage"));
+        assert_eq!(NAMES.get(&ID_CE.child(14)), None);
+        assert!(NAMES.contains_key(&ID_CE));
+        assert_eq!(NAMES.key_of(&"keyUsage"), Some(&ID_CE.child(15)));
+        assert_eq!(NAMES.key_of(&"bogus"), None);
+
+        assert!(EMPTY_MAP.is_empty());
+        assert_eq!(EMPTY_MAP.get(&EXAMPLE_OID_1), None);
+    }
+
+    #[test]
+    fn map_is_sorted() {
+        let keys = NAMES.iter().map(|(oid, _)| *oid).collect::<Vec<_>>();
+        assert_eq!(keys, [EXAMPLE_OID_1, ID_CE, ID_CE.child(15), EXAMPLE_OID_2]);
+    }
+
+    #[test]
+    fn set_contains() {
+        assert_eq!(SET.len(), 3);
+        assert!(SET.contains(&ObjectIdentifier::parse("1.2.16383")));
+        assert!(SET.contains(&ObjectIdentifier::parse("1.2.16384")));
+        assert!(!SET.contains(&ObjectIdentifier::parse("1.2.16385")));
+        assert!(!EMPTY_SET.contains(&EXAMPLE_OID_1));
+
+        let oids = SET.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(oids, ["1.2.3", "1.2.16383", "1.2.16384"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn map_new_unsorted() {
+        OidMap::new(&[(EXAMPLE_OID_2, 2), (EXAMPLE_OID_1, 1)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_new_duplicates() {
+        OidSet::new(&[EXAMPLE_OID_1, EXAMPLE_OID_1]);
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::{EXAMPLE_OID_1, EXAMPLE_OID_1_BER, EXAMPLE_OID_1_STRING};
+    use const_oid::ObjectIdentifier;
+    use serde_test::{assert_de_tokens_error, assert_tokens, Configure, Token};
+
+    #[test]
+    fn human_readable() {
+        assert_tokens(
+            &EXAMPLE_OID_1.readable(),
+            &[Token::Str(EXAMPLE_OID_1_STRING)],
+        );
+    }
+
+    #[test]
+    fn binary() {
+        assert_tokens(&EXAMPLE_OID_1.compact(), &[Token::Bytes(EXAMPLE_OID_1_BER)]);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_de_tokens_error::<serde_test::Readable<ObjectIdentifier>>(
+            &[Token::Str("1.2.840.")],
+            "invalid OID: trailing '.' at position 7",
+        );
+
+        assert_de_tokens_error::<serde_test::Compact<ObjectIdentifier>>(
+            &[Token::Bytes(&[0x2A, 0x80, 0x01])],
+            "invalid OID: non-minimal base 128 encoding of arc at position 1",
+        );
+
+        assert_de_tokens_error::<serde_test::Readable<ObjectIdentifier>>(
+            &[Token::U32(1)],
+            "invalid type: integer `1`, expected an OID as a dotted string or BER-encoded bytes",
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn buf() {
+        use const_oid::ObjectIdentifierBuf;
+
+        let oid = ObjectIdentifierBuf::from(EXAMPLE_OID_1);
+        assert_tokens(&oid.clone().readable(), &[Token::Str(EXAMPLE_OID_1_STRING)]);
+        assert_tokens(&oid.compact(), &[Token::Bytes(EXAMPLE_OID_1_BER)]);
+    }
+}
@@ -0,0 +1,76 @@
+#![feature(test)]
+extern crate test;
+use test::Bencher;
+
+use core::convert::TryFrom;
+use der::{Any, Encodable, Encoder, Error, ErrorKind, Message, OctetString};
+
+/// Nesting depth of the benchmarked messages.
+const DEPTH: usize = 64;
+
+/// Message containing a payload and (optionally) a nested message, i.e.
+/// `Node ::= SEQUENCE { payload OCTET STRING, child Node OPTIONAL }`.
+struct Node<'a> {
+    payload: OctetString<'a>,
+    child: Option<&'a Node<'a>>,
+}
+
+impl<'a> TryFrom<Any<'a>> for Node<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Node<'a>, Error> {
+        // Decoding isn't benchmarked
+        Err(ErrorKind::Value { tag: any.tag() }.into())
+    }
+}
+
+impl<'a> Message<'a> for Node<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> der::Result<T>,
+    {
+        match self.child {
+            Some(child) => f(&[&self.payload, child]),
+            None => f(&[&self.payload]),
+        }
+    }
+}
+
+/// Build a chain of nested messages of the given depth on the stack, and call
+/// the provided function with the outermost one.
+fn with_nested<R>(depth: usize, child: Option<&Node<'_>>, f: impl FnOnce(&Node<'_>) -> R) -> R {
+    let node = Node {
+        payload: OctetString::new(&[0x42; 32]).unwrap(),
+        child,
+    };
+
+    if depth == 1 {
+        f(&node)
+    } else {
+        with_nested(depth - 1, Some(&node), f)
+    }
+}
+
+#[bench]
+fn encode_nested_forward(b: &mut Bencher) {
+    let mut buf = [0u8; 4096];
+
+    with_nested(DEPTH, None, |node| {
+        b.iter(|| {
+            let mut encoder = Encoder::new(&mut buf);
+            node.encode(&mut encoder).unwrap();
+            test::black_box(encoder.finish().unwrap());
+        })
+    });
+}
+
+#[bench]
+fn encode_nested_reverse(b: &mut Bencher) {
+    let mut buf = [0u8; 4096];
+
+    with_nested(DEPTH, None, |node| {
+        b.iter(|| {
+            test::black_box(node.encode_to_slice(&mut buf).unwrap());
+        })
+    });
+}
@@ -0,0 +1,332 @@
+//! Abstract syntax tree for the supported subset of X.680 module syntax.
+
+use std::fmt;
+
+/// ASN.1 module definition.
+#[derive(Clone, Debug)]
+pub(crate) struct Module {
+    /// Module reference (i.e. name).
+    pub name: String,
+
+    /// Default tagging mode for tagged types in this module.
+    pub tag_default: TagDefault,
+
+    /// Type and value assignments, in the order they're defined.
+    pub assignments: Vec<Assignment>,
+}
+
+/// Default tagging mode of a module.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum TagDefault {
+    /// `EXPLICIT TAGS` (also the default when unspecified).
+    Explicit,
+
+    /// `IMPLICIT TAGS`
+    Implicit,
+
+    /// `AUTOMATIC TAGS`
+    Automatic,
+}
+
+/// Type or value assignment.
+#[derive(Clone, Debug)]
+pub(crate) struct Assignment {
+    /// Type or value reference being assigned.
+    pub name: String,
+
+    /// Type being assigned (type assignments), or the type of the value
+    /// being assigned (value assignments).
+    pub ty: Type,
+
+    /// Value being assigned, for value assignments.
+    pub value: Option<Value>,
+
+    /// ASN.1 source of the assignment.
+    pub source: String,
+}
+
+/// ASN.1 type along with its constraints.
+#[derive(Clone, Debug)]
+pub(crate) struct Type {
+    /// Kind of type.
+    pub kind: TypeKind,
+
+    /// Constraints on the type's values.
+    pub constraint: Constraint,
+}
+
+impl Type {
+    /// Create an unconstrained type.
+    pub fn new(kind: TypeKind) -> Self {
+        Self {
+            kind,
+            constraint: Constraint::default(),
+        }
+    }
+}
+
+/// Kinds of ASN.1 types.
+#[derive(Clone, Debug)]
+pub(crate) enum TypeKind {
+    /// `ANY` (optionally `DEFINED BY` another component).
+    Any,
+
+    /// `BIT STRING`
+    BitString,
+
+    /// `BOOLEAN`
+    Boolean,
+
+    /// `CHOICE`
+    Choice(Components),
+
+    /// `ENUMERATED`
+    Enumerated,
+
+    /// `GeneralizedTime`
+    GeneralizedTime,
+
+    /// `INTEGER`, along with any named numbers.
+    Integer(Vec<NamedNumber>),
+
+    /// `NULL`
+    Null,
+
+    /// `OBJECT IDENTIFIER`
+    ObjectIdentifier,
+
+    /// `OCTET STRING`
+    OctetString,
+
+    /// Reference to a type assigned in this or another module.
+    Reference(String),
+
+    /// `SEQUENCE`
+    Sequence(Components),
+
+    /// `SEQUENCE OF`
+    SequenceOf(Box<Type>),
+
+    /// `SET`
+    Set,
+
+    /// `SET OF`
+    SetOf,
+
+    /// Character string type, e.g. `UTF8String`.
+    String(StringType),
+
+    /// Tagged type.
+    Tagged {
+        /// Tag.
+        tag: Tag,
+
+        /// Tagging mode, if specified.
+        mode: Option<TagMode>,
+
+        /// Type being tagged.
+        inner: Box<Type>,
+    },
+
+    /// `UTCTime`
+    UtcTime,
+}
+
+/// Character string types.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum StringType {
+    /// `BMPString`
+    Bmp,
+
+    /// `GeneralString`
+    General,
+
+    /// `GraphicString`
+    Graphic,
+
+    /// `IA5String`
+    Ia5,
+
+    /// `NumericString`
+    Numeric,
+
+    /// `PrintableString`
+    Printable,
+
+    /// `TeletexString` or `T61String`
+    Teletex,
+
+    /// `UniversalString`
+    Universal,
+
+    /// `UTF8String`
+    Utf8,
+
+    /// `VisibleString` or `ISO646String`
+    Visible,
+}
+
+impl StringType {
+    /// Parse a character string type keyword.
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        Some(match keyword {
+            "BMPString" => StringType::Bmp,
+            "GeneralString" => StringType::General,
+            "GraphicString" => StringType::Graphic,
+            "IA5String" => StringType::Ia5,
+            "NumericString" => StringType::Numeric,
+            "PrintableString" => StringType::Printable,
+            "TeletexString" | "T61String" => StringType::Teletex,
+            "UniversalString" => StringType::Universal,
+            "UTF8String" => StringType::Utf8,
+            "VisibleString" | "ISO646String" => StringType::Visible,
+            _ => return None,
+        })
+    }
+}
+
+/// Components of a `SEQUENCE`, `SET` or `CHOICE`.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Components {
+    /// Named component types.
+    pub components: Vec<Component>,
+
+    /// Does the type have an extension marker (`...`)?
+    pub extensible: bool,
+}
+
+/// Component of a `SEQUENCE`, `SET` or `CHOICE`.
+#[derive(Clone, Debug)]
+pub(crate) struct Component {
+    /// Identifier of the component.
+    pub name: String,
+
+    /// Type of the component.
+    pub ty: Type,
+
+    /// Is this component `OPTIONAL` or does it have a `DEFAULT`?
+    pub presence: Presence,
+
+    /// ASN.1 source of the component.
+    pub source: String,
+}
+
+/// Presence of a component.
+#[derive(Clone, Debug)]
+pub(crate) enum Presence {
+    /// Always present.
+    Required,
+
+    /// `OPTIONAL`
+    Optional,
+
+    /// `DEFAULT` with the given value.
+    Default(Value),
+}
+
+/// Named number of an `INTEGER`.
+#[derive(Clone, Debug)]
+pub(crate) struct NamedNumber {
+    /// Identifier.
+    pub name: String,
+
+    /// Value.
+    pub value: Value,
+}
+
+/// ASN.1 tag.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Tag {
+    /// Tag class.
+    pub class: TagClass,
+
+    /// Tag number.
+    pub number: u128,
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.class {
+            TagClass::Universal => write!(f, "[UNIVERSAL {}]", self.number),
+            TagClass::Application => write!(f, "[APPLICATION {}]", self.number),
+            TagClass::ContextSpecific => write!(f, "[{}]", self.number),
+            TagClass::Private => write!(f, "[PRIVATE {}]", self.number),
+        }
+    }
+}
+
+/// Tag classes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum TagClass {
+    /// `UNIVERSAL`
+    Universal,
+
+    /// `APPLICATION`
+    Application,
+
+    /// Context-specific (no class keyword).
+    ContextSpecific,
+
+    /// `PRIVATE`
+    Private,
+}
+
+/// Tagging modes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum TagMode {
+    /// `EXPLICIT`
+    Explicit,
+
+    /// `IMPLICIT`
+    Implicit,
+}
+
+/// Subtype constraints.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Constraint {
+    /// Permitted range of values (e.g. `(0..255)`).
+    pub value: Option<Range>,
+
+    /// Permitted range of sizes (e.g. `(SIZE (1..MAX))`).
+    pub size: Option<Range>,
+}
+
+/// Range of values (lower and upper bound, inclusive).
+#[derive(Clone, Debug)]
+pub(crate) struct Range {
+    /// Lower bound (`None` for `MIN`).
+    pub lower: Option<Value>,
+
+    /// Upper bound (`None` for `MAX`).
+    pub upper: Option<Value>,
+}
+
+/// ASN.1 value.
+#[derive(Clone, Debug)]
+pub(crate) enum Value {
+    /// `TRUE` or `FALSE`
+    Boolean(bool),
+
+    /// Integer.
+    Integer(i128),
+
+    /// Object identifier components.
+    ObjectIdentifier(Vec<OidComponent>),
+
+    /// Reference to a value (or a named number).
+    Reference(String),
+
+    /// Any other value.
+    Other,
+}
+
+/// Component of an object identifier value.
+#[derive(Clone, Debug)]
+pub(crate) enum OidComponent {
+    /// Name (e.g. `iso`), which may also be a reference to another
+    /// object identifier value.
+    Name(String),
+
+    /// Number (e.g. `1` or `iso(1)`).
+    Number(u128),
+}
@@ -0,0 +1,84 @@
+//! Error types.
+
+use std::{fmt, io};
+
+/// Result type.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Error type.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// I/O error reading an ASN.1 module or writing generated code.
+    Io(io::Error),
+
+    /// Syntax error in an ASN.1 module.
+    Syntax {
+        /// Line number (starting from 1).
+        line: usize,
+
+        /// Column number (starting from 1).
+        column: usize,
+
+        /// Description of the error.
+        message: String,
+    },
+
+    /// Reference to a type or value which is neither defined in the module
+    /// nor registered with the [`Compiler`][`crate::Compiler`].
+    Unresolved {
+        /// Name of the referenced type or value.
+        name: String,
+    },
+
+    /// ASN.1 definition which can't be represented using the `der` crate.
+    Unsupported {
+        /// Name of the type or value assignment containing the definition.
+        name: String,
+
+        /// Description of the unsupported construct.
+        reason: String,
+    },
+}
+
+impl Error {
+    /// Create a new [`Error::Unsupported`].
+    pub(crate) fn unsupported(name: &str, reason: impl Into<String>) -> Self {
+        Error::Unsupported {
+            name: name.to_owned(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "I/O error: {}", err),
+            Error::Syntax {
+                line,
+                column,
+                message,
+            } => write!(f, "syntax error at {}:{}: {}", line, column, message),
+            Error::Unresolved { name } => write!(f, "unresolved reference: `{}`", name),
+            Error::Unsupported { name, reason } => {
+                write!(f, "unsupported in `{}`: {}", name, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        Error::Io(err)
+    }
+}
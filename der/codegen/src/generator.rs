@@ -0,0 +1,1232 @@
+//! Rust code generator.
+
+use crate::{
+    ast::{
+        Assignment, Component, Components, Module, OidComponent, Presence, StringType, Tag,
+        TagClass, TagDefault, TagMode, Type, TypeKind, Value,
+    },
+    names::{screaming_snake_case, snake_case, upper_camel_case},
+    Compiler, Error, Result,
+};
+use std::{collections::BTreeMap, fmt::Write};
+
+/// Rust type used for `INTEGER` types whose values don't fit in a primitive
+/// integer type supported by `der`.
+const BIG_INTEGER: &str = "der::BigUInt<'a, der::consts::U512>";
+
+/// Maximum length of a BER-encoded object identifier supported by `const-oid`.
+const OID_MAX_LEN: usize = 23;
+
+/// Maximum depth of references which will be followed when resolving a type
+/// or value, used to detect circular definitions.
+const MAX_REFERENCE_DEPTH: usize = 64;
+
+/// Generate Rust code for the given module.
+pub(crate) fn generate(module: &Module, compiler: &Compiler) -> Result<String> {
+    let mut generator = Generator {
+        module,
+        compiler,
+        assignments: module
+            .assignments
+            .iter()
+            .map(|a| (a.name.as_str(), a))
+            .collect(),
+        output: String::new(),
+        pending: Vec::new(),
+    };
+
+    writeln!(
+        generator.output,
+        "// Generated by der_codegen from the `{}` ASN.1 module. Do not edit.",
+        module.name
+    )
+    .unwrap();
+
+    for assignment in &module.assignments {
+        generator.output.push('\n');
+
+        if assignment.value.is_some() {
+            generator.value_assignment(assignment)?;
+        } else {
+            generator.type_assignment(assignment)?;
+        }
+
+        for definition in generator.pending.split_off(0) {
+            generator.output.push('\n');
+            generator.output.push_str(&definition);
+        }
+    }
+
+    Ok(generator.output)
+}
+
+/// Code generator state.
+struct Generator<'a> {
+    /// Module being compiled.
+    module: &'a Module,
+
+    /// Compiler configuration.
+    compiler: &'a Compiler,
+
+    /// Assignments of the module by name.
+    assignments: BTreeMap<&'a str, &'a Assignment>,
+
+    /// Generated code.
+    output: String,
+
+    /// Definitions of types nested within the current assignment.
+    pending: Vec<String>,
+}
+
+impl<'a> Generator<'a> {
+    /// Generate code for a type assignment.
+    fn type_assignment(&mut self, assignment: &Assignment) -> Result<()> {
+        let owner = assignment.name.as_str();
+        let name = upper_camel_case(owner);
+        let doc = format!(
+            "/// `{}` as defined in the `{}` ASN.1 module.\n///\n{}",
+            owner,
+            self.module.name,
+            source_doc(&assignment.source)
+        );
+        let ty = &assignment.ty;
+
+        let definition = match &ty.kind {
+            TypeKind::Sequence(components) => self.sequence(owner, &name, &doc, components)?,
+            TypeKind::Choice(components) => self.choice(owner, &name, &doc, components)?,
+            TypeKind::Tagged { .. } => self.tagged(owner, &name, &doc, ty)?,
+            TypeKind::Set | TypeKind::SetOf => format!(
+                "// `{}` is a `SET` type, which can only be used inside of tagged types.\n",
+                owner
+            ),
+            _ => {
+                let rust_type = self.rust_type(owner, &name, &format!("[`{}`]", name), ty)?;
+                let mut definition = format!(
+                    "{}pub type {}{} = {};\n",
+                    doc,
+                    name,
+                    lifetime(&rust_type),
+                    rust_type
+                );
+
+                if let TypeKind::Integer(named_numbers) = &ty.kind {
+                    if rust_type != BIG_INTEGER {
+                        for named in named_numbers {
+                            writeln!(
+                                definition,
+                                "\n/// `{}` value of [`{}`].\npub const {}_{}: {} = {};",
+                                named.name,
+                                name,
+                                screaming_snake_case(owner),
+                                screaming_snake_case(&named.name),
+                                name,
+                                self.integer_value(owner, &named.value, 0)?
+                            )
+                            .unwrap();
+                        }
+                    }
+                }
+
+                definition
+            }
+        };
+
+        self.output.push_str(&definition);
+        Ok(())
+    }
+
+    /// Generate code for a value assignment.
+    fn value_assignment(&mut self, assignment: &Assignment) -> Result<()> {
+        let owner = assignment.name.as_str();
+        let value = assignment.value.as_ref().expect("not a value assignment");
+        let name = screaming_snake_case(owner);
+        let doc = format!("/// `{}`\n", collapse_whitespace(&assignment.source));
+
+        let definition = match self.resolve(owner, &assignment.ty)?.map(|ty| &ty.kind) {
+            Some(TypeKind::ObjectIdentifier) => {
+                let arcs = self.oid_arcs(owner, value, 0)?;
+                format!(
+                    "{}pub const {}: der::ObjectIdentifier = der::ObjectIdentifier::parse(\"{}\");\n",
+                    doc,
+                    name,
+                    arcs.iter().map(u128::to_string).collect::<Vec<_>>().join(".")
+                )
+            }
+            Some(TypeKind::Integer(_)) => {
+                let value = self.integer_value(owner, value, 0)?;
+
+                if value < i64::MIN as i128 || value > i64::MAX as i128 {
+                    return Err(Error::unsupported(owner, "integer value out of range"));
+                }
+
+                format!("{}pub const {}: i64 = {};\n", doc, name, value)
+            }
+            Some(TypeKind::Boolean) => match value {
+                Value::Boolean(value) => format!("{}pub const {}: bool = {};\n", doc, name, value),
+                _ => return Err(Error::unsupported(owner, "expected `TRUE` or `FALSE`")),
+            },
+            _ => {
+                return Err(Error::unsupported(
+                    owner,
+                    "only `OBJECT IDENTIFIER`, `INTEGER` and `BOOLEAN` values are supported",
+                ))
+            }
+        };
+
+        self.output.push_str(&definition);
+        Ok(())
+    }
+
+    /// Generate a struct for a `SEQUENCE` type.
+    fn sequence(
+        &mut self,
+        owner: &str,
+        name: &str,
+        doc: &str,
+        components: &Components,
+    ) -> Result<String> {
+        let mut fields = String::new();
+
+        for (i, component) in self.tagged_components(components).iter().enumerate() {
+            let hint = format!("{}{}", name, upper_camel_case(&component.name));
+            let context = format!("the `{}` component of [`{}`]", component.name, name);
+            let mut rust_type = self.rust_type(owner, &hint, &context, &component.ty)?;
+
+            let default = match &component.presence {
+                Presence::Required => None,
+                Presence::Optional => {
+                    rust_type = format!("Option<{}>", rust_type);
+                    None
+                }
+                Presence::Default(value) => Some(self.default_value(owner, component, value)?),
+            };
+
+            if i > 0 {
+                fields.push('\n');
+            }
+
+            writeln!(
+                fields,
+                "    /// `{}`",
+                collapse_whitespace(&component.source)
+            )
+            .unwrap();
+
+            let mut args = self.constraint_args(owner, &component.ty)?;
+
+            if let Some(default) = default {
+                args.push(format!("default = \"{}\"", default));
+            }
+
+            if !args.is_empty() {
+                writeln!(fields, "    #[asn1({})]", args.join(", ")).unwrap();
+            }
+
+            writeln!(
+                fields,
+                "    pub {}: {},",
+                snake_case(&component.name),
+                rust_type
+            )
+            .unwrap();
+        }
+
+        let lifetime = self.components_lifetime(owner, components)?;
+        let attrs = if components.extensible {
+            "#[asn1(extensible)]\n"
+        } else {
+            ""
+        };
+
+        Ok(format!(
+            "{}#[derive(Clone, Debug, Eq, PartialEq, der::Message)]\n{}pub struct {}{} {{\n{}}}\n",
+            doc, attrs, name, lifetime, fields
+        ))
+    }
+
+    /// Generate an enum for a `CHOICE` type.
+    fn choice(
+        &mut self,
+        owner: &str,
+        name: &str,
+        doc: &str,
+        components: &Components,
+    ) -> Result<String> {
+        let lifetime = self.components_lifetime(owner, components)?;
+        let mut variants = String::new();
+        let mut conversions = String::new();
+        let mut der_types = Vec::new();
+
+        for (i, component) in self.tagged_components(components).iter().enumerate() {
+            let kind = self.resolve(owner, &component.ty)?.map(|ty| &ty.kind);
+
+            // `der::Choice` decodes each alternative as a `der` type and
+            // converts it into the enum using `TryFrom`
+            let (asn1_type, der_type, rust_type) = match kind {
+                Some(TypeKind::BitString) => ("BIT STRING", "der::BitString<'a>", "&'a [u8]"),
+                Some(TypeKind::GeneralizedTime) => (
+                    "GeneralizedTime",
+                    "der::GeneralizedTime",
+                    "der::GeneralizedTime",
+                ),
+                Some(TypeKind::OctetString) => ("OCTET STRING", "der::OctetString<'a>", "&'a [u8]"),
+                Some(TypeKind::String(StringType::Printable)) => {
+                    ("PrintableString", "der::PrintableString<'a>", "&'a [u8]")
+                }
+                Some(TypeKind::String(StringType::Utf8)) => {
+                    ("UTF8String", "der::Utf8String<'a>", "&'a [u8]")
+                }
+                Some(TypeKind::UtcTime) => ("UTCTime", "der::UtcTime", "der::UtcTime"),
+                _ => {
+                    return Err(Error::unsupported(
+                        owner,
+                        format!(
+                            "type of `CHOICE` alternative `{}` must be one of `BIT STRING`, \
+                             `GeneralizedTime`, `OCTET STRING`, `PrintableString`, `UTCTime` \
+                             or `UTF8String`",
+                            component.name
+                        ),
+                    ))
+                }
+            };
+
+            if der_types.contains(&der_type) {
+                return Err(Error::unsupported(
+                    owner,
+                    format!(
+                        "`CHOICE` alternative `{}` has the same type as another alternative",
+                        component.name
+                    ),
+                ));
+            }
+
+            der_types.push(der_type);
+            let variant = upper_camel_case(&component.name);
+
+            if i > 0 {
+                variants.push('\n');
+            }
+
+            let mut args = vec![format!("type = \"{}\"", asn1_type)];
+            args.extend(self.constraint_args(owner, &component.ty)?);
+
+            writeln!(
+                variants,
+                "    /// `{}`\n    #[asn1({})]\n    {}({}),",
+                collapse_whitespace(&component.source),
+                args.join(", "),
+                variant,
+                rust_type
+            )
+            .unwrap();
+
+            let value = if der_type == rust_type {
+                "value"
+            } else {
+                "value.into()"
+            };
+
+            write!(
+                conversions,
+                "\nimpl{lifetime} From<{der_type}> for {name}{lifetime} {{\n    \
+                 fn from(value: {der_type}) -> Self {{\n        \
+                 {name}::{variant}({value})\n    }}\n}}\n",
+                lifetime = if der_type.contains("'a") {
+                    "<'a>"
+                } else {
+                    lifetime
+                },
+                der_type = der_type,
+                name = name,
+                variant = variant,
+                value = value
+            )
+            .unwrap();
+        }
+
+        Ok(format!(
+            "{}#[derive(Clone, Debug, Eq, PartialEq, der::Choice)]\npub enum {}{} {{\n{}}}\n{}",
+            doc, name, lifetime, variants, conversions
+        ))
+    }
+
+    /// Generate a newtype for a tagged type.
+    fn tagged(&mut self, owner: &str, name: &str, doc: &str, ty: &Type) -> Result<String> {
+        let (tag, mode, inner) = match &ty.kind {
+            TypeKind::Tagged { tag, mode, inner } => (tag, *mode, inner.as_ref()),
+            _ => unreachable!("not a tagged type"),
+        };
+
+        let tag_name = match (tag.class, tag.number) {
+            (TagClass::ContextSpecific, n) if n <= 3 => format!("der::Tag::ContextSpecific{}", n),
+            _ => {
+                return Err(Error::unsupported(
+                    owner,
+                    format!(
+                        "tag `{}` (only context-specific tags `[0]` to `[3]` are supported)",
+                        tag
+                    ),
+                ))
+            }
+        };
+
+        // Arbitrary contents are built from a run of well-formed TLVs, which
+        // is truncated to the first one for explicitly tagged values
+        let (contents, arbitrary_contents) = if self.is_explicit(owner, mode, inner)? {
+            (
+                "the DER encoding of the explicitly tagged value",
+                "let tlvs = <der::Sequence<'a> as der::arbitrary::Arbitrary<'a>>::arbitrary(u)?.as_bytes();
+            let len = der::Decoder::new(tlvs)
+                .any()
+                .and_then(|any| der::Encodable::encoded_len(&any))
+                .map_err(|_| der::arbitrary::Error::IncorrectFormat)?;
+            let contents = &tlvs[..len.to_usize()];",
+            )
+        } else if self.is_constructed(owner, inner)? {
+            (
+                "the contents of the implicitly tagged value",
+                "let contents = <der::Sequence<'a> as der::arbitrary::Arbitrary<'a>>::arbitrary(u)?.as_bytes();",
+            )
+        } else {
+            return Err(Error::unsupported(
+                owner,
+                format!(
+                    "`IMPLICIT` tag `{}` on a primitive type (context-specific tags must be \
+                     constructed)",
+                    tag
+                ),
+            ));
+        };
+
+        Ok(format!(
+            r#"{doc}///
+/// The contents of the wrapped [`der::Any`] are {contents}.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct {name}<'a>(pub der::Any<'a>);
+
+impl<'a> core::convert::TryFrom<der::Any<'a>> for {name}<'a> {{
+    type Error = der::Error;
+
+    fn try_from(any: der::Any<'a>) -> der::Result<Self> {{
+        any.tag().assert_eq({tag})?;
+        Ok(Self(any))
+    }}
+}}
+
+impl der::Encodable for {name}<'_> {{
+    fn encoded_len(&self) -> der::Result<der::Length> {{
+        self.0.encoded_len()
+    }}
+
+    fn encode(&self, encoder: &mut der::Encoder<'_>) -> der::Result<()> {{
+        self.0.encode(encoder)
+    }}
+}}
+
+impl der::Tagged for {name}<'_> {{
+    const TAG: der::Tag = {tag};
+}}
+
+der::__impl_arbitrary! {{
+    impl<'a> der::arbitrary::Arbitrary<'a> for {name}<'a> {{
+        fn arbitrary(u: &mut der::arbitrary::Unstructured<'a>) -> der::arbitrary::Result<Self> {{
+            {arbitrary_contents}
+
+            der::Any::new({tag}, contents)
+                .map(Self)
+                .map_err(|_| der::arbitrary::Error::IncorrectFormat)
+        }}
+    }}
+}}
+"#,
+            doc = doc,
+            contents = contents,
+            arbitrary_contents = arbitrary_contents,
+            name = name,
+            tag = tag_name
+        ))
+    }
+
+    /// Get the Rust type for the given ASN.1 type, generating definitions for
+    /// any nested `SEQUENCE`, `CHOICE` or tagged types using the given name.
+    ///
+    /// The `context` describes where the type is used for the documentation
+    /// of nested types.
+    fn rust_type(&mut self, owner: &str, hint: &str, context: &str, ty: &Type) -> Result<String> {
+        Ok(match &ty.kind {
+            TypeKind::Any => "der::Any<'a>".to_owned(),
+            TypeKind::BitString => "der::BitString<'a>".to_owned(),
+            TypeKind::Boolean => "bool".to_owned(),
+            TypeKind::Choice(components) => {
+                let doc = format!("/// Type of {}.\n", context);
+                let definition = self.choice(owner, hint, &doc, components)?;
+                self.nested(owner, hint, ty, definition)?
+            }
+            TypeKind::Enumerated => {
+                return Err(Error::unsupported(owner, "`ENUMERATED` types"));
+            }
+            TypeKind::GeneralizedTime => "der::GeneralizedTime".to_owned(),
+            TypeKind::Integer(_) => self.integer_type(owner, ty)?.to_owned(),
+            TypeKind::Null => "der::Null".to_owned(),
+            TypeKind::ObjectIdentifier => "der::ObjectIdentifier".to_owned(),
+            TypeKind::OctetString => "der::OctetString<'a>".to_owned(),
+            TypeKind::Reference(name) => {
+                if let Some(TypeKind::Set) | Some(TypeKind::SetOf) =
+                    self.resolve(owner, ty)?.map(|ty| &ty.kind)
+                {
+                    return Err(Error::unsupported(
+                        owner,
+                        format!("untagged reference to `SET` type `{}`", name),
+                    ));
+                }
+
+                if self.assignments.contains_key(name.as_str()) {
+                    let rust_type = upper_camel_case(name);
+
+                    if self.needs_lifetime(owner, ty, 0)? {
+                        format!("{}<'a>", rust_type)
+                    } else {
+                        rust_type
+                    }
+                } else {
+                    self.external_type(name)?.to_owned()
+                }
+            }
+            TypeKind::Sequence(components) => {
+                let doc = format!("/// Type of {}.\n", context);
+                let definition = self.sequence(owner, hint, &doc, components)?;
+                self.nested(owner, hint, ty, definition)?
+            }
+            TypeKind::SequenceOf(element) => {
+                if let TypeKind::Sequence(_) | TypeKind::Choice(_) = element.kind {
+                    let context = format!("the elements of {}", context);
+                    self.rust_type(owner, &format!("{}Item", hint), &context, element)?;
+                }
+
+                "der::Sequence<'a>".to_owned()
+            }
+            TypeKind::Set | TypeKind::SetOf => {
+                return Err(Error::unsupported(
+                    owner,
+                    "untagged `SET` and `SET OF` types (`der` has no `SET` type)",
+                ));
+            }
+            TypeKind::String(StringType::Ia5) => "der::Ia5String<'a>".to_owned(),
+            TypeKind::String(StringType::Printable) => "der::PrintableString<'a>".to_owned(),
+            TypeKind::String(StringType::Utf8) => "der::Utf8String<'a>".to_owned(),
+            TypeKind::String(string_type) => {
+                return Err(Error::unsupported(
+                    owner,
+                    format!("character string type `{:?}String`", string_type),
+                ));
+            }
+            TypeKind::Tagged { .. } => {
+                let doc = format!("/// Type of {}.\n", context);
+                let definition = self.tagged(owner, hint, &doc, ty)?;
+                self.nested(owner, hint, ty, definition)?
+            }
+            TypeKind::UtcTime => "der::UtcTime".to_owned(),
+        })
+    }
+
+    /// Record the definition of a nested type, returning its Rust type.
+    fn nested(&mut self, owner: &str, name: &str, ty: &Type, definition: String) -> Result<String> {
+        let rust_type = if self.needs_lifetime(owner, ty, 0)? {
+            format!("{}<'a>", name)
+        } else {
+            name.to_owned()
+        };
+
+        self.pending.push(definition);
+        Ok(rust_type)
+    }
+
+    /// Get the lifetime parameter for a type with the given components.
+    fn components_lifetime(&self, owner: &str, components: &Components) -> Result<&'static str> {
+        for component in self.tagged_components(components) {
+            if self.needs_lifetime(owner, &component.ty, 0)? {
+                return Ok("<'a>");
+            }
+        }
+
+        Ok("")
+    }
+
+    /// Get the Rust type for an `INTEGER`, which is the smallest primitive
+    /// integer type able to hold the values permitted by its value range
+    /// constraint, or [`BIG_INTEGER`] for other non-negative ranges.
+    ///
+    /// `INTEGER` types which are unconstrained or may be negative are mapped
+    /// to `i64`, since `BigUInt` is unsigned. Values which don't fit in an
+    /// `i64` are rejected when decoding.
+    fn integer_type(&self, owner: &str, ty: &Type) -> Result<&'static str> {
+        Ok(match self.integer_range(owner, ty)? {
+            (Some(lower), Some(upper)) if lower >= 0 && upper <= u8::MAX as i128 => "u8",
+            (Some(lower), Some(upper)) if lower >= 0 && upper <= u16::MAX as i128 => "u16",
+            (Some(lower), Some(upper)) if lower >= i8::MIN as i128 && upper <= i8::MAX as i128 => {
+                "i8"
+            }
+            (Some(lower), Some(upper))
+                if lower >= i16::MIN as i128 && upper <= i16::MAX as i128 =>
+            {
+                "i16"
+            }
+            (Some(lower), Some(upper))
+                if lower >= i32::MIN as i128 && upper <= i32::MAX as i128 =>
+            {
+                "i32"
+            }
+            (Some(lower), Some(upper))
+                if lower >= i64::MIN as i128 && upper <= i64::MAX as i128 =>
+            {
+                "i64"
+            }
+            (Some(lower), _) if lower >= 0 => BIG_INTEGER,
+            (lower, upper)
+                if lower.unwrap_or(i64::MIN as i128) >= i64::MIN as i128
+                    && upper.unwrap_or(i64::MAX as i128) <= i64::MAX as i128 =>
+            {
+                "i64"
+            }
+            _ => {
+                return Err(Error::unsupported(
+                    owner,
+                    "`INTEGER` types which may be negative, unless their value range fits in \
+                     an `i64` (`der` has no larger signed integer type)",
+                ))
+            }
+        })
+    }
+
+    /// Evaluate the lower and upper bounds of the value range constraint of
+    /// an `INTEGER` type (`None` for `MIN`, `MAX` or unconstrained bounds).
+    fn integer_range(&self, owner: &str, ty: &Type) -> Result<(Option<i128>, Option<i128>)> {
+        let named_numbers = match &ty.kind {
+            TypeKind::Integer(named_numbers) => named_numbers.as_slice(),
+            _ => &[],
+        };
+
+        // Bounds may refer to the named numbers of the type itself
+        let bound = |value: &Option<Value>| -> Result<Option<i128>> {
+            let value = match value {
+                Some(value) => value,
+                None => return Ok(None),
+            };
+
+            if let Value::Reference(name) = value {
+                if let Some(named) = named_numbers.iter().find(|n| &n.name == name) {
+                    return self.integer_value(owner, &named.value, 0).map(Some);
+                }
+            }
+
+            self.integer_value(owner, value, 0).map(Some)
+        };
+
+        match &ty.constraint.value {
+            Some(range) => Ok((bound(&range.lower)?, bound(&range.upper)?)),
+            None => Ok((None, None)),
+        }
+    }
+
+    /// Get the `#[asn1(...)]` attribute arguments which enforce the `SIZE`
+    /// and value range constraints of the given type, or of the types it
+    /// refers to.
+    ///
+    /// Constraints on the contents of tagged types aren't enforced, since
+    /// the contents are left to be decoded by the caller.
+    fn constraint_args(&self, owner: &str, ty: &Type) -> Result<Vec<String>> {
+        let mut size = None;
+        let mut current = ty;
+
+        for depth in 0.. {
+            if depth > MAX_REFERENCE_DEPTH {
+                return Err(Error::unsupported(owner, "circular type definitions"));
+            }
+
+            if size.is_none() {
+                size = current.constraint.size.as_ref();
+            }
+
+            match &current.kind {
+                TypeKind::Reference(name) => match self.assignments.get(name.as_str()) {
+                    Some(assignment) if assignment.value.is_none() => current = &assignment.ty,
+                    _ => return Ok(Vec::new()),
+                },
+                _ => break,
+            }
+        }
+
+        let mut args = Vec::new();
+
+        if let Some(size) = size {
+            match &current.kind {
+                TypeKind::BitString
+                | TypeKind::OctetString
+                | TypeKind::SequenceOf(_)
+                | TypeKind::String(StringType::Ia5)
+                | TypeKind::String(StringType::Printable)
+                | TypeKind::String(StringType::Utf8) => {
+                    let lower = match &size.lower {
+                        Some(value) => self.integer_value(owner, value, 0)?,
+                        None => 0,
+                    };
+                    let upper = match &size.upper {
+                        Some(value) => Some(self.integer_value(owner, value, 0)?),
+                        None => None,
+                    };
+
+                    if lower < 0 || matches!(upper, Some(upper) if upper < lower) {
+                        return Err(Error::unsupported(owner, "invalid `SIZE` constraint"));
+                    }
+
+                    args.push(format!("size = \"{}\"", range_expr(lower, upper)));
+                }
+                TypeKind::Tagged { .. } => (),
+                _ => {
+                    return Err(Error::unsupported(
+                        owner,
+                        "`SIZE` constraints on types other than `BIT STRING`, `OCTET STRING`, \
+                         `SEQUENCE OF` and the supported character string types",
+                    ))
+                }
+            }
+        }
+
+        // Ranges which only exclude values the Rust type can't hold anyway
+        // don't need to be checked
+        if let TypeKind::Integer(_) = current.kind {
+            let bounds = match integer_bounds(self.integer_type(owner, current)?) {
+                Some(bounds) => bounds,
+                None => return Ok(args),
+            };
+
+            if let (Some(lower), upper) = self.integer_range(owner, current)? {
+                if (lower, upper) != (bounds.0, Some(bounds.1)) {
+                    args.push(format!("range = \"{}\"", range_expr(lower, upper)));
+                }
+            }
+        }
+
+        Ok(args)
+    }
+
+    /// Get the Rust expression for the `DEFAULT` value of a component, used
+    /// as the `#[asn1(default = "...")]` attribute of its field.
+    ///
+    /// Only `BOOLEAN` and `INTEGER` values are supported. Integer values may
+    /// refer to the named numbers of the component's type.
+    fn default_value(&self, owner: &str, component: &Component, value: &Value) -> Result<String> {
+        match self.resolve(owner, &component.ty)? {
+            Some(Type {
+                kind: TypeKind::Boolean,
+                ..
+            }) => match value {
+                Value::Boolean(value) => Ok(value.to_string()),
+                _ => Err(Error::unsupported(owner, "expected `TRUE` or `FALSE`")),
+            },
+            Some(
+                ty @ Type {
+                    kind: TypeKind::Integer(named_numbers),
+                    ..
+                },
+            ) => {
+                let value = match value {
+                    Value::Reference(name) => {
+                        match named_numbers.iter().find(|n| &n.name == name) {
+                            Some(named) => self.integer_value(owner, &named.value, 0)?,
+                            None => self.integer_value(owner, value, 0)?,
+                        }
+                    }
+                    _ => self.integer_value(owner, value, 0)?,
+                };
+
+                let in_range = match self.integer_range(owner, ty)? {
+                    (Some(lower), _) if value < lower => false,
+                    (_, Some(upper)) if value > upper => false,
+                    _ => true,
+                };
+
+                match integer_bounds(self.integer_type(owner, ty)?) {
+                    Some((lower, upper)) if in_range && value >= lower && value <= upper => {
+                        Ok(value.to_string())
+                    }
+                    _ => Err(Error::unsupported(
+                        owner,
+                        format!(
+                            "`DEFAULT` value of component `{}` which is out of range, or of a \
+                             `der::BigUInt` type",
+                            component.name
+                        ),
+                    )),
+                }
+            }
+            _ => Err(Error::unsupported(
+                owner,
+                format!(
+                    "`DEFAULT` value of component `{}` (only untagged `BOOLEAN` and `INTEGER` \
+                     components can have a default)",
+                    component.name
+                ),
+            )),
+        }
+    }
+
+    /// Look up the Rust type registered for a type defined in another module.
+    fn external_type(&self, name: &str) -> Result<&'a str> {
+        self.compiler
+            .external_types
+            .get(name)
+            .map(String::as_str)
+            .ok_or_else(|| Error::Unresolved {
+                name: name.to_owned(),
+            })
+    }
+
+    /// Does the Rust type for the given ASN.1 type have a lifetime?
+    fn needs_lifetime(&self, owner: &str, ty: &Type, depth: usize) -> Result<bool> {
+        if depth > MAX_REFERENCE_DEPTH {
+            return Err(Error::unsupported(owner, "circular type definitions"));
+        }
+
+        Ok(match &ty.kind {
+            TypeKind::Boolean
+            | TypeKind::Enumerated
+            | TypeKind::GeneralizedTime
+            | TypeKind::Null
+            | TypeKind::ObjectIdentifier
+            | TypeKind::UtcTime => false,
+            TypeKind::Integer(_) => self.integer_type(owner, ty)? == BIG_INTEGER,
+            TypeKind::Choice(components) | TypeKind::Sequence(components) => {
+                let mut result = false;
+
+                for component in self.tagged_components(components) {
+                    result |= self.needs_lifetime(owner, &component.ty, depth + 1)?;
+                }
+
+                result
+            }
+            TypeKind::Reference(name) => match self.assignments.get(name.as_str()) {
+                Some(assignment) => self.needs_lifetime(owner, &assignment.ty, depth + 1)?,
+                None => self.external_type(name)?.contains("'a"),
+            },
+            _ => true,
+        })
+    }
+
+    /// Apply automatic tagging to the given components, if enabled for the
+    /// module and none of the components are already tagged.
+    fn tagged_components(&self, components: &Components) -> Vec<Component> {
+        let components = &components.components;
+
+        if self.module.tag_default != TagDefault::Automatic
+            || components
+                .iter()
+                .any(|c| matches!(c.ty.kind, TypeKind::Tagged { .. }))
+        {
+            return components.clone();
+        }
+
+        components
+            .iter()
+            .enumerate()
+            .map(|(i, component)| Component {
+                ty: Type::new(TypeKind::Tagged {
+                    tag: Tag {
+                        class: TagClass::ContextSpecific,
+                        number: i as u128,
+                    },
+                    mode: None,
+                    inner: Box::new(component.ty.clone()),
+                }),
+                ..component.clone()
+            })
+            .collect()
+    }
+
+    /// Is a tagged type with the given tagging mode explicitly tagged?
+    fn is_explicit(&self, owner: &str, mode: Option<TagMode>, inner: &Type) -> Result<bool> {
+        // `CHOICE` and open types can't be implicitly tagged
+        if let Some(TypeKind::Choice(_)) | Some(TypeKind::Any) =
+            self.resolve(owner, inner)?.map(|ty| &ty.kind)
+        {
+            return Ok(true);
+        }
+
+        Ok(match mode {
+            Some(mode) => mode == TagMode::Explicit,
+            None => self.module.tag_default == TagDefault::Explicit,
+        })
+    }
+
+    /// Does the given type use the constructed encoding?
+    fn is_constructed(&self, owner: &str, ty: &Type) -> Result<bool> {
+        Ok(match self.resolve(owner, ty)?.map(|ty| &ty.kind) {
+            Some(TypeKind::Sequence(_))
+            | Some(TypeKind::SequenceOf(_))
+            | Some(TypeKind::Set)
+            | Some(TypeKind::SetOf) => true,
+            Some(TypeKind::Tagged { mode, inner, .. }) => {
+                self.is_explicit(owner, *mode, inner)? || self.is_constructed(owner, inner)?
+            }
+            _ => false,
+        })
+    }
+
+    /// Follow type references defined in this module, returning the
+    /// referenced type, or `None` for types defined in other modules.
+    fn resolve<'t>(&'t self, owner: &str, mut ty: &'t Type) -> Result<Option<&'t Type>> {
+        for _ in 0..MAX_REFERENCE_DEPTH {
+            match &ty.kind {
+                TypeKind::Reference(name) => match self.assignments.get(name.as_str()) {
+                    Some(assignment) if assignment.value.is_none() => ty = &assignment.ty,
+                    _ => return Ok(None),
+                },
+                _ => return Ok(Some(ty)),
+            }
+        }
+
+        Err(Error::unsupported(owner, "circular type definitions"))
+    }
+
+    /// Evaluate an integer value.
+    fn integer_value(&self, owner: &str, value: &Value, depth: usize) -> Result<i128> {
+        match value {
+            Value::Integer(n) => Ok(*n),
+            Value::Reference(name) if depth < MAX_REFERENCE_DEPTH => {
+                match self
+                    .assignments
+                    .get(name.as_str())
+                    .and_then(|a| a.value.as_ref())
+                {
+                    Some(value) => self.integer_value(owner, value, depth + 1),
+                    None => Err(Error::Unresolved { name: name.clone() }),
+                }
+            }
+            _ => Err(Error::unsupported(owner, "expected an integer value")),
+        }
+    }
+
+    /// Evaluate the arcs of an object identifier value.
+    fn oid_arcs(&self, owner: &str, value: &Value, depth: usize) -> Result<Vec<u128>> {
+        if depth > MAX_REFERENCE_DEPTH {
+            return Err(Error::unsupported(owner, "circular value definitions"));
+        }
+
+        let components = match value {
+            Value::ObjectIdentifier(components) => components,
+            Value::Reference(name) => return self.oid_reference(owner, name, depth),
+            _ => {
+                return Err(Error::unsupported(
+                    owner,
+                    "expected an object identifier value",
+                ))
+            }
+        };
+
+        let mut arcs = Vec::new();
+
+        for (i, component) in components.iter().enumerate() {
+            match component {
+                OidComponent::Number(n) => arcs.push(*n),
+                OidComponent::Name(name) => match well_known_arc(&arcs, name) {
+                    Some(arc) => arcs.push(arc),
+                    None if i == 0 => arcs = self.oid_reference(owner, name, depth)?,
+                    None => return Err(Error::Unresolved { name: name.clone() }),
+                },
+            }
+        }
+
+        let valid_root = match arcs.as_slice() {
+            [0, second, ..] | [1, second, ..] => *second <= 39,
+            [2, _, ..] => true,
+            _ => false,
+        };
+
+        let ber_len = match arcs.as_slice() {
+            [first, second, rest @ ..] => {
+                rest.iter().map(|&arc| base128_len(arc)).sum::<usize>()
+                    + base128_len(first * 40 + second)
+            }
+            _ => 0,
+        };
+
+        if !valid_root || ber_len > OID_MAX_LEN {
+            return Err(Error::unsupported(
+                owner,
+                "object identifier can't be represented by `const-oid`",
+            ));
+        }
+
+        Ok(arcs)
+    }
+
+    /// Evaluate the arcs of a reference to an object identifier value.
+    fn oid_reference(&self, owner: &str, name: &str, depth: usize) -> Result<Vec<u128>> {
+        if let Some(value) = self.assignments.get(name).and_then(|a| a.value.as_ref()) {
+            return self.oid_arcs(owner, value, depth + 1);
+        }
+
+        let oid = self
+            .compiler
+            .external_oids
+            .get(name)
+            .ok_or_else(|| Error::Unresolved {
+                name: name.to_owned(),
+            })?;
+
+        oid.split('.')
+            .map(|arc| arc.parse().ok())
+            .collect::<Option<Vec<u128>>>()
+            .ok_or_else(|| Error::unsupported(name, format!("invalid object identifier: {}", oid)))
+    }
+}
+
+/// Get the arc of a well-known object identifier component name (i.e. ones
+/// which may be used without a number) following the given arcs.
+fn well_known_arc(arcs: &[u128], name: &str) -> Option<u128> {
+    Some(match (arcs, name) {
+        ([], "itu-t") | ([], "ccitt") => 0,
+        ([], "iso") => 1,
+        ([], "joint-iso-itu-t") | ([], "joint-iso-ccitt") => 2,
+        ([0], "recommendation") => 0,
+        ([0], "question") => 1,
+        ([0], "administration") => 2,
+        ([0], "network-operator") => 3,
+        ([0], "identified-organization") => 4,
+        ([1], "standard") => 0,
+        ([1], "member-body") => 2,
+        ([1], "identified-organization") => 3,
+        _ => return None,
+    })
+}
+
+/// Get the lifetime parameter for a type alias of the given Rust type.
+fn lifetime(rust_type: &str) -> &'static str {
+    if rust_type.contains("'a") {
+        "<'a>"
+    } else {
+        ""
+    }
+}
+
+/// Get the bounds of a primitive integer type used for `INTEGER` types, or
+/// `None` for [`BIG_INTEGER`].
+fn integer_bounds(rust_type: &str) -> Option<(i128, i128)> {
+    Some(match rust_type {
+        "u8" => (0, u8::MAX as i128),
+        "u16" => (0, u16::MAX as i128),
+        "i8" => (i8::MIN as i128, i8::MAX as i128),
+        "i16" => (i16::MIN as i128, i16::MAX as i128),
+        "i32" => (i32::MIN as i128, i32::MAX as i128),
+        "i64" => (i64::MIN as i128, i64::MAX as i128),
+        _ => return None,
+    })
+}
+
+/// Format an inclusive range (unbounded above if `upper` is `None`) as a
+/// Rust range expression for `#[asn1(size = "...")]` or
+/// `#[asn1(range = "...")]` attributes.
+fn range_expr(lower: i128, upper: Option<i128>) -> String {
+    match upper {
+        Some(upper) if upper == lower => lower.to_string(),
+        Some(upper) => format!("{}..={}", lower, upper),
+        None => format!("{}..", lower),
+    }
+}
+
+/// Get the length of an object identifier arc encoded as base 128.
+fn base128_len(mut arc: u128) -> usize {
+    let mut len = 1;
+
+    while arc >= 0x80 {
+        arc >>= 7;
+        len += 1;
+    }
+
+    len
+}
+
+/// Format the ASN.1 source of an assignment as a doc comment.
+fn source_doc(source: &str) -> String {
+    let lines = source.lines().map(str::trim_end).collect::<Vec<_>>();
+
+    let indent = lines
+        .iter()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    let mut doc = String::from("/// ```text\n");
+
+    for line in lines {
+        if line.is_empty() {
+            doc.push_str("///\n");
+        } else {
+            writeln!(doc, "/// {}", &line[indent..]).unwrap();
+        }
+    }
+
+    doc.push_str("/// ```\n");
+    doc
+}
+
+/// Collapse runs of whitespace into single spaces.
+fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{compile, Compiler, Error};
+
+    fn module(tag_default: &str, body: &str) -> String {
+        format!("M DEFINITIONS {} ::= BEGIN\n{}\nEND", tag_default, body)
+    }
+
+    fn unsupported(tag_default: &str, body: &str) -> String {
+        match compile(&module(tag_default, body)) {
+            Err(Error::Unsupported { reason, .. }) => reason,
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn automatic_tags() {
+        let code = compile(&module(
+            "AUTOMATIC TAGS",
+            "A ::= SEQUENCE { a SEQUENCE OF INTEGER, b ANY OPTIONAL }",
+        ))
+        .unwrap();
+
+        assert!(code.contains("pub a: AA<'a>,"));
+        assert!(code.contains("pub b: Option<AB<'a>>,"));
+        assert!(code.contains("const TAG: der::Tag = der::Tag::ContextSpecific1;"));
+        assert!(code.contains("the contents of the implicitly tagged value"));
+        assert!(code.contains("the DER encoding of the explicitly tagged value"));
+    }
+
+    #[test]
+    fn integer_types() {
+        let code = compile(&module(
+            "",
+            "A ::= INTEGER (-1..127)\nB ::= INTEGER (0..MAX)\nC ::= INTEGER { x(-200), y(200) } (x..y)\nD ::= INTEGER { z(1) } (0..255)",
+        ))
+        .unwrap();
+
+        assert!(code.contains("pub type A = i8;"));
+        assert!(code.contains("pub type B<'a> = der::BigUInt<'a, der::consts::U512>;"));
+        assert!(code.contains("pub type C = i16;"));
+        assert!(code.contains("pub const C_X: C = -200;"));
+        assert!(code.contains("pub type D = u8;"));
+    }
+
+    #[test]
+    fn signed_integer_types() {
+        let code = compile(&module(
+            "",
+            "A ::= INTEGER\nB ::= INTEGER { x(0), y(1) }\nC ::= INTEGER (-40000..0)\nD ::= INTEGER (0..4294967296)
+             E ::= SEQUENCE { a INTEGER (-1..MAX), b INTEGER (MIN..0), c C }",
+        ))
+        .unwrap();
+
+        // Named numbers don't imply a range
+        assert!(code.contains("pub type A = i64;"));
+        assert!(code.contains("pub type B = i64;"));
+        assert!(code.contains("pub type C = i32;"));
+        assert!(code.contains("pub type D = i64;"));
+        assert!(code.contains("    #[asn1(range = \"-1..\")]\n    pub a: i64,"));
+        assert!(code.contains("    pub b: i64,"));
+        assert!(code.contains("    #[asn1(range = \"-40000..=0\")]\n    pub c: C,"));
+
+        let reason = unsupported("", "A ::= INTEGER (-1..9223372036854775808)");
+        assert!(reason.contains("`i64`"));
+    }
+
+    #[test]
+    fn constraints() {
+        let code = compile(&module(
+            "",
+            "ub INTEGER ::= 64
+             Name ::= UTF8String (SIZE (1..ub))
+             A ::= SEQUENCE {
+                 a OCTET STRING (SIZE (16)),
+                 b Name OPTIONAL,
+                 c SEQUENCE SIZE (1..MAX) OF INTEGER,
+                 d INTEGER (0..2) }
+             B ::= CHOICE { x OCTET STRING (SIZE (0..4)), y UTF8String }",
+        ))
+        .unwrap();
+
+        assert!(code.contains("    #[asn1(size = \"16\")]\n    pub a: der::OctetString<'a>,"));
+        assert!(code.contains("    #[asn1(size = \"1..=64\")]\n    pub b: Option<Name<'a>>,"));
+        assert!(code.contains("    #[asn1(size = \"1..\")]\n    pub c: der::Sequence<'a>,"));
+        assert!(code.contains("    #[asn1(range = \"0..=2\")]\n    pub d: u8,"));
+        assert!(code.contains("    #[asn1(type = \"OCTET STRING\", size = \"0..=4\")]\n"));
+        assert!(code.contains("    #[asn1(type = \"UTF8String\")]\n"));
+
+        assert!(unsupported("", "A ::= SEQUENCE { a BOOLEAN (SIZE (1)) }").contains("`SIZE`"));
+    }
+
+    #[test]
+    fn default_components() {
+        let code = compile(&module(
+            "",
+            "A ::= INTEGER { v1(0), v2(1) }
+             B ::= SEQUENCE {
+                 a BOOLEAN DEFAULT TRUE,
+                 b A DEFAULT v2,
+                 c INTEGER (0..9) DEFAULT 3 }",
+        ))
+        .unwrap();
+
+        assert!(code.contains("    #[asn1(default = \"true\")]\n    pub a: bool,"));
+        assert!(code.contains("    #[asn1(default = \"1\")]\n    pub b: A,"));
+        assert!(code.contains("    #[asn1(range = \"0..=9\", default = \"3\")]\n    pub c: u8,"));
+
+        let reason = unsupported("", "A ::= SEQUENCE { a OCTET STRING DEFAULT '00'H }");
+        assert!(reason.contains("`a`"));
+
+        let reason = unsupported("", "A ::= SEQUENCE { a INTEGER (0..9) DEFAULT 10 }");
+        assert!(reason.contains("out of range"));
+
+        let reason = unsupported("", "A ::= SEQUENCE { a [0] BOOLEAN DEFAULT TRUE }");
+        assert!(reason.contains("`a`"));
+    }
+
+    #[test]
+    fn external_oids() {
+        let input = module("", "id-a OBJECT IDENTIFIER ::= { id-pkix 3 }");
+        assert!(matches!(
+            compile(&input),
+            Err(Error::Unresolved { name }) if name == "id-pkix"
+        ));
+
+        let code = Compiler::new()
+            .external_oid("id-pkix", "1.3.6.1.5.5.7")
+            .compile_str(&input)
+            .unwrap();
+
+        assert!(code.contains("pub const ID_A: der::ObjectIdentifier = der::ObjectIdentifier::parse(\"1.3.6.1.5.5.7.3\");"));
+    }
+
+    #[test]
+    fn unsupported_definitions() {
+        assert!(unsupported("", "A ::= SEQUENCE { a SET OF INTEGER }").contains("`SET`"));
+        assert!(unsupported("IMPLICIT TAGS", "A ::= [0] INTEGER").contains("primitive"));
+        assert!(unsupported("", "A ::= [4] INTEGER").contains("`[4]`"));
+        assert!(unsupported("", "A ::= [APPLICATION 1] INTEGER").contains("APPLICATION"));
+        assert!(unsupported("", "A ::= ENUMERATED { a, b }").contains("ENUMERATED"));
+        assert!(unsupported("", "A ::= CHOICE { a BOOLEAN }").contains("`a`"));
+        assert!(unsupported("", "A ::= CHOICE { a UTCTime, b UTCTime }").contains("`b`"));
+        assert!(unsupported("", "a OBJECT IDENTIFIER ::= { 3 1 }").contains("const-oid"));
+    }
+
+    #[test]
+    fn unresolved_types() {
+        assert!(matches!(
+            compile(&module("", "A ::= SEQUENCE { b B }")),
+            Err(Error::Unresolved { name }) if name == "B"
+        ));
+    }
+}
@@ -0,0 +1,284 @@
+//! Lexer for ASN.1 module definitions.
+
+use crate::{Error, Result};
+
+/// Lexical token.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Token {
+    /// Identifier, reference or reserved word.
+    Word(String),
+
+    /// Non-negative number.
+    Number(u128),
+
+    /// Binary (`'0101'B`) or hexadecimal (`'7F'H`) string.
+    Bits(String),
+
+    /// Character string (`"..."`).
+    Text(String),
+
+    /// `::=`
+    Assign,
+
+    /// `...`
+    Ellipsis,
+
+    /// `..`
+    Range,
+
+    /// Any other single character punctuation, e.g. `{` or `,`.
+    Punct(char),
+}
+
+/// Token along with its location in the module source.
+#[derive(Clone, Debug)]
+pub(crate) struct Spanned {
+    /// Token.
+    pub token: Token,
+
+    /// Byte offset of the start of the token.
+    pub start: usize,
+
+    /// Byte offset of the end of the token.
+    pub end: usize,
+}
+
+/// Punctuation characters which form single character tokens.
+const PUNCTUATION: &str = "{}()[],;|-<>@!^.&:";
+
+/// Split an ASN.1 module into tokens, skipping whitespace and comments.
+pub(crate) fn tokenize(input: &str) -> Result<Vec<Spanned>> {
+    let bytes = input.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        let start = pos;
+        let c = bytes[pos];
+
+        let token = if c.is_ascii_whitespace() {
+            pos += 1;
+            continue;
+        } else if input[pos..].starts_with("--") {
+            // Comments end at the next `--` or the end of the line
+            pos += 2;
+
+            while pos < bytes.len() && bytes[pos] != b'\n' && !input[pos..].starts_with("--") {
+                pos += 1;
+            }
+
+            if input[pos..].starts_with("--") {
+                pos += 2;
+            }
+
+            continue;
+        } else if input[pos..].starts_with("/*") {
+            pos = skip_block_comment(input, pos)?;
+            continue;
+        } else if c.is_ascii_alphabetic() {
+            pos += 1;
+
+            while pos < bytes.len() {
+                let c = bytes[pos];
+
+                // Hyphens may only appear between two other characters, and
+                // two consecutive hyphens begin a comment.
+                let hyphen =
+                    c == b'-' && matches!(bytes.get(pos + 1), Some(c) if c.is_ascii_alphanumeric());
+
+                if c.is_ascii_alphanumeric() || hyphen {
+                    pos += 1;
+                } else {
+                    break;
+                }
+            }
+
+            Token::Word(input[start..pos].to_owned())
+        } else if c.is_ascii_digit() {
+            while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+                pos += 1;
+            }
+
+            let number = input[start..pos]
+                .parse()
+                .map_err(|_| syntax_error(input, start, "number out of range"))?;
+
+            Token::Number(number)
+        } else if c == b'\'' {
+            let end = input[pos + 1..]
+                .find('\'')
+                .map(|n| pos + 1 + n)
+                .ok_or_else(|| syntax_error(input, start, "unterminated bit string"))?;
+
+            match bytes.get(end + 1) {
+                Some(b'B') | Some(b'H') => pos = end + 2,
+                _ => return Err(syntax_error(input, start, "expected `B` or `H` suffix")),
+            }
+
+            Token::Bits(input[start..pos].to_owned())
+        } else if c == b'"' {
+            // Quotes within character strings are escaped by doubling them
+            pos += 1;
+
+            loop {
+                match bytes.get(pos) {
+                    Some(b'"') if bytes.get(pos + 1) == Some(&b'"') => pos += 2,
+                    Some(b'"') => break,
+                    Some(_) => pos += 1,
+                    None => return Err(syntax_error(input, start, "unterminated string")),
+                }
+            }
+
+            pos += 1;
+            Token::Text(input[start + 1..pos - 1].replace("\"\"", "\""))
+        } else if input[pos..].starts_with("::=") {
+            pos += 3;
+            Token::Assign
+        } else if input[pos..].starts_with("...") {
+            pos += 3;
+            Token::Ellipsis
+        } else if input[pos..].starts_with("..") {
+            pos += 2;
+            Token::Range
+        } else if PUNCTUATION.contains(c as char) {
+            pos += 1;
+            Token::Punct(c as char)
+        } else {
+            return Err(syntax_error(input, start, "unexpected character"));
+        };
+
+        tokens.push(Spanned {
+            token,
+            start,
+            end: pos,
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// Skip a (possibly nested) `/* ... */` comment starting at `pos`, returning
+/// the position following it.
+fn skip_block_comment(input: &str, mut pos: usize) -> Result<usize> {
+    let start = pos;
+    let mut depth = 0usize;
+
+    while pos < input.len() {
+        if input[pos..].starts_with("/*") {
+            depth += 1;
+            pos += 2;
+        } else if input[pos..].starts_with("*/") {
+            depth -= 1;
+            pos += 2;
+
+            if depth == 0 {
+                return Ok(pos);
+            }
+        } else {
+            pos += input[pos..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+
+    Err(syntax_error(input, start, "unterminated comment"))
+}
+
+/// Create a syntax error at the given byte offset.
+pub(crate) fn syntax_error(input: &str, offset: usize, message: impl Into<String>) -> Error {
+    let preceding = &input[..offset.min(input.len())];
+    let line = preceding.matches('\n').count() + 1;
+    let column = preceding
+        .rfind('\n')
+        .map_or(preceding, |n| &preceding[n + 1..])
+        .chars()
+        .count()
+        + 1;
+
+    Error::Syntax {
+        line,
+        column,
+        message: message.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tokenize, Token};
+    use crate::Error;
+
+    fn tokens(input: &str) -> Vec<Token> {
+        tokenize(input)
+            .unwrap()
+            .into_iter()
+            .map(|t| t.token)
+            .collect()
+    }
+
+    fn word(s: &str) -> Token {
+        Token::Word(s.to_owned())
+    }
+
+    #[test]
+    fn assignment() {
+        assert_eq!(
+            tokens("Version ::= INTEGER { v1(0) }"),
+            [
+                word("Version"),
+                Token::Assign,
+                word("INTEGER"),
+                Token::Punct('{'),
+                word("v1"),
+                Token::Punct('('),
+                Token::Number(0),
+                Token::Punct(')'),
+                Token::Punct('}'),
+            ]
+        );
+    }
+
+    #[test]
+    fn hyphenated_identifiers() {
+        assert_eq!(
+            tokens("id-ce-keyUsage a -1"),
+            [
+                word("id-ce-keyUsage"),
+                word("a"),
+                Token::Punct('-'),
+                Token::Number(1)
+            ]
+        );
+    }
+
+    #[test]
+    fn comments() {
+        assert_eq!(
+            tokens("a -- one -- b -- two\nc /* three /* four */ */ d"),
+            [word("a"), word("b"), word("c"), word("d")]
+        );
+    }
+
+    #[test]
+    fn ranges_and_strings() {
+        assert_eq!(
+            tokens("(1..MAX, ...) '0101'B \"a\"\"b\""),
+            [
+                Token::Punct('('),
+                Token::Number(1),
+                Token::Range,
+                word("MAX"),
+                Token::Punct(','),
+                Token::Ellipsis,
+                Token::Punct(')'),
+                Token::Bits("'0101'B".to_owned()),
+                Token::Text("a\"b".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn error_location() {
+        match tokenize("A ::=\n  B #").err().unwrap() {
+            Error::Syntax { line, column, .. } => assert_eq!((line, column), (2, 5)),
+            other => panic!("unexpected error: {}", other),
+        }
+    }
+}
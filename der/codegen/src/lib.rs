@@ -0,0 +1,190 @@
+//! ASN.1 module compiler for the [`der`] crate.
+//!
+//! Parses ASN.1 module definitions written in (a practical subset of) the
+//! X.680 syntax used by RFCs and generates Rust types which use `der`'s
+//! custom derive support, along with `const-oid` constants for object
+//! identifier value assignments.
+//!
+//! The generated code refers to the `der` crate by name and requires its
+//! `derive` feature, as well as the `oid` and `big-uint` features when the
+//! module contains object identifiers or `INTEGER` types larger than `u16`.
+//!
+//! # Usage
+//!
+//! The compiler is intended to be used from a `build.rs` script:
+//!
+//! ```no_run
+//! use std::{env, path::Path};
+//!
+//! let out_dir = env::var("OUT_DIR").unwrap();
+//!
+//! der_codegen::Compiler::new()
+//!     .external_type("AlgorithmIdentifier", "spki::AlgorithmIdentifier<'a>")
+//!     .compile_file("asn1/example.asn1", Path::new(&out_dir).join("example.rs"))
+//!     .unwrap();
+//! ```
+//!
+//! ...after which the generated code can be included in the crate:
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/example.rs"));
+//! ```
+//!
+//! The crate also contains a `der_codegen` command line tool which writes
+//! the generated code to stdout (or a file given with `-o`).
+//!
+//! # Supported syntax
+//!
+//! - Module headers, including `EXPLICIT`, `IMPLICIT` and `AUTOMATIC` tagging
+//!   defaults. `EXPORTS` are ignored, and `IMPORTS` are resolved using
+//!   [`Compiler::external_type`] and [`Compiler::external_oid`].
+//! - Type assignments of the types listed below, and value assignments of
+//!   `OBJECT IDENTIFIER`, `INTEGER` and `BOOLEAN` types.
+//! - `SIZE` and value range constraints, which are enforced using the
+//!   `#[asn1(size = "...")]` and `#[asn1(range = "...")]` attributes of
+//!   `der`'s custom derive. Value ranges also select the Rust type used for
+//!   `INTEGER` types. Other constraints (e.g. table constraints) are skipped.
+//! - `OPTIONAL` components, which are mapped to `Option`, and `DEFAULT`
+//!   components of `BOOLEAN` and `INTEGER` types, which are generated with the
+//!   `#[asn1(default = "...")]` attribute so absent values decode to the
+//!   default and values equal to the default are omitted when encoding.
+//! - Extension markers (`...`) in `SEQUENCE` types, which are generated with
+//!   the `#[asn1(extensible)]` attribute so unknown trailing elements are
+//!   skipped when decoding. Extension additions are treated like any other
+//!   component.
+//!
+//! Parameterized types, information object classes and `COMPONENTS OF` are
+//! not supported.
+//!
+//! # Type mapping
+//!
+//! | ASN.1 type                           | Rust type                                       |
+//! |--------------------------------------|-------------------------------------------------|
+//! | `ANY`                                | [`der::Any`]                                    |
+//! | `BIT STRING`                         | [`der::BitString`]                              |
+//! | `BOOLEAN`                            | `bool`                                          |
+//! | `CHOICE`                             | enum deriving [`der::Choice`]                   |
+//! | `GeneralizedTime`                    | [`der::GeneralizedTime`]                        |
+//! | `IA5String`                          | [`der::Ia5String`]                              |
+//! | `INTEGER`                            | `u8`, `u16`, `i8` to `i64` or [`der::BigUInt`]  |
+//! | `NULL`                               | [`der::Null`]                                   |
+//! | `OBJECT IDENTIFIER`                  | [`der::ObjectIdentifier`]                       |
+//! | `OCTET STRING`                       | [`der::OctetString`]                            |
+//! | `PrintableString`                    | [`der::PrintableString`]                        |
+//! | `SEQUENCE`                           | struct deriving [`der::Message`]                |
+//! | `SEQUENCE OF`                        | [`der::Sequence`]                               |
+//! | `UTCTime`                            | [`der::UtcTime`]                                |
+//! | `UTF8String`                         | [`der::Utf8String`]                             |
+//! | `[0]` to `[3]` tagged types          | newtype around [`der::Any`]                     |
+//!
+//! `SEQUENCE` and `CHOICE` types nested inside of other types are given names
+//! derived from the enclosing type and component, e.g. `RecordItem` for the
+//! `item` component of `Record`.
+//!
+//! Tagged types are generated as newtypes which check the tag and hold the
+//! tagged value as [`der::Any`], leaving the contents to be decoded by the
+//! caller. Since the `der` crate only supports constructed context-specific
+//! tags, `IMPLICIT` tags on primitive types are not supported. Like the
+//! custom derives, these newtypes impl `Arbitrary` when the `arbitrary` feature
+//! of `der` is enabled, with contents made up of well-formed DER elements.
+//!
+//! `INTEGER` types are mapped to the smallest of `u8`, `u16`, `i8`, `i16`,
+//! `i32` and `i64` able to hold their value range, or [`der::BigUInt`] for
+//! other ranges with a non-negative lower bound. Unconstrained `INTEGER` types
+//! and ranges which may be negative are mapped to `i64`, so e.g.
+//! `Version ::= INTEGER { v1(0) }` becomes `i64`, and values which don't fit
+//! are rejected when decoding. Named numbers don't affect the Rust type.
+
+//! `CHOICE` alternatives are limited to the types supported by the
+//! `#[asn1(type = "...")]` attribute of `der`'s custom derive.
+//!
+//! `SET` and `SET OF` types can only be used inside of tagged types, as `der`
+//! has no `SET` type.
+//!
+//! [`der`]: https://docs.rs/der
+//! [`der::Any`]: https://docs.rs/der/latest/der/struct.Any.html
+//! [`der::BigUInt`]: https://docs.rs/der/latest/der/struct.BigUInt.html
+//! [`der::BitString`]: https://docs.rs/der/latest/der/struct.BitString.html
+//! [`der::Choice`]: https://docs.rs/der/latest/der/trait.Choice.html
+//! [`der::GeneralizedTime`]: https://docs.rs/der/latest/der/struct.GeneralizedTime.html
+//! [`der::Ia5String`]: https://docs.rs/der/latest/der/struct.Ia5String.html
+//! [`der::Message`]: https://docs.rs/der/latest/der/trait.Message.html
+//! [`der::Null`]: https://docs.rs/der/latest/der/struct.Null.html
+//! [`der::ObjectIdentifier`]: https://docs.rs/der/latest/der/struct.ObjectIdentifier.html
+//! [`der::OctetString`]: https://docs.rs/der/latest/der/struct.OctetString.html
+//! [`der::PrintableString`]: https://docs.rs/der/latest/der/struct.PrintableString.html
+//! [`der::Sequence`]: https://docs.rs/der/latest/der/struct.Sequence.html
+//! [`der::UtcTime`]: https://docs.rs/der/latest/der/struct.UtcTime.html
+//! [`der::Utf8String`]: https://docs.rs/der/latest/der/struct.Utf8String.html
+
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo_small.png",
+    html_root_url = "https://docs.rs/der_codegen/0.1.0"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+mod ast;
+mod error;
+mod generator;
+mod lexer;
+mod names;
+mod parser;
+
+pub use crate::error::{Error, Result};
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+/// Compile an ASN.1 module into Rust code using the default configuration.
+pub fn compile(input: &str) -> Result<String> {
+    Compiler::new().compile_str(input)
+}
+
+/// ASN.1 module compiler.
+#[derive(Clone, Debug, Default)]
+pub struct Compiler {
+    /// Rust types for types defined in other modules.
+    external_types: BTreeMap<String, String>,
+
+    /// Object identifiers for values defined in other modules.
+    external_oids: BTreeMap<String, String>,
+}
+
+impl Compiler {
+    /// Create a new compiler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use the given Rust type for references to an ASN.1 type defined in
+    /// another module (e.g. one listed in the module's `IMPORTS`).
+    ///
+    /// Rust types with a lifetime must use `'a`, e.g.
+    /// `spki::AlgorithmIdentifier<'a>`.
+    pub fn external_type(&mut self, name: &str, rust_type: &str) -> &mut Self {
+        self.external_types
+            .insert(name.to_owned(), rust_type.to_owned());
+        self
+    }
+
+    /// Use the given object identifier (in dotted notation) for references
+    /// to an `OBJECT IDENTIFIER` value defined in another module.
+    pub fn external_oid(&mut self, name: &str, oid: &str) -> &mut Self {
+        self.external_oids.insert(name.to_owned(), oid.to_owned());
+        self
+    }
+
+    /// Compile an ASN.1 module into Rust code.
+    pub fn compile_str(&self, input: &str) -> Result<String> {
+        let module = parser::parse(input)?;
+        generator::generate(&module, self)
+    }
+
+    /// Compile the ASN.1 module in the `input` file, writing the generated
+    /// Rust code to the `output` file.
+    pub fn compile_file(&self, input: impl AsRef<Path>, output: impl AsRef<Path>) -> Result<()> {
+        let code = self.compile_str(&fs::read_to_string(input)?)?;
+        fs::write(output, code)?;
+        Ok(())
+    }
+}
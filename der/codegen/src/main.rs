@@ -0,0 +1,74 @@
+//! Command line interface for the `der_codegen` ASN.1 module compiler.
+
+#![forbid(unsafe_code)]
+#![warn(rust_2018_idioms)]
+
+use der_codegen::Compiler;
+use std::{env, fs, process};
+
+const USAGE: &str = "\
+Usage: der_codegen [OPTIONS] <INPUT>
+
+Compile the ASN.1 module in <INPUT> into Rust types for the `der` crate.
+
+Options:
+    -o <FILE>               Write generated code to <FILE> instead of stdout
+    --type <NAME>=<TYPE>    Use Rust type <TYPE> for the external ASN.1 type <NAME>
+    --oid <NAME>=<OID>      Use object identifier <OID> for the external value <NAME>
+    -h, --help              Print this message";
+
+fn main() {
+    if let Err(message) = run(env::args().skip(1)) {
+        eprintln!("error: {}\n\n{}", message, USAGE);
+        process::exit(1);
+    }
+}
+
+fn run(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut compiler = Compiler::new();
+    let mut input = None;
+    let mut output = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            "-o" => output = Some(args.next().ok_or("missing value for `-o`")?),
+            "--type" | "--oid" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("missing value for `{}`", arg))?;
+
+                let mut parts = value.splitn(2, '=');
+                let (name, target) = match (parts.next(), parts.next()) {
+                    (Some(name), Some(target)) => (name, target),
+                    _ => return Err(format!("expected `NAME=VALUE` for `{}`", arg)),
+                };
+
+                if arg == "--type" {
+                    compiler.external_type(name, target);
+                } else {
+                    compiler.external_oid(name, target);
+                }
+            }
+            _ if input.is_none() && !arg.starts_with('-') => input = Some(arg),
+            _ => return Err(format!("unexpected argument `{}`", arg)),
+        }
+    }
+
+    let input = input.ok_or("missing input file")?;
+    let source = fs::read_to_string(&input).map_err(|e| format!("{}: {}", input, e))?;
+    let code = compiler
+        .compile_str(&source)
+        .map_err(|e| format!("{}: {}", input, e))?;
+
+    match output {
+        Some(path) => fs::write(&path, code).map_err(|e| format!("{}: {}", path, e)),
+        None => {
+            print!("{}", code);
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! Conversion of ASN.1 identifiers into Rust identifiers.
+
+/// Rust keywords which can't be used as identifiers without the `r#` prefix.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "static", "struct", "super", "trait", "true", "try", "type", "typeof", "unsafe",
+    "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Convert an ASN.1 identifier to `UpperCamelCase`, e.g. `TBSCertificate`
+/// becomes `TbsCertificate`.
+pub(crate) fn upper_camel_case(name: &str) -> String {
+    words(name)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_ascii_uppercase().to_string() + &chars.as_str().to_ascii_lowercase()
+            })
+        })
+        .collect()
+}
+
+/// Convert an ASN.1 identifier to `snake_case`, e.g. `subjectPublicKey`
+/// becomes `subject_public_key`.
+pub(crate) fn snake_case(name: &str) -> String {
+    let ident = words(name)
+        .iter()
+        .map(|word| word.to_ascii_lowercase())
+        .collect::<Vec<_>>()
+        .join("_");
+
+    if KEYWORDS.contains(&ident.as_str()) {
+        format!("r#{}", ident)
+    } else {
+        ident
+    }
+}
+
+/// Convert an ASN.1 identifier to `SCREAMING_SNAKE_CASE`, e.g.
+/// `id-ecPublicKey` becomes `ID_EC_PUBLIC_KEY`.
+pub(crate) fn screaming_snake_case(name: &str) -> String {
+    words(name)
+        .iter()
+        .map(|word| word.to_ascii_uppercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Split an identifier into words at hyphens and case changes.
+///
+/// A run of capital letters (i.e. an acronym) forms a single word, except
+/// for its last letter when it's followed by a lowercase letter.
+fn words(name: &str) -> Vec<&str> {
+    let chars = name.char_indices().collect::<Vec<_>>();
+    let mut words = Vec::new();
+    let mut start = 0;
+
+    for (i, &(offset, c)) in chars.iter().enumerate() {
+        if c == '-' || c == '_' {
+            if start < offset {
+                words.push(&name[start..offset]);
+            }
+
+            start = offset + 1;
+            continue;
+        }
+
+        if i == 0 || start == offset || !c.is_ascii_uppercase() {
+            continue;
+        }
+
+        let prev = chars[i - 1].1;
+        let next_lowercase = matches!(chars.get(i + 1), Some(&(_, c)) if c.is_ascii_lowercase());
+
+        if prev.is_ascii_lowercase()
+            || prev.is_ascii_digit()
+            || (prev.is_ascii_uppercase() && next_lowercase)
+        {
+            words.push(&name[start..offset]);
+            start = offset;
+        }
+    }
+
+    if start < name.len() {
+        words.push(&name[start..]);
+    }
+
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{screaming_snake_case, snake_case, upper_camel_case};
+
+    #[test]
+    fn types() {
+        assert_eq!(upper_camel_case("Certificate"), "Certificate");
+        assert_eq!(upper_camel_case("TBSCertificate"), "TbsCertificate");
+        assert_eq!(upper_camel_case("ECParameters"), "EcParameters");
+        assert_eq!(upper_camel_case("Ecdsa-Sig-Value"), "EcdsaSigValue");
+        assert_eq!(upper_camel_case("utcTime"), "UtcTime");
+        assert_eq!(upper_camel_case("PKCS7Data"), "Pkcs7Data");
+    }
+
+    #[test]
+    fn fields() {
+        assert_eq!(snake_case("subjectPublicKey"), "subject_public_key");
+        assert_eq!(snake_case("tbsCertificate"), "tbs_certificate");
+        assert_eq!(snake_case("issuerUID"), "issuer_uid");
+        assert_eq!(snake_case("type"), "r#type");
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(screaming_snake_case("id-ecPublicKey"), "ID_EC_PUBLIC_KEY");
+        assert_eq!(screaming_snake_case("ub-name"), "UB_NAME");
+        assert_eq!(screaming_snake_case("secp256r1"), "SECP256R1");
+    }
+}
@@ -0,0 +1,861 @@
+//! Recursive descent parser for ASN.1 module definitions.
+
+use crate::{
+    ast::{
+        Assignment, Component, Components, Constraint, Module, NamedNumber, OidComponent, Presence,
+        Range, StringType, Tag, TagClass, TagDefault, TagMode, Type, TypeKind, Value,
+    },
+    lexer::{syntax_error, tokenize, Spanned, Token},
+    Error, Result,
+};
+
+/// Parse an ASN.1 module definition.
+pub(crate) fn parse(input: &str) -> Result<Module> {
+    let mut parser = Parser {
+        input,
+        tokens: tokenize(input)?,
+        pos: 0,
+    };
+
+    let module = parser.module()?;
+
+    if parser.peek().is_some() {
+        return Err(parser.error("unexpected tokens after `END`"));
+    }
+
+    Ok(module)
+}
+
+/// ASN.1 parser state.
+struct Parser<'a> {
+    /// Module source.
+    input: &'a str,
+
+    /// Tokens of the module.
+    tokens: Vec<Spanned>,
+
+    /// Index of the next token.
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Parse a module definition.
+    fn module(&mut self) -> Result<Module> {
+        let name = self.word()?;
+
+        if self.is_punct('{') {
+            // Module identifier
+            self.skip_balanced()?;
+        }
+
+        self.expect_word("DEFINITIONS")?;
+
+        let tag_default = if self.eat_word("EXPLICIT") {
+            Some(TagDefault::Explicit)
+        } else if self.eat_word("IMPLICIT") {
+            Some(TagDefault::Implicit)
+        } else if self.eat_word("AUTOMATIC") {
+            Some(TagDefault::Automatic)
+        } else {
+            None
+        };
+
+        if tag_default.is_some() {
+            self.expect_word("TAGS")?;
+        }
+
+        if self.eat_word("EXTENSIBILITY") {
+            self.expect_word("IMPLIED")?;
+        }
+
+        self.expect(&Token::Assign)?;
+        self.expect_word("BEGIN")?;
+
+        if self.eat_word("EXPORTS") {
+            while !self.eat_punct(';') {
+                self.next()?;
+            }
+        }
+
+        if self.eat_word("IMPORTS") {
+            self.imports()?;
+        }
+
+        let mut assignments = Vec::new();
+
+        while !self.eat_word("END") {
+            assignments.push(self.assignment()?);
+        }
+
+        Ok(Module {
+            name,
+            tag_default: tag_default.unwrap_or(TagDefault::Explicit),
+            assignments,
+        })
+    }
+
+    /// Skip the symbols imported by an `IMPORTS` statement, which are
+    /// resolved using the [`Compiler`][`crate::Compiler`] configuration.
+    fn imports(&mut self) -> Result<()> {
+        while !self.eat_punct(';') {
+            self.word()?;
+
+            if self.is_punct('{') {
+                // Parameterized reference
+                self.skip_balanced()?;
+            }
+
+            if self.eat_punct(',') {
+                continue;
+            }
+
+            self.expect_word("FROM")?;
+            self.word()?;
+
+            if self.is_punct('{') {
+                self.skip_balanced()?;
+            } else if self.peek_lowercase_word() {
+                // Distinguish a value reference identifying the module from
+                // the first symbol imported from the next module
+                match self.tokens.get(self.pos + 1).map(|t| &t.token) {
+                    Some(Token::Punct(',')) => (),
+                    Some(Token::Word(word)) if word == "FROM" => (),
+                    _ => {
+                        self.next()?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parse a type or value assignment.
+    fn assignment(&mut self) -> Result<Assignment> {
+        let start = self.pos;
+        let name = self.word()?;
+
+        if self.is_punct('{') {
+            return Err(self.error("parameterized assignments are not supported"));
+        }
+
+        if !starts_lowercase(&name) && !self.is_token(&Token::Assign) {
+            return Err(self.error("information object sets are not supported"));
+        }
+
+        let (ty, value) = if starts_lowercase(&name) {
+            let ty = self.ty()?;
+            self.expect(&Token::Assign)?;
+            (ty, Some(self.value()?))
+        } else {
+            self.expect(&Token::Assign)?;
+
+            if self.is_word("CLASS") {
+                return Err(self.error("information object classes are not supported"));
+            }
+
+            (self.ty()?, None)
+        };
+
+        Ok(Assignment {
+            name,
+            ty,
+            value,
+            source: self.block_source(start),
+        })
+    }
+
+    /// Parse a type, including any constraints.
+    fn ty(&mut self) -> Result<Type> {
+        let mut ty = if self.eat_punct('[') {
+            self.tagged()?
+        } else {
+            self.untagged()?
+        };
+
+        while self.is_punct('(') {
+            self.constraint(&mut ty.constraint)?;
+        }
+
+        Ok(ty)
+    }
+
+    /// Parse a tagged type (following the opening `[`).
+    fn tagged(&mut self) -> Result<Type> {
+        let class = if self.eat_word("UNIVERSAL") {
+            TagClass::Universal
+        } else if self.eat_word("APPLICATION") {
+            TagClass::Application
+        } else if self.eat_word("PRIVATE") {
+            TagClass::Private
+        } else {
+            TagClass::ContextSpecific
+        };
+
+        let number = match self.next()? {
+            Token::Number(n) => n,
+            _ => return Err(self.previous_error("expected tag number")),
+        };
+
+        self.expect_punct(']')?;
+
+        let mode = if self.eat_word("IMPLICIT") {
+            Some(TagMode::Implicit)
+        } else if self.eat_word("EXPLICIT") {
+            Some(TagMode::Explicit)
+        } else {
+            None
+        };
+
+        Ok(Type::new(TypeKind::Tagged {
+            tag: Tag { class, number },
+            mode,
+            inner: Box::new(self.ty()?),
+        }))
+    }
+
+    /// Parse a type which isn't tagged.
+    fn untagged(&mut self) -> Result<Type> {
+        let keyword = self.word()?;
+        let mut constraint = Constraint::default();
+
+        let kind = match keyword.as_str() {
+            "ANY" => {
+                if self.eat_word("DEFINED") {
+                    self.expect_word("BY")?;
+                    self.word()?;
+                }
+
+                TypeKind::Any
+            }
+            "BIT" => {
+                self.expect_word("STRING")?;
+                self.named_numbers()?;
+                TypeKind::BitString
+            }
+            "BOOLEAN" => TypeKind::Boolean,
+            "CHOICE" => TypeKind::Choice(self.components()?),
+            "ENUMERATED" => {
+                self.named_numbers()?;
+                TypeKind::Enumerated
+            }
+            "GeneralizedTime" => TypeKind::GeneralizedTime,
+            "INTEGER" => TypeKind::Integer(self.named_numbers()?),
+            "NULL" => TypeKind::Null,
+            "OBJECT" => {
+                self.expect_word("IDENTIFIER")?;
+                TypeKind::ObjectIdentifier
+            }
+            "OCTET" => {
+                self.expect_word("STRING")?;
+                TypeKind::OctetString
+            }
+            "SEQUENCE" | "SET" => {
+                if self.is_punct('{') {
+                    let components = self.components()?;
+
+                    if keyword == "SET" {
+                        TypeKind::Set
+                    } else {
+                        TypeKind::Sequence(components)
+                    }
+                } else {
+                    if self.eat_word("SIZE") {
+                        constraint.size = self.parenthesized_range()?;
+                    } else if self.is_punct('(') {
+                        self.constraint(&mut constraint)?;
+                    }
+
+                    self.expect_word("OF")?;
+
+                    // Skip the optional identifier of the element type
+                    if self.peek_lowercase_word() {
+                        self.next()?;
+                    }
+
+                    let element = Box::new(self.ty()?);
+
+                    if keyword == "SET" {
+                        TypeKind::SetOf
+                    } else {
+                        TypeKind::SequenceOf(element)
+                    }
+                }
+            }
+            "UTCTime" => TypeKind::UtcTime,
+            _ => {
+                if let Some(string_type) = StringType::from_keyword(&keyword) {
+                    TypeKind::String(string_type)
+                } else if starts_lowercase(&keyword) {
+                    return Err(self.previous_error("expected type"));
+                } else {
+                    let mut name = keyword;
+
+                    // External type reference (i.e. `Module.Type`)
+                    if self.eat_punct('.') {
+                        name = self.word()?;
+                    }
+
+                    if self.is_punct('{') {
+                        return Err(self.error("parameterized types are not supported"));
+                    }
+
+                    TypeKind::Reference(name)
+                }
+            }
+        };
+
+        Ok(Type { kind, constraint })
+    }
+
+    /// Parse the components of a `SEQUENCE`, `SET` or `CHOICE`.
+    fn components(&mut self) -> Result<Components> {
+        let mut result = Components::default();
+        self.expect_punct('{')?;
+
+        if self.eat_punct('}') {
+            return Ok(result);
+        }
+
+        loop {
+            if self.eat(&Token::Ellipsis) {
+                result.extensible = true;
+
+                // Exception specification
+                if self.eat_punct('!') {
+                    self.value()?;
+                }
+            } else if self.eat_punct('[') {
+                // Extension addition group, i.e. `[[ ... ]]`
+                self.expect_punct('[')?;
+
+                if let Some(Token::Number(_)) = self.peek() {
+                    self.next()?;
+                    self.expect_punct(':')?;
+                }
+
+                loop {
+                    result.components.push(self.component()?);
+
+                    if !self.eat_punct(',') {
+                        break;
+                    }
+                }
+
+                self.expect_punct(']')?;
+                self.expect_punct(']')?;
+            } else if self.is_word("COMPONENTS") {
+                return Err(self.error("`COMPONENTS OF` is not supported"));
+            } else {
+                result.components.push(self.component()?);
+            }
+
+            if self.eat_punct('}') {
+                return Ok(result);
+            }
+
+            self.expect_punct(',')?;
+        }
+    }
+
+    /// Parse a named component of a `SEQUENCE`, `SET` or `CHOICE`.
+    fn component(&mut self) -> Result<Component> {
+        let start = self.pos;
+
+        if !self.peek_lowercase_word() {
+            return Err(self.error("expected component identifier"));
+        }
+
+        let name = self.word()?;
+        let ty = self.ty()?;
+
+        let presence = if self.eat_word("OPTIONAL") {
+            Presence::Optional
+        } else if self.eat_word("DEFAULT") {
+            Presence::Default(self.value()?)
+        } else {
+            Presence::Required
+        };
+
+        Ok(Component {
+            name,
+            ty,
+            presence,
+            source: self.source(start),
+        })
+    }
+
+    /// Parse the optional named numbers of an `INTEGER`, named bits of a
+    /// `BIT STRING` or enumeration items of an `ENUMERATED`.
+    fn named_numbers(&mut self) -> Result<Vec<NamedNumber>> {
+        let mut result = Vec::new();
+
+        if !self.eat_punct('{') {
+            return Ok(result);
+        }
+
+        loop {
+            if !self.eat(&Token::Ellipsis) {
+                let name = self.word()?;
+
+                let value = if self.eat_punct('(') {
+                    let value = self.value()?;
+                    self.expect_punct(')')?;
+                    value
+                } else {
+                    Value::Integer(result.len() as i128)
+                };
+
+                result.push(NamedNumber { name, value });
+            }
+
+            if self.eat_punct('}') {
+                return Ok(result);
+            }
+
+            self.expect_punct(',')?;
+        }
+    }
+
+    /// Parse a constraint (starting with `(`), recording any size or value
+    /// range constraints which are understood.
+    ///
+    /// Other kinds of constraints (e.g. table constraints) are skipped.
+    fn constraint(&mut self, constraint: &mut Constraint) -> Result<()> {
+        self.expect_punct('(')?;
+
+        if self.eat_word("SIZE") {
+            constraint.size = self.parenthesized_range()?;
+        } else if let Some(range) = self.range()? {
+            constraint.value = Some(range);
+        }
+
+        self.skip_until(')')
+    }
+
+    /// Parse a range constraint surrounded by parentheses.
+    fn parenthesized_range(&mut self) -> Result<Option<Range>> {
+        self.expect_punct('(')?;
+        let range = self.range()?;
+        self.skip_until(')')?;
+        Ok(range)
+    }
+
+    /// Attempt to parse a single value or value range constraint, e.g.
+    /// `1..MAX`, returning `None` (and consuming no input) if the constraint
+    /// isn't of that form.
+    fn range(&mut self) -> Result<Option<Range>> {
+        let start = self.pos;
+
+        let lower = match self.bound()? {
+            Some(bound) => bound,
+            None => return Ok(None),
+        };
+
+        let upper = if self.eat(&Token::Range) {
+            match self.bound()? {
+                Some(bound) => bound,
+                None => {
+                    self.pos = start;
+                    return Ok(None);
+                }
+            }
+        } else {
+            lower.clone()
+        };
+
+        // Unions, intersections, etc are not understood
+        if !self.is_punct(')') && !self.is_punct(',') {
+            self.pos = start;
+            return Ok(None);
+        }
+
+        Ok(Some(Range { lower, upper }))
+    }
+
+    /// Parse a bound of a range: `MIN`, `MAX`, a number or a value reference.
+    fn bound(&mut self) -> Result<Option<Option<Value>>> {
+        let bound = match self.peek() {
+            Some(Token::Word(word)) if word == "MIN" || word == "MAX" => None,
+            Some(Token::Word(word)) if starts_lowercase(word) => {
+                Some(Value::Reference(word.clone()))
+            }
+            Some(Token::Number(n)) => Some(Value::Integer(self.integer(*n)?)),
+            Some(Token::Punct('-')) => return self.value().map(|value| Some(Some(value))),
+            _ => return Ok(None),
+        };
+
+        self.next()?;
+        Ok(Some(bound))
+    }
+
+    /// Parse a value.
+    fn value(&mut self) -> Result<Value> {
+        let start = self.pos;
+
+        Ok(match self.next()? {
+            Token::Number(n) => Value::Integer(self.integer(n)?),
+            Token::Punct('-') => match self.next()? {
+                Token::Number(n) => Value::Integer(-self.integer(n)?),
+                _ => return Err(self.previous_error("expected number")),
+            },
+            Token::Word(word) if word == "TRUE" => Value::Boolean(true),
+            Token::Word(word) if word == "FALSE" => Value::Boolean(false),
+            Token::Word(word) if starts_lowercase(&word) => Value::Reference(word),
+            Token::Punct('{') => {
+                self.pos = start;
+
+                match self.oid_components() {
+                    Ok(components) => Value::ObjectIdentifier(components),
+                    Err(_) => {
+                        self.pos = start;
+                        self.skip_balanced()?;
+                        Value::Other
+                    }
+                }
+            }
+            Token::Word(_) | Token::Bits(_) | Token::Text(_) => Value::Other,
+            _ => return Err(self.previous_error("expected value")),
+        })
+    }
+
+    /// Parse the components of an object identifier value, e.g.
+    /// `{ iso(1) member-body(2) us(840) }`.
+    fn oid_components(&mut self) -> Result<Vec<OidComponent>> {
+        let mut components = Vec::new();
+        self.expect_punct('{')?;
+
+        while !self.eat_punct('}') {
+            components.push(match self.next()? {
+                Token::Number(n) => OidComponent::Number(n),
+                Token::Word(name) if starts_lowercase(&name) => {
+                    if self.eat_punct('(') {
+                        let number = match self.next()? {
+                            Token::Number(n) => n,
+                            _ => return Err(self.previous_error("expected number")),
+                        };
+
+                        self.expect_punct(')')?;
+                        OidComponent::Number(number)
+                    } else {
+                        OidComponent::Name(name)
+                    }
+                }
+                _ => return Err(self.previous_error("expected object identifier component")),
+            });
+        }
+
+        Ok(components)
+    }
+
+    /// Convert a number token into an integer value.
+    fn integer(&self, n: u128) -> Result<i128> {
+        if n > i128::MAX as u128 {
+            return Err(self.error("integer out of range"));
+        }
+
+        Ok(n as i128)
+    }
+
+    /// Skip tokens until the given closing punctuation (which is consumed),
+    /// skipping any nested brackets.
+    fn skip_until(&mut self, close: char) -> Result<()> {
+        while !self.eat_punct(close) {
+            if self.is_punct('(') || self.is_punct('{') || self.is_punct('[') {
+                self.skip_balanced()?;
+            } else {
+                self.next()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Skip a bracketed group of tokens, e.g. `{ ... }`, including any
+    /// nested groups.
+    fn skip_balanced(&mut self) -> Result<()> {
+        let close = match self.next()? {
+            Token::Punct('(') => ')',
+            Token::Punct('{') => '}',
+            Token::Punct('[') => ']',
+            _ => return Err(self.previous_error("expected bracket")),
+        };
+
+        self.skip_until(close)
+    }
+
+    /// Get the source text of the tokens from `start` to the current
+    /// position.
+    fn source(&self, start: usize) -> String {
+        match (self.tokens.get(start), self.tokens.get(self.pos - 1)) {
+            (Some(first), Some(last)) if start < self.pos => {
+                self.input[first.start..last.end].to_owned()
+            }
+            _ => String::new(),
+        }
+    }
+
+    /// Get the source text of the tokens from `start` to the current
+    /// position, including the indentation of the first line.
+    fn block_source(&self, start: usize) -> String {
+        let offset = self.tokens.get(start).map_or(0, |t| t.start);
+        let line_start = self.input[..offset].rfind('\n').map_or(0, |n| n + 1);
+        let indent = &self.input[line_start..offset];
+
+        if indent.trim().is_empty() {
+            format!("{}{}", indent, self.source(start))
+        } else {
+            self.source(start)
+        }
+    }
+
+    /// Peek at the next token.
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|t| &t.token)
+    }
+
+    /// Is the next token the given token?
+    fn is_token(&self, token: &Token) -> bool {
+        self.peek() == Some(token)
+    }
+
+    /// Is the next token the given punctuation?
+    fn is_punct(&self, c: char) -> bool {
+        self.is_token(&Token::Punct(c))
+    }
+
+    /// Is the next token the given word?
+    fn is_word(&self, word: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(w)) if w == word)
+    }
+
+    /// Is the next token a word starting with a lowercase letter (i.e. an
+    /// identifier or value reference)?
+    fn peek_lowercase_word(&self) -> bool {
+        matches!(self.peek(), Some(Token::Word(w)) if starts_lowercase(w))
+    }
+
+    /// Get the next token.
+    fn next(&mut self) -> Result<Token> {
+        let token = self
+            .peek()
+            .cloned()
+            .ok_or_else(|| self.error("unexpected end of module"))?;
+
+        self.pos += 1;
+        Ok(token)
+    }
+
+    /// Get the next token, which must be a word.
+    fn word(&mut self) -> Result<String> {
+        match self.next()? {
+            Token::Word(word) => Ok(word),
+            _ => Err(self.previous_error("expected identifier")),
+        }
+    }
+
+    /// Consume the next token if it matches the given token.
+    fn eat(&mut self, token: &Token) -> bool {
+        let matches = self.is_token(token);
+
+        if matches {
+            self.pos += 1;
+        }
+
+        matches
+    }
+
+    /// Consume the next token if it's the given punctuation.
+    fn eat_punct(&mut self, c: char) -> bool {
+        self.eat(&Token::Punct(c))
+    }
+
+    /// Consume the next token if it's the given word.
+    fn eat_word(&mut self, word: &str) -> bool {
+        let matches = self.is_word(word);
+
+        if matches {
+            self.pos += 1;
+        }
+
+        matches
+    }
+
+    /// Consume the given token, returning an error if it isn't next.
+    fn expect(&mut self, token: &Token) -> Result<()> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected {}", describe(token))))
+        }
+    }
+
+    /// Consume the given punctuation, returning an error if it isn't next.
+    fn expect_punct(&mut self, c: char) -> Result<()> {
+        self.expect(&Token::Punct(c))
+    }
+
+    /// Consume the given word, returning an error if it isn't next.
+    fn expect_word(&mut self, word: &str) -> Result<()> {
+        self.expect(&Token::Word(word.to_owned()))
+    }
+
+    /// Create a syntax error at the next token.
+    fn error(&self, message: impl Into<String>) -> Error {
+        let offset = self
+            .tokens
+            .get(self.pos)
+            .map_or(self.input.len(), |t| t.start);
+
+        syntax_error(self.input, offset, message)
+    }
+
+    /// Create a syntax error at the previous token.
+    fn previous_error(&self, message: impl Into<String>) -> Error {
+        let offset = self.tokens.get(self.pos - 1).map_or(0, |t| t.start);
+        syntax_error(self.input, offset, message)
+    }
+}
+
+/// Does the given identifier start with a lowercase letter?
+fn starts_lowercase(word: &str) -> bool {
+    word.starts_with(|c: char| c.is_ascii_lowercase())
+}
+
+/// Describe a token for use in error messages.
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Word(word) => format!("`{}`", word),
+        Token::Assign => "`::=`".to_owned(),
+        Token::Punct(c) => format!("`{}`", c),
+        other => format!("{:?}", other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use crate::{
+        ast::{OidComponent, Presence, TagClass, TagDefault, TagMode, TypeKind, Value},
+        Error,
+    };
+
+    const MODULE: &str = r#"
+        Example { iso(1) 2 } DEFINITIONS IMPLICIT TAGS ::= BEGIN
+        IMPORTS AlgorithmIdentifier FROM PKIX1Explicit88 { iso(1) 3 } id-pkix
+                FROM Other-Module ;
+
+        Record ::= SEQUENCE {
+            version   [0] EXPLICIT INTEGER { v1(0), v2(1) } DEFAULT v1,
+            name      UTF8String (SIZE (1..64)),
+            items     SEQUENCE SIZE (1..MAX) OF Item,
+            flag      BOOLEAN OPTIONAL,
+            ...
+        }
+
+        Item ::= INTEGER (0..ub-item)
+
+        ub-item INTEGER ::= 255
+
+        id-example OBJECT IDENTIFIER ::= { id-pkix 42 }
+        END
+    "#;
+
+    #[test]
+    fn module_header() {
+        let module = parse(MODULE).unwrap();
+        assert_eq!(module.name, "Example");
+        assert_eq!(module.tag_default, TagDefault::Implicit);
+        assert_eq!(module.assignments.len(), 4);
+    }
+
+    #[test]
+    fn sequence() {
+        let module = parse(MODULE).unwrap();
+        let record = &module.assignments[0];
+        assert_eq!(record.name, "Record");
+        assert!(record.source.starts_with("        Record ::= SEQUENCE {"));
+        assert!(record.source.ends_with('}'));
+
+        let components = match &record.ty.kind {
+            TypeKind::Sequence(components) => components,
+            other => panic!("unexpected type: {:?}", other),
+        };
+
+        assert!(components.extensible);
+        assert_eq!(components.components.len(), 4);
+
+        let version = &components.components[0];
+        assert!(matches!(
+            &version.presence,
+            Presence::Default(Value::Reference(name)) if name == "v1"
+        ));
+
+        match &version.ty.kind {
+            TypeKind::Tagged { tag, mode, inner } => {
+                assert_eq!(tag.class, TagClass::ContextSpecific);
+                assert_eq!(tag.number, 0);
+                assert_eq!(*mode, Some(TagMode::Explicit));
+                assert!(matches!(&inner.kind, TypeKind::Integer(n) if n.len() == 2));
+            }
+            other => panic!("unexpected type: {:?}", other),
+        }
+
+        let name = &components.components[1];
+        assert_eq!(name.source, "name      UTF8String (SIZE (1..64))");
+        let size = name.ty.constraint.size.as_ref().unwrap();
+        assert!(matches!(size.lower, Some(Value::Integer(1))));
+        assert!(matches!(size.upper, Some(Value::Integer(64))));
+
+        let items = &components.components[2];
+        assert!(matches!(&items.ty.kind, TypeKind::SequenceOf(_)));
+        assert!(items.ty.constraint.size.as_ref().unwrap().upper.is_none());
+
+        assert!(matches!(
+            components.components[3].presence,
+            Presence::Optional
+        ));
+    }
+
+    #[test]
+    fn value_assignments() {
+        let module = parse(MODULE).unwrap();
+        assert!(matches!(
+            module.assignments[2].value,
+            Some(Value::Integer(255))
+        ));
+
+        match &module.assignments[3].value {
+            Some(Value::ObjectIdentifier(components)) => {
+                assert!(matches!(&components[0], OidComponent::Name(n) if n == "id-pkix"));
+                assert!(matches!(components[1], OidComponent::Number(42)));
+            }
+            other => panic!("unexpected value: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn skips_unknown_constraints() {
+        let module = parse(
+            "M DEFINITIONS ::= BEGIN
+             A ::= OCTET STRING (CONTAINING B) (SIZE (1 | 4))
+             END",
+        )
+        .unwrap();
+
+        let constraint = &module.assignments[0].ty.constraint;
+        assert!(constraint.size.is_none());
+        assert!(constraint.value.is_none());
+    }
+
+    #[test]
+    fn syntax_errors() {
+        match parse("M DEFINITIONS ::= BEGIN\nA ::= SEQUENCE { a }\nEND") {
+            Err(Error::Syntax { line, column, .. }) => assert_eq!((line, column), (2, 20)),
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        assert!(parse("M DEFINITIONS ::= BEGIN A{T} ::= SEQUENCE {} END").is_err());
+        assert!(parse("M DEFINITIONS ::= BEGIN A ::= INTEGER").is_err());
+    }
+}
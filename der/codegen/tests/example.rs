@@ -0,0 +1,196 @@
+//! Tests for the code generated from `tests/modules/example.asn1`.
+
+use core::convert::TryFrom;
+use der::{Any, Decodable, Encodable, Message, ObjectIdentifier};
+use hex_literal::hex;
+use std::time::Duration;
+
+/// Generated code (regenerate with the `der_codegen` CLI if the compiler or
+/// module changes).
+#[allow(dead_code)]
+mod example {
+    include!("modules/example.rs");
+}
+
+use example::*;
+
+/// Stand-in for `AlgorithmIdentifier`, imported by the example module.
+#[derive(Clone, Debug, Eq, PartialEq, Message)]
+pub struct AlgorithmIdentifier<'a> {
+    pub algorithm: ObjectIdentifier,
+    pub parameters: Option<Any<'a>>,
+}
+
+const P256_PRIV_DER: &[u8] = include_bytes!("../../../pkcs8/tests/examples/p256-priv.der");
+const P256_PUB_DER: &[u8] = include_bytes!("../../../pkcs8/tests/examples/p256-pub.der");
+
+#[test]
+fn generated_code_is_up_to_date() {
+    let code = der_codegen::Compiler::new()
+        .external_type("AlgorithmIdentifier", "crate::AlgorithmIdentifier<'a>")
+        .compile_str(include_str!("modules/example.asn1"))
+        .unwrap();
+
+    assert_eq!(code, include_str!("modules/example.rs"));
+}
+
+#[test]
+fn oid_constants() {
+    assert_eq!(ID_EC_PUBLIC_KEY.to_string(), "1.2.840.10045.2.1");
+    assert_eq!(SECP256R1.to_string(), "1.2.840.10045.3.1.7");
+    assert_eq!(UB_NAME, 64);
+}
+
+#[test]
+fn decode_private_key_info() {
+    let pk = PrivateKeyInfo::from_bytes(P256_PRIV_DER).unwrap();
+    assert_eq!(pk.version, VERSION_V1);
+    assert_eq!(pk.private_key_algorithm.algorithm, ID_EC_PUBLIC_KEY);
+    assert_eq!(
+        pk.private_key_algorithm.parameters.unwrap().oid().unwrap(),
+        SECP256R1
+    );
+    assert_eq!(pk.attributes, None);
+    assert_eq!(pk.to_vec().unwrap(), P256_PRIV_DER);
+
+    let ec = EcPrivateKey::from_bytes(pk.private_key.as_bytes()).unwrap();
+    assert_eq!(ec.version, 1);
+    assert_eq!(ec.private_key.as_bytes().len(), 32);
+    assert_eq!(ec.parameters, None);
+
+    // Contents of the explicitly tagged `[1]` are a `BIT STRING`
+    let public_key = der::BitString::from_bytes(ec.public_key.unwrap().0.as_bytes()).unwrap();
+    assert_eq!(public_key.as_bytes().len(), 65);
+    assert_eq!(public_key.as_bytes()[0], 0x04);
+}
+
+#[test]
+fn decode_subject_public_key_info() {
+    let spki = SubjectPublicKeyInfo::from_bytes(P256_PUB_DER).unwrap();
+    assert_eq!(spki.algorithm.algorithm, ID_EC_PUBLIC_KEY);
+    assert_eq!(spki.subject_public_key.as_bytes().len(), 65);
+    assert_eq!(spki.to_vec().unwrap(), P256_PUB_DER);
+}
+
+#[test]
+fn tagged_types_check_tag() {
+    let any = Any::new(der::Tag::ContextSpecific0, &[0x03, 0x01, 0x00]).unwrap();
+    assert!(EcPrivateKeyParameters::try_from(any).is_ok());
+    assert!(EcPrivateKeyPublicKey::try_from(any).is_err());
+}
+
+#[test]
+fn choice_round_trip() {
+    for label in &[Label::Text(b"example"), Label::Octets(&[1, 2, 3])] {
+        let der = label.to_vec().unwrap();
+        assert_eq!(&Label::from_bytes(&der).unwrap(), label);
+    }
+
+    assert_eq!(
+        Label::from_bytes(&hex!("04 02 01 02")).unwrap(),
+        Label::Octets(&[1, 2])
+    );
+}
+
+#[test]
+fn record_round_trip() {
+    // `entries` of 5 and 256
+    let entries = hex!("02 01 05 02 02 01 00");
+    let time = der::UtcTime::new(Duration::from_secs(673573540)).unwrap();
+
+    let record = Record {
+        name: der::Utf8String::new("example").unwrap(),
+        enabled: false,
+        validity: RecordValidity {
+            not_before: Time::UtcTime(time),
+            not_after: Time::UtcTime(time),
+        },
+        entries: der::Sequence::new(&entries).unwrap(),
+    };
+
+    let der = record.to_vec().unwrap();
+    assert_eq!(Record::from_bytes(&der).unwrap(), record);
+
+    // `Record` is extensible, so unknown trailing elements are skipped
+    let mut extended = der.clone();
+    extended[1] += 2;
+    extended.extend_from_slice(&hex!("05 00"));
+    assert_eq!(Record::from_bytes(&extended).unwrap(), record);
+
+    let mut decoder = der::Decoder::new(record.entries.as_bytes());
+    assert_eq!(decoder.decode::<Entry>().unwrap(), 5);
+    assert_eq!(decoder.decode::<Entry>().unwrap(), 256);
+    assert!(decoder.is_finished());
+}
+
+#[test]
+fn record_default_component() {
+    let entries = hex!("02 01 05");
+    let time = der::UtcTime::new(Duration::from_secs(673573540)).unwrap();
+    let mut record = Record {
+        name: der::Utf8String::new("example").unwrap(),
+        enabled: true,
+        validity: RecordValidity {
+            not_before: Time::UtcTime(time),
+            not_after: Time::UtcTime(time),
+        },
+        entries: der::Sequence::new(&entries).unwrap(),
+    };
+
+    // `enabled` is omitted when equal to its default of `TRUE`
+    let der = record.to_vec().unwrap();
+    record.enabled = false;
+    let with_enabled = record.to_vec().unwrap();
+    assert_eq!(with_enabled.len(), der.len() + 3);
+
+    record.enabled = true;
+    assert_eq!(Record::from_bytes(&der).unwrap(), record);
+
+    // DER forbids encoding the default value explicitly
+    let mut explicit = with_enabled.clone();
+    let pos = with_enabled
+        .windows(3)
+        .position(|tlv| tlv == hex!("01 01 00"))
+        .unwrap();
+    explicit[pos + 2] = 0xFF;
+    assert_eq!(
+        Record::from_bytes(&explicit).unwrap_err().kind(),
+        der::ErrorKind::Noncanonical
+    );
+}
+
+#[test]
+fn constraints_are_enforced() {
+    let entries = hex!("02 01 05");
+    let time = der::UtcTime::new(Duration::from_secs(673573540)).unwrap();
+    let mut record = Record {
+        name: der::Utf8String::new("").unwrap(),
+        enabled: true,
+        validity: RecordValidity {
+            not_before: Time::UtcTime(time),
+            not_after: Time::UtcTime(time),
+        },
+        entries: der::Sequence::new(&entries).unwrap(),
+    };
+
+    // `name` is `SIZE (1..ub-name)`, inclusive of both bounds
+    assert!(record.to_vec().is_err());
+
+    let long_name = "x".repeat(64);
+    record.name = der::Utf8String::new(&long_name).unwrap();
+    let der = record.to_vec().unwrap();
+    assert_eq!(Record::from_bytes(&der).unwrap(), record);
+
+    let too_long = "x".repeat(65);
+    record.name = der::Utf8String::new(&too_long).unwrap();
+    assert!(record.to_vec().is_err());
+
+    // `version` of `ECPrivateKey` is constrained to `ecPrivkeyVer1`
+    let ec = hex!("30 08 020102 0403010203");
+    assert_eq!(
+        EcPrivateKey::from_bytes(&ec).unwrap_err().kind(),
+        der::ErrorKind::Constraint {
+            tag: der::Tag::Integer
+        }
+    );
+}
@@ -0,0 +1,338 @@
+// Generated by der_codegen from the `Example` ASN.1 module. Do not edit.
+
+/// `ansi-X9-62 OBJECT IDENTIFIER ::= { iso(1) member-body(2) us(840) 10045 }`
+pub const ANSI_X9_62: der::ObjectIdentifier = der::ObjectIdentifier::parse("1.2.840.10045");
+
+/// `id-ecPublicKey OBJECT IDENTIFIER ::= { ansi-X9-62 keyType(2) 1 }`
+pub const ID_EC_PUBLIC_KEY: der::ObjectIdentifier = der::ObjectIdentifier::parse("1.2.840.10045.2.1");
+
+/// `secp256r1 OBJECT IDENTIFIER ::= { iso(1) member-body(2) us(840) ansi-X9-62(10045) curves(3) prime(1) 7 }`
+pub const SECP256R1: der::ObjectIdentifier = der::ObjectIdentifier::parse("1.2.840.10045.3.1.7");
+
+/// `ub-name INTEGER ::= 64`
+pub const UB_NAME: i64 = 64;
+
+/// `SubjectPublicKeyInfo` as defined in the `Example` ASN.1 module.
+///
+/// ```text
+/// SubjectPublicKeyInfo ::= SEQUENCE {
+///     algorithm         AlgorithmIdentifier,
+///     subjectPublicKey  BIT STRING }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, der::Message)]
+pub struct SubjectPublicKeyInfo<'a> {
+    /// `algorithm AlgorithmIdentifier`
+    pub algorithm: crate::AlgorithmIdentifier<'a>,
+
+    /// `subjectPublicKey BIT STRING`
+    pub subject_public_key: der::BitString<'a>,
+}
+
+/// `Version` as defined in the `Example` ASN.1 module.
+///
+/// ```text
+/// Version ::= INTEGER { v1(0) }
+/// ```
+pub type Version = i64;
+
+/// `v1` value of [`Version`].
+pub const VERSION_V1: Version = 0;
+
+/// `PrivateKeyInfo` as defined in the `Example` ASN.1 module.
+///
+/// ```text
+/// PrivateKeyInfo ::= SEQUENCE {
+///     version                   Version,
+///     privateKeyAlgorithm       AlgorithmIdentifier,
+///     privateKey                PrivateKey,
+///     attributes           [0]  IMPLICIT Attributes OPTIONAL }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, der::Message)]
+pub struct PrivateKeyInfo<'a> {
+    /// `version Version`
+    pub version: Version,
+
+    /// `privateKeyAlgorithm AlgorithmIdentifier`
+    pub private_key_algorithm: crate::AlgorithmIdentifier<'a>,
+
+    /// `privateKey PrivateKey`
+    pub private_key: PrivateKey<'a>,
+
+    /// `attributes [0] IMPLICIT Attributes OPTIONAL`
+    pub attributes: Option<PrivateKeyInfoAttributes<'a>>,
+}
+
+/// Type of the `attributes` component of [`PrivateKeyInfo`].
+///
+/// The contents of the wrapped [`der::Any`] are the contents of the implicitly tagged value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PrivateKeyInfoAttributes<'a>(pub der::Any<'a>);
+
+impl<'a> core::convert::TryFrom<der::Any<'a>> for PrivateKeyInfoAttributes<'a> {
+    type Error = der::Error;
+
+    fn try_from(any: der::Any<'a>) -> der::Result<Self> {
+        any.tag().assert_eq(der::Tag::ContextSpecific0)?;
+        Ok(Self(any))
+    }
+}
+
+impl der::Encodable for PrivateKeyInfoAttributes<'_> {
+    fn encoded_len(&self) -> der::Result<der::Length> {
+        self.0.encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut der::Encoder<'_>) -> der::Result<()> {
+        self.0.encode(encoder)
+    }
+}
+
+impl der::Tagged for PrivateKeyInfoAttributes<'_> {
+    const TAG: der::Tag = der::Tag::ContextSpecific0;
+}
+
+der::__impl_arbitrary! {
+    impl<'a> der::arbitrary::Arbitrary<'a> for PrivateKeyInfoAttributes<'a> {
+        fn arbitrary(u: &mut der::arbitrary::Unstructured<'a>) -> der::arbitrary::Result<Self> {
+            let contents = <der::Sequence<'a> as der::arbitrary::Arbitrary<'a>>::arbitrary(u)?.as_bytes();
+
+            der::Any::new(der::Tag::ContextSpecific0, contents)
+                .map(Self)
+                .map_err(|_| der::arbitrary::Error::IncorrectFormat)
+        }
+    }
+}
+
+/// `PrivateKey` as defined in the `Example` ASN.1 module.
+///
+/// ```text
+/// PrivateKey ::= OCTET STRING
+/// ```
+pub type PrivateKey<'a> = der::OctetString<'a>;
+
+// `Attributes` is a `SET` type, which can only be used inside of tagged types.
+
+/// `ECPrivateKey` as defined in the `Example` ASN.1 module.
+///
+/// ```text
+/// ECPrivateKey ::= SEQUENCE {
+///     version        INTEGER { ecPrivkeyVer1(1) } (ecPrivkeyVer1),
+///     privateKey     OCTET STRING,
+///     parameters [0] OBJECT IDENTIFIER OPTIONAL, -- namedCurve
+///     publicKey  [1] BIT STRING OPTIONAL }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, der::Message)]
+pub struct EcPrivateKey<'a> {
+    /// `version INTEGER { ecPrivkeyVer1(1) } (ecPrivkeyVer1)`
+    #[asn1(range = "1")]
+    pub version: u8,
+
+    /// `privateKey OCTET STRING`
+    pub private_key: der::OctetString<'a>,
+
+    /// `parameters [0] OBJECT IDENTIFIER OPTIONAL`
+    pub parameters: Option<EcPrivateKeyParameters<'a>>,
+
+    /// `publicKey [1] BIT STRING OPTIONAL`
+    pub public_key: Option<EcPrivateKeyPublicKey<'a>>,
+}
+
+/// Type of the `parameters` component of [`EcPrivateKey`].
+///
+/// The contents of the wrapped [`der::Any`] are the DER encoding of the explicitly tagged value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct EcPrivateKeyParameters<'a>(pub der::Any<'a>);
+
+impl<'a> core::convert::TryFrom<der::Any<'a>> for EcPrivateKeyParameters<'a> {
+    type Error = der::Error;
+
+    fn try_from(any: der::Any<'a>) -> der::Result<Self> {
+        any.tag().assert_eq(der::Tag::ContextSpecific0)?;
+        Ok(Self(any))
+    }
+}
+
+impl der::Encodable for EcPrivateKeyParameters<'_> {
+    fn encoded_len(&self) -> der::Result<der::Length> {
+        self.0.encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut der::Encoder<'_>) -> der::Result<()> {
+        self.0.encode(encoder)
+    }
+}
+
+impl der::Tagged for EcPrivateKeyParameters<'_> {
+    const TAG: der::Tag = der::Tag::ContextSpecific0;
+}
+
+der::__impl_arbitrary! {
+    impl<'a> der::arbitrary::Arbitrary<'a> for EcPrivateKeyParameters<'a> {
+        fn arbitrary(u: &mut der::arbitrary::Unstructured<'a>) -> der::arbitrary::Result<Self> {
+            let tlvs = <der::Sequence<'a> as der::arbitrary::Arbitrary<'a>>::arbitrary(u)?.as_bytes();
+            let len = der::Decoder::new(tlvs)
+                .any()
+                .and_then(|any| der::Encodable::encoded_len(&any))
+                .map_err(|_| der::arbitrary::Error::IncorrectFormat)?;
+            let contents = &tlvs[..len.to_usize()];
+
+            der::Any::new(der::Tag::ContextSpecific0, contents)
+                .map(Self)
+                .map_err(|_| der::arbitrary::Error::IncorrectFormat)
+        }
+    }
+}
+
+/// Type of the `publicKey` component of [`EcPrivateKey`].
+///
+/// The contents of the wrapped [`der::Any`] are the DER encoding of the explicitly tagged value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct EcPrivateKeyPublicKey<'a>(pub der::Any<'a>);
+
+impl<'a> core::convert::TryFrom<der::Any<'a>> for EcPrivateKeyPublicKey<'a> {
+    type Error = der::Error;
+
+    fn try_from(any: der::Any<'a>) -> der::Result<Self> {
+        any.tag().assert_eq(der::Tag::ContextSpecific1)?;
+        Ok(Self(any))
+    }
+}
+
+impl der::Encodable for EcPrivateKeyPublicKey<'_> {
+    fn encoded_len(&self) -> der::Result<der::Length> {
+        self.0.encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut der::Encoder<'_>) -> der::Result<()> {
+        self.0.encode(encoder)
+    }
+}
+
+impl der::Tagged for EcPrivateKeyPublicKey<'_> {
+    const TAG: der::Tag = der::Tag::ContextSpecific1;
+}
+
+der::__impl_arbitrary! {
+    impl<'a> der::arbitrary::Arbitrary<'a> for EcPrivateKeyPublicKey<'a> {
+        fn arbitrary(u: &mut der::arbitrary::Unstructured<'a>) -> der::arbitrary::Result<Self> {
+            let tlvs = <der::Sequence<'a> as der::arbitrary::Arbitrary<'a>>::arbitrary(u)?.as_bytes();
+            let len = der::Decoder::new(tlvs)
+                .any()
+                .and_then(|any| der::Encodable::encoded_len(&any))
+                .map_err(|_| der::arbitrary::Error::IncorrectFormat)?;
+            let contents = &tlvs[..len.to_usize()];
+
+            der::Any::new(der::Tag::ContextSpecific1, contents)
+                .map(Self)
+                .map_err(|_| der::arbitrary::Error::IncorrectFormat)
+        }
+    }
+}
+
+/// `Time` as defined in the `Example` ASN.1 module.
+///
+/// ```text
+/// Time ::= CHOICE {
+///     utcTime        UTCTime,
+///     generalTime    GeneralizedTime }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, der::Choice)]
+pub enum Time {
+    /// `utcTime UTCTime`
+    #[asn1(type = "UTCTime")]
+    UtcTime(der::UtcTime),
+
+    /// `generalTime GeneralizedTime`
+    #[asn1(type = "GeneralizedTime")]
+    GeneralTime(der::GeneralizedTime),
+}
+
+impl From<der::UtcTime> for Time {
+    fn from(value: der::UtcTime) -> Self {
+        Time::UtcTime(value)
+    }
+}
+
+impl From<der::GeneralizedTime> for Time {
+    fn from(value: der::GeneralizedTime) -> Self {
+        Time::GeneralTime(value)
+    }
+}
+
+/// `Label` as defined in the `Example` ASN.1 module.
+///
+/// ```text
+/// Label ::= CHOICE {
+///     text           UTF8String,
+///     octets         OCTET STRING }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, der::Choice)]
+pub enum Label<'a> {
+    /// `text UTF8String`
+    #[asn1(type = "UTF8String")]
+    Text(&'a [u8]),
+
+    /// `octets OCTET STRING`
+    #[asn1(type = "OCTET STRING")]
+    Octets(&'a [u8]),
+}
+
+impl<'a> From<der::Utf8String<'a>> for Label<'a> {
+    fn from(value: der::Utf8String<'a>) -> Self {
+        Label::Text(value.into())
+    }
+}
+
+impl<'a> From<der::OctetString<'a>> for Label<'a> {
+    fn from(value: der::OctetString<'a>) -> Self {
+        Label::Octets(value.into())
+    }
+}
+
+/// `Record` as defined in the `Example` ASN.1 module.
+///
+/// ```text
+/// Record ::= SEQUENCE {
+///     name        UTF8String (SIZE (1..ub-name)),
+///     enabled     BOOLEAN DEFAULT TRUE,
+///     validity    SEQUENCE {
+///         notBefore  Time,
+///         notAfter   Time },
+///     entries     SEQUENCE SIZE (1..MAX) OF Entry,
+///     ... }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, der::Message)]
+#[asn1(extensible)]
+pub struct Record<'a> {
+    /// `name UTF8String (SIZE (1..ub-name))`
+    #[asn1(size = "1..=64")]
+    pub name: der::Utf8String<'a>,
+
+    /// `enabled BOOLEAN DEFAULT TRUE`
+    #[asn1(default = "true")]
+    pub enabled: bool,
+
+    /// `validity SEQUENCE { notBefore Time, notAfter Time }`
+    pub validity: RecordValidity,
+
+    /// `entries SEQUENCE SIZE (1..MAX) OF Entry`
+    #[asn1(size = "1..")]
+    pub entries: der::Sequence<'a>,
+}
+
+/// Type of the `validity` component of [`Record`].
+#[derive(Clone, Debug, Eq, PartialEq, der::Message)]
+pub struct RecordValidity {
+    /// `notBefore Time`
+    pub not_before: Time,
+
+    /// `notAfter Time`
+    pub not_after: Time,
+}
+
+/// `Entry` as defined in the `Example` ASN.1 module.
+///
+/// ```text
+/// Entry ::= INTEGER (0..65535)
+/// ```
+pub type Entry = u16;
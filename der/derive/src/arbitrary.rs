@@ -0,0 +1,42 @@
+//! Support for deriving the `arbitrary` crate's `Arbitrary` trait, which is
+//! emitted when the `arbitrary` feature of `der` is enabled.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use synstructure::Structure;
+
+/// Derive `Arbitrary` for types with no generic parameters or a single
+/// lifetime parameter, using the given impl body to generate a value.
+///
+/// Generated values which fail to encode (e.g. because they violate a
+/// constraint) are rejected, ensuring they always round-trip.
+pub(crate) fn derive_arbitrary(s: &Structure<'_>, body: TokenStream) -> TokenStream {
+    let ident = &s.ast().ident;
+    let generics = &s.ast().generics;
+
+    let (lifetime, ty) = if generics.params.is_empty() {
+        (quote!('arbitrary), quote!(#ident))
+    } else if generics.params.len() == 1 && generics.lifetimes().count() == 1 {
+        let lifetime = &generics.lifetimes().next().unwrap().lifetime;
+        (quote!(#lifetime), quote!(#ident<#lifetime>))
+    } else {
+        return TokenStream::new();
+    };
+
+    quote! {
+        ::der::__impl_arbitrary! {
+            impl<#lifetime> ::der::arbitrary::Arbitrary<#lifetime> for #ty {
+                fn arbitrary(
+                    u: &mut ::der::arbitrary::Unstructured<#lifetime>,
+                ) -> ::der::arbitrary::Result<Self> {
+                    let value = #body;
+
+                    match ::der::Encodable::encoded_len(&value) {
+                        Ok(_) => Ok(value),
+                        Err(_) => Err(::der::arbitrary::Error::IncorrectFormat),
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,50 +1,107 @@
 //! Attribute-related types used by the proc macro
 
-use crate::Asn1Type;
+use crate::{Asn1Type, Constraint};
+use proc_macro2::TokenStream;
 use syn::{Attribute, Lit, Meta, MetaList, MetaNameValue, NestedMeta};
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub(crate) struct Asn1Attrs {
     /// Value of the `#[asn1(type = "...")]` attribute if provided
     pub asn1_type: Option<Asn1Type>,
+
+    /// Value of the `#[asn1(size = "...")]` attribute if provided
+    pub size: Option<Constraint>,
+
+    /// Value of the `#[asn1(range = "...")]` attribute if provided
+    pub range: Option<Constraint>,
+
+    /// Value of the `#[asn1(default = "...")]` attribute if provided
+    pub default: Option<TokenStream>,
+
+    /// Is the `#[asn1(encapsulates)]` attribute present?
+    pub encapsulates: bool,
+
+    /// Is the `#[asn1(extensible)]` attribute present?
+    pub extensible: bool,
+
+    /// Is the `#[asn1(extensions)]` attribute present?
+    pub extensions: bool,
 }
 
 impl Asn1Attrs {
     /// Parse attributes from a field or enum variant
     pub fn new(attrs: &[Attribute]) -> Self {
-        let mut asn1_type = None;
+        let mut result = Self::default();
 
         for attr in attrs {
             if !attr.path.is_ident("asn1") {
                 continue;
             }
 
-            match attr.parse_meta().expect("error parsing `asn1` attribute") {
-                Meta::List(MetaList { nested, .. }) if nested.len() == 1 => {
-                    match nested.first() {
-                        Some(NestedMeta::Meta(Meta::NameValue(MetaNameValue {
-                            path,
-                            lit: Lit::Str(lit_str),
-                            ..
-                        }))) => {
+            let nested = match attr.parse_meta().expect("error parsing `asn1` attribute") {
+                Meta::List(MetaList { nested, .. }) if !nested.is_empty() => nested,
+                other => panic!("malformed `asn1` attribute: {:?}", other),
+            };
+
+            for meta in &nested {
+                match meta {
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(lit_str),
+                        ..
+                    })) => {
+                        let value = lit_str.value();
+
+                        if path.is_ident("type") {
                             // Parse the `type = "..."` attribute
-                            if !path.is_ident("type") {
-                                panic!("unknown `asn1` attribute: {:?}", path);
+                            if let Some(ty) = result.asn1_type {
+                                panic!("duplicate ASN.1 `type` attribute: {:?}", ty);
                             }
 
-                            if let Some(ty) = asn1_type {
-                                panic!("duplicate ASN.1 `type` attribute: {:?}", ty);
+                            result.asn1_type = Some(Asn1Type::new(&value));
+                        } else if path.is_ident("size") {
+                            // Parse the `size = "..."` attribute
+                            if result.size.is_some() {
+                                panic!("duplicate ASN.1 `size` attribute: {:?}", value);
                             }
 
-                            asn1_type = Some(Asn1Type::new(&lit_str.value()));
+                            result.size = Some(Constraint::new("size", &value));
+                        } else if path.is_ident("range") {
+                            // Parse the `range = "..."` attribute
+                            if result.range.is_some() {
+                                panic!("duplicate ASN.1 `range` attribute: {:?}", value);
+                            }
+
+                            result.range = Some(Constraint::new("range", &value));
+                        } else if path.is_ident("default") {
+                            // Parse the `default = "..."` attribute
+                            if result.default.is_some() {
+                                panic!("duplicate ASN.1 `default` attribute: {:?}", value);
+                            }
+
+                            result.default = Some(value.parse().unwrap_or_else(|_| {
+                                panic!("malformed `default` attribute: {:?}", value)
+                            }));
+                        } else {
+                            panic!("unknown `asn1` attribute: {:?}", path);
+                        }
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) => {
+                        if path.is_ident("encapsulates") {
+                            result.encapsulates = true;
+                        } else if path.is_ident("extensible") {
+                            result.extensible = true;
+                        } else if path.is_ident("extensions") {
+                            result.extensions = true;
+                        } else {
+                            panic!("unknown `asn1` attribute: {:?}", path);
                         }
-                        other => panic!("malformed `asn1` attribute: {:?}", other),
                     }
+                    other => panic!("malformed `asn1` attribute: {:?}", other),
                 }
-                other => panic!("malformed `asn1` attribute: {:?}", other),
             }
         }
 
-        Self { asn1_type }
+        result
     }
 }
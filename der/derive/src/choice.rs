@@ -2,7 +2,7 @@
 //! the purposes of decoding/encoding ASN.1 `CHOICE` types as mapped to
 //! enum variants.
 
-use crate::{Asn1Attrs, Asn1Type};
+use crate::{arbitrary::derive_arbitrary, Asn1Attrs, Asn1Type, Constraint};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::{DataEnum, Ident, Lifetime, Variant};
@@ -47,22 +47,51 @@ impl DeriveChoice {
         };
 
         for (variant_info, variant) in s.variants().iter().zip(&data.variants) {
-            let asn1_type = Asn1Attrs::new(&variant.attrs).asn1_type.unwrap_or_else(|| {
+            let attrs = Asn1Attrs::new(&variant.attrs);
+            let asn1_type = attrs.asn1_type.unwrap_or_else(|| {
                 panic!(
                     "no #[asn1(type=...)] specified for enum variant: {}",
                     variant.ident
                 )
             });
 
+            if attrs.size.is_some() && !asn1_type.has_size() {
+                panic!(
+                    "`size` constraint unsupported for ASN.1 type: {}",
+                    asn1_type
+                );
+            }
+
+            if attrs.range.is_some() {
+                panic!(
+                    "`range` constraint unsupported for enum variant: {}",
+                    variant.ident
+                );
+            }
+
+            if attrs.encapsulates {
+                panic!(
+                    "`encapsulates` attribute unsupported for enum variant: {}",
+                    variant.ident
+                );
+            }
+
+            if attrs.default.is_some() {
+                panic!(
+                    "`default` attribute unsupported for enum variant: {}",
+                    variant.ident
+                );
+            }
+
             Alternative::register(&mut state.alternatives, asn1_type, variant);
             state.derive_variant_choice(asn1_type);
-            state.derive_variant_decoder(asn1_type);
+            state.derive_variant_decoder(asn1_type, attrs.size.as_ref());
 
             match variant_info.bindings().len() {
                 // TODO(tarcieri): handle 0 bindings for ASN.1 NULL
                 1 => {
-                    state.derive_variant_encoder(&variant_info, asn1_type);
-                    state.derive_variant_encoded_len(&variant_info);
+                    state.derive_variant_encoder(&variant_info, asn1_type, attrs.size.as_ref());
+                    state.derive_variant_encoded_len(&variant_info, asn1_type, attrs.size.as_ref());
                 }
                 other => panic!(
                     "unsupported number of ASN.1 variant bindings for {}: {}",
@@ -87,7 +116,7 @@ impl DeriveChoice {
     }
 
     /// Derive a match arm of the impl body for `TryFrom<der::Any<'_>>`.
-    fn derive_variant_decoder(&mut self, asn1_type: Asn1Type) {
+    fn derive_variant_decoder(&mut self, asn1_type: Asn1Type, size: Option<&Constraint>) {
         let tag = asn1_type.tag();
 
         let decoder = match asn1_type {
@@ -99,20 +128,34 @@ impl DeriveChoice {
             Asn1Type::Utf8String => quote!(any.utf8_string()),
         };
 
-        {
-            quote! {
+        match size {
+            Some(size) => quote! {
+                #tag => {
+                    let val = #decoder
+                        .map_err(|_| ::der::Error::from(::der::ErrorKind::Value { tag: #tag }))?;
+
+                    val.check_size(#size)?;
+                    val.try_into().map_err(|_| ::der::ErrorKind::Value { tag: #tag }.into())
+                }
+            },
+            None => quote! {
                 #tag => {
                     #decoder.ok().and_then(|val| val.try_into().ok()).ok_or_else(|| {
                         ::der::ErrorKind::Value { tag: #tag }.into()
                     })
                 }
-            }
+            },
         }
         .to_tokens(&mut self.decode_body);
     }
 
     /// Derive a match arm for the impl body for `der::Encodable::encode`.
-    fn derive_variant_encoder(&mut self, variant: &VariantInfo<'_>, asn1_type: Asn1Type) {
+    fn derive_variant_encoder(
+        &mut self,
+        variant: &VariantInfo<'_>,
+        asn1_type: Asn1Type,
+        size: Option<&Constraint>,
+    ) {
         assert_eq!(
             variant.bindings().len(),
             1,
@@ -123,13 +166,26 @@ impl DeriveChoice {
             .each(|bi| {
                 let binding = &bi.binding;
                 let encoder_obj = asn1_type.encoder(quote!(#binding));
-                quote!(#encoder_obj?.encode(encoder))
+
+                match size {
+                    Some(size) => quote!({
+                        let val = #encoder_obj?;
+                        val.check_size(#size)?;
+                        val.encode(encoder)
+                    }),
+                    None => quote!((#encoder_obj?).encode(encoder)),
+                }
             })
             .to_tokens(&mut self.encode_body);
     }
 
-    /// Derive a match arm for the impl body for `der::Encodable::encode`.
-    fn derive_variant_encoded_len(&mut self, variant: &VariantInfo<'_>) {
+    /// Derive a match arm for the impl body for `der::Encodable::encoded_len`.
+    fn derive_variant_encoded_len(
+        &mut self,
+        variant: &VariantInfo<'_>,
+        asn1_type: Asn1Type,
+        size: Option<&Constraint>,
+    ) {
         assert_eq!(
             variant.bindings().len(),
             1,
@@ -139,7 +195,16 @@ impl DeriveChoice {
         variant
             .each(|bi| {
                 let binding = &bi.binding;
-                quote!(#binding.encoded_len())
+                let encoder_obj = asn1_type.encoder(quote!(#binding));
+
+                match size {
+                    Some(size) => quote!({
+                        let val = #encoder_obj?;
+                        val.check_size(#size)?;
+                        val.encoded_len()
+                    }),
+                    None => quote!((#encoder_obj?).encoded_len()),
+                }
             })
             .to_tokens(&mut self.encoded_len_body);
     }
@@ -159,7 +224,24 @@ impl DeriveChoice {
             ..
         } = self;
 
-        s.gen_impl(quote! {
+        let variants = s.variants().iter().enumerate().map(|(i, variant)| {
+            let constructor = variant.construct(|_, _| quote!(u.arbitrary()?));
+            quote!(#i => #constructor,)
+        });
+
+        let last_variant = s.variants().len().saturating_sub(1);
+
+        let arbitrary = derive_arbitrary(
+            &s,
+            quote! {
+                match u.int_in_range(0..=#last_variant)? {
+                    #(#variants)*
+                    _ => unreachable!(),
+                }
+            },
+        );
+
+        let impls = s.gen_impl(quote! {
             gen impl ::der::Choice<#lifetime> for @Self {
                 fn can_decode(tag: ::der::Tag) -> bool {
                     matches!(tag, #choice_body)
@@ -172,6 +254,8 @@ impl DeriveChoice {
                 fn try_from(any: der::Any<#lifetime>) -> der::Result<Self> {
                     #[allow(unused_imports)]
                     use core::convert::TryInto;
+                    #[allow(unused_imports)]
+                    use ::der::ConstrainedSize;
 
                     match any.tag() {
                         #decode_body
@@ -188,6 +272,8 @@ impl DeriveChoice {
                 fn encode(&self, encoder: &mut ::der::Encoder<'_>) -> ::der::Result<()> {
                     #[allow(unused_imports)]
                     use core::convert::TryFrom;
+                    #[allow(unused_imports)]
+                    use ::der::ConstrainedSize;
 
                     match self {
                         #encode_body
@@ -195,12 +281,22 @@ impl DeriveChoice {
                 }
 
                 fn encoded_len(&self) -> ::der::Result<::der::Length> {
+                    #[allow(unused_imports)]
+                    use core::convert::TryFrom;
+                    #[allow(unused_imports)]
+                    use ::der::ConstrainedSize;
+
                     match self {
                         #encoded_len_body
                     }
                 }
             }
-        })
+        });
+
+        quote! {
+            #impls
+            #arbitrary
+        }
     }
 }
 
@@ -0,0 +1,82 @@
+//! ASN.1 subtype constraints supported by the proc macro
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+/// Range of permitted sizes or values specified using the
+/// `#[asn1(size = "...")]` or `#[asn1(range = "...")]` attributes
+#[derive(Clone, Debug)]
+pub(crate) struct Constraint {
+    /// Rust range expression
+    range: TokenStream,
+}
+
+impl Constraint {
+    /// Parse a constraint written as a range (e.g. `1..64`, `1..=64` or `1..`)
+    /// or a single value (e.g. `16`, equivalent to `16..=16`).
+    ///
+    /// As in ASN.1, ranges include their upper bound, so `1..64` is emitted
+    /// as the Rust range `1..=64`.
+    pub fn new(kind: &str, s: &str) -> Self {
+        if s.trim().is_empty() {
+            panic!("empty `{}` constraint", kind);
+        }
+
+        let range = match s.find("..") {
+            Some(pos) => {
+                let (lower, upper) = (&s[..pos], &s[pos + 2..]);
+
+                if upper.trim().is_empty() || upper.starts_with('=') {
+                    s.to_owned()
+                } else {
+                    format!("{}..={}", lower, upper)
+                }
+            }
+            None => format!("{0}..={0}", s),
+        };
+
+        let range = range
+            .parse()
+            .unwrap_or_else(|_| panic!("malformed `{}` constraint: {:?}", kind, s));
+
+        Self { range }
+    }
+}
+
+impl ToTokens for Constraint {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let range = &self.range;
+        tokens.extend(quote!(&(#range)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Constraint;
+
+    fn range(s: &str) -> String {
+        Constraint::new("size", s).range.to_string()
+    }
+
+    #[test]
+    fn inclusive_ranges() {
+        assert_eq!(range("16"), "16 ..= 16");
+        assert_eq!(range("1..=64"), "1 ..= 64");
+        assert_eq!(range("1.."), "1 ..");
+        assert_eq!(range("..=4"), "..= 4");
+        assert_eq!(range(".."), "..");
+    }
+
+    #[test]
+    fn asn1_ranges_include_upper_bound() {
+        assert_eq!(range("1..64"), "1 ..= 64");
+        assert_eq!(range("..3"), "..= 3");
+        assert_eq!(range("0 .. 255"), "0 ..= 255");
+    }
+
+    #[test]
+    #[should_panic(expected = "empty `range` constraint")]
+    fn reject_empty() {
+        Constraint::new("range", " ");
+    }
+}
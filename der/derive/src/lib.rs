@@ -6,6 +6,9 @@
 //! - [`Choice`][`derive@Choice`]: map ASN.1 `CHOICE` to a Rust enum.
 //! - [`Message`][`derive@Message`]: map ASN.1 `SEQUENCE` to a Rust struct.
 //!
+//! It also contains the [`der!`] macro for encoding constant ASN.1 values
+//! as DER at compile time.
+//!
 //! Note that this crate shouldn't be used directly, but instead accessed
 //! by using the `derive` feature of the `der` crate.
 //!
@@ -32,10 +35,127 @@
 //! Note: please open a GitHub Issue if you would like to request support
 //! for additional ASN.1 types.
 //!
+//! # `#[asn1(size = "...")]` and `#[asn1(range = "...")]` attributes
+//!
+//! These attributes enforce ASN.1 subtype constraints on struct fields (and,
+//! in the case of `size`, on enum variants) when both decoding and encoding,
+//! returning `der::ErrorKind::Constraint` for values which violate them.
+//!
+//! Constraints are written as ranges, e.g. `1..64`, `1..=64` or `1..`, or as
+//! a single value, e.g. `16` (equivalent to `16..=16`). As in ASN.1 notation,
+//! ranges include their upper bound: `1..64` permits `64`, the same as
+//! `1..=64`.
+//!
+//! - `size`: constrains the size of a value as defined by the
+//!   [`der::ConstrainedSize`] trait, e.g. for `OCTET STRING (SIZE (16))` or
+//!   `SEQUENCE SIZE (1..MAX) OF ...`. When combined with the `type` attribute,
+//!   the size of the intermediate ASN.1 type is checked.
+//! - `range`: constrains integer values as defined by the
+//!   [`der::ConstrainedValue`] trait, e.g. for `INTEGER (0..255)`.
+//!
+//! Constraints on `Option` fields are only checked when the value is present.
+//!
+//! ```ignore
+//! #[derive(Message)]
+//! pub struct KeyInfo<'a> {
+//!     #[asn1(type = "OCTET STRING", size = "16")]
+//!     pub id: &'a [u8],
+//!
+//!     #[asn1(range = "0..2")]
+//!     pub version: u8,
+//! }
+//! ```
+//!
+//! # `#[asn1(default = "...")]` attribute
+//!
+//! Marks a struct field as an ASN.1 `DEFAULT` component. The attribute's value
+//! is a Rust expression for the default value, which the field's type must
+//! be comparable to with `==`.
+//!
+//! When decoding, an absent field takes the default value. When encoding, the
+//! field is omitted if it's equal to the default. As required by DER, decoding
+//! an explicitly encoded default value returns `der::ErrorKind::Noncanonical`.
+//!
+//! ```ignore
+//! #[derive(Message)]
+//! pub struct Settings {
+//!     #[asn1(default = "false")]
+//!     pub critical: bool,
+//!
+//!     #[asn1(default = "VERSION_V1")]
+//!     pub version: i64,
+//! }
+//! ```
+//!
+//! # `#[asn1(encapsulates)]` attribute
+//!
+//! Used together with `type = "OCTET STRING"` or `type = "BIT STRING"` on a
+//! struct field, this attribute indicates the string contains a DER-encoded
+//! value of the field's type, which is decoded and encoded transparently
+//! using [`der::OctetStringEncapsulated`] or [`der::BitStringEncapsulated`].
+//! Fields of type `Option<T>` are `OPTIONAL` strings encapsulating a `T`.
+//!
+//! ```ignore
+//! #[derive(Message)]
+//! pub struct Extension {
+//!     pub extn_id: ObjectIdentifier,
+//!
+//!     #[asn1(type = "OCTET STRING", encapsulates)]
+//!     pub extn_value: BasicConstraints,
+//! }
+//! ```
+//!
+//! # `#[asn1(extensible)]` attribute
+//!
+//! Placing this attribute on a struct deriving `Message` marks the ASN.1
+//! `SEQUENCE` as having an extension marker (`...`), making the decoder skip
+//! any unknown elements following the known fields rather than returning a
+//! `TrailingData` error.
+//!
+//! Unknown elements are skipped using only their tag byte and length, so they
+//! may have any single-byte tag, including ones which `der::Tag` doesn't
+//! support (e.g. `SET` or context-specific tags above `[3]`). This includes
+//! unknown elements following trailing `Option` fields, which are treated as
+//! absent when the next element's tag isn't supported.
+//!
+//! The unknown elements can optionally be captured by marking the last field
+//! with `#[asn1(extensions)]`. This field can be of any type which impls
+//! `FromIterator<&'a [u8]>`, e.g. `Vec<&'a [u8]>`, and receives the complete
+//! DER encoding of each element. It isn't encoded, so only the known fields
+//! are included when re-encoding the struct.
+//!
+//! Extensions are deliberately captured as raw `&'a [u8]` encodings rather
+//! than as `der::Any`, which can only hold tags supported by `der::Tag`.
+//! Elements with a supported tag can be parsed further with
+//! `der::Any::from_bytes`.
+//!
+//! ```ignore
+//! #[derive(Message)]
+//! #[asn1(extensible)]
+//! pub struct Example<'a> {
+//!     pub version: u8,
+//!
+//!     #[asn1(extensions)]
+//!     pub extensions: Vec<&'a [u8]>,
+//! }
+//! ```
+//!
+//! # `Arbitrary` impl
+//!
+//! When the `arbitrary` feature of [`der`] is enabled, both derives also
+//! generate an impl of `der::arbitrary::Arbitrary` for types with no generic
+//! parameters or a single lifetime, so all fields must impl `Arbitrary` too.
+//! Generated values which violate a constraint are rejected, and
+//! `#[asn1(extensions)]` fields are left empty.
+//!
 //! [`der`]: https://docs.rs/der/
 //! [`der::BitString`]: https://docs.rs/der/latest/der/struct.BitString.html
+//! [`der::BitStringEncapsulated`]: https://docs.rs/der/latest/der/struct.BitStringEncapsulated.html
+//! [`der::ConstrainedSize`]: https://docs.rs/der/latest/der/trait.ConstrainedSize.html
+//! [`der::ConstrainedValue`]: https://docs.rs/der/latest/der/trait.ConstrainedValue.html
 //! [`der::GeneralizedTime`]: https://docs.rs/der/latest/der/struct.GeneralizedTime.html
 //! [`der::OctetString`]: https://docs.rs/der/latest/der/struct.OctetString.html
+//! [`der::OctetStringEncapsulated`]: https://docs.rs/der/latest/der/struct.OctetStringEncapsulated.html
 //! [`der::PrintableString`]: https://docs.rs/der/latest/der/struct.PrintableString.html
 //! [`der::UtcTime`]: https://docs.rs/der/latest/der/struct.UtcTime.html
 //! [`der::Utf8String`]: https://docs.rs/der/latest/der/struct.Utf8String.html
@@ -43,12 +163,18 @@
 #![crate_type = "proc-macro"]
 #![warn(rust_2018_idioms, trivial_casts, unused_qualifications)]
 
+mod arbitrary;
 mod attributes;
 mod choice;
+mod constraint;
 mod message;
 mod types;
+mod value;
 
-use crate::{attributes::Asn1Attrs, choice::DeriveChoice, message::DeriveMessage, types::Asn1Type};
+use crate::{
+    attributes::Asn1Attrs, choice::DeriveChoice, constraint::Constraint, message::DeriveMessage,
+    types::Asn1Type, value::Value,
+};
 use proc_macro2::TokenStream;
 use syn::{Generics, Lifetime};
 use synstructure::{decl_derive, Structure};
@@ -122,11 +248,57 @@ decl_derive!(
     /// See [toplevel documentation for the `der_derive` crate][2] for more
     /// information about the `#[asn1]` attribute.
     ///
+    /// # `Borrowable` impl
+    ///
+    /// Structs with no generic parameters or a single lifetime parameter also
+    /// get an impl of the [`Borrowable`][3] trait, which allows them to be
+    /// used with `der::Document`, e.g. `Document<AlgorithmIdentifier<'static>>`.
+    ///
     /// [1]: https://docs.rs/der/latest/der/trait.Message.html
     /// [2]: https://docs.rs/der_derive/
+    /// [3]: https://docs.rs/der/latest/der/trait.Borrowable.html
     derive_message
 );
 
+/// Encode an ASN.1 value as DER at compile time, expanding to a `[u8; N]`
+/// array expression.
+///
+/// Values are written in a notation similar to ASN.1 value notation, with the
+/// ASN.1 type preceding each value:
+///
+/// - `BOOLEAN true`
+/// - `INTEGER -42`
+/// - `BIT STRING [0x01, 0x02]` or `BIT STRING b"..."` (no unused bits)
+/// - `OCTET STRING [0x01, 0x02]` or `OCTET STRING b"..."`
+/// - `NULL`
+/// - `OBJECT IDENTIFIER 1.2.840.10045.2.1` (or `OID 1.2.840.10045.2.1`)
+/// - `UTF8String "..."`, `PrintableString "..."` or `IA5String "..."`
+/// - `SEQUENCE { value, ... }`
+/// - `[0] EXPLICIT value` or `[0] IMPLICIT value` for context-specific tags
+///   (`EXPLICIT` unless otherwise specified)
+///
+/// Malformed values are reported as compile errors pointing at the offending
+/// token.
+///
+/// # Usage
+///
+/// ```ignore
+/// /// `AlgorithmIdentifier` for `id-ecPublicKey` with the P-256 curve
+/// const ALGORITHM_ID: [u8; 21] = der::der!(SEQUENCE {
+///     OID 1.2.840.10045.2.1,
+///     OID 1.2.840.10045.3.1.7
+/// });
+/// ```
+#[proc_macro]
+pub fn der(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let value = syn::parse_macro_input!(input as Value);
+
+    value
+        .to_tokens()
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 /// Custom derive for `der::Choice`
 fn derive_choice(s: Structure<'_>) -> TokenStream {
     let ast = s.ast();
@@ -1,10 +1,10 @@
 //! Support for deriving the `Message` trait on structs for the purposes of
 //! decoding/encoding ASN.1 `SEQUENCE` types as mapped to struct fields.
 
-use crate::{Asn1Attrs, Asn1Type};
+use crate::{arbitrary::derive_arbitrary, Asn1Attrs, Asn1Type};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::{DataStruct, Field, Ident, Lifetime};
+use syn::{DataStruct, Field, Ident, Lifetime, Type};
 use synstructure::Structure;
 
 /// Derive the `Message` trait for a struct
@@ -15,16 +15,47 @@ pub(crate) struct DeriveMessage {
     /// Bound fields of a struct to be returned
     decode_result: TokenStream,
 
+    /// Constraint checks to perform prior to serializing a struct
+    encode_checks: TokenStream,
+
     /// Fields of a struct to be serialized
     encode_fields: TokenStream,
+
+    /// Fields of a struct generated by its `Arbitrary` impl
+    arbitrary_fields: TokenStream,
+
+    /// Does the `SEQUENCE` have an extension marker?
+    extensible: bool,
+
+    /// Field which captures unknown trailing elements, if any
+    extensions: Option<Ident>,
 }
 
 impl DeriveMessage {
     pub fn derive(s: Structure<'_>, data: &DataStruct, lifetime: Option<&Lifetime>) -> TokenStream {
+        let attrs = Asn1Attrs::new(&s.ast().attrs);
+
+        if attrs.asn1_type.is_some()
+            || attrs.size.is_some()
+            || attrs.range.is_some()
+            || attrs.default.is_some()
+            || attrs.encapsulates
+        {
+            panic!("only the `extensible` attribute can be used on structs");
+        }
+
+        if attrs.extensions {
+            panic!("`extensions` attribute must be used on a struct field");
+        }
+
         let mut state = Self {
             decode_fields: TokenStream::new(),
             decode_result: TokenStream::new(),
+            encode_checks: TokenStream::new(),
             encode_fields: TokenStream::new(),
+            arbitrary_fields: TokenStream::new(),
+            extensible: attrs.extensible,
+            extensions: None,
         };
 
         for field in &data.fields {
@@ -42,31 +73,174 @@ impl DeriveMessage {
             .cloned()
             .expect("no name on struct field i.e. tuple structs unsupported");
 
-        let asn1_type = Asn1Attrs::new(&field.attrs).asn1_type;
-        self.derive_field_decoder(&name, asn1_type);
-        self.derive_field_encoder(&name, asn1_type);
+        let attrs = Asn1Attrs::new(&field.attrs);
+
+        if attrs.extensible {
+            panic!(
+                "`extensible` attribute must be used on the struct: {}",
+                name
+            );
+        }
+
+        if let Some(extensions) = &self.extensions {
+            panic!(
+                "field follows `extensions` field `{}`: {}",
+                extensions, name
+            );
+        }
+
+        if attrs.extensions {
+            if !self.extensible {
+                panic!(
+                    "`extensions` field requires `#[asn1(extensible)]`: {}",
+                    name
+                );
+            }
+
+            if attrs.asn1_type.is_some()
+                || attrs.size.is_some()
+                || attrs.range.is_some()
+                || attrs.default.is_some()
+                || attrs.encapsulates
+            {
+                panic!(
+                    "`extensions` field can't have other `asn1` attributes: {}",
+                    name
+                );
+            }
+
+            quote!(#name,).to_tokens(&mut self.decode_result);
+
+            // Extensions aren't encoded, so they can't round-trip
+            quote!(#name: core::iter::empty().collect(),).to_tokens(&mut self.arbitrary_fields);
+            self.extensions = Some(name);
+            return;
+        }
+
+        if attrs.encapsulates {
+            match attrs.asn1_type.and_then(Asn1Type::encapsulated) {
+                Some(_) if attrs.size.is_some() || attrs.range.is_some() => panic!(
+                    "constraints unsupported on `encapsulates` field: {}",
+                    name
+                ),
+                Some(_) => (),
+                None => panic!(
+                    "`encapsulates` requires `type = \"OCTET STRING\"` or `type = \"BIT STRING\"`: {}",
+                    name
+                ),
+            }
+        }
+
+        if attrs.default.is_some() {
+            if attrs.asn1_type.is_some() || attrs.encapsulates {
+                panic!(
+                    "`default` attribute can't be combined with `type` or `encapsulates`: {}",
+                    name
+                );
+            }
+
+            if is_option(&field.ty) {
+                panic!(
+                    "`default` attribute unsupported on `Option` field: {}",
+                    name
+                );
+            }
+        }
+
+        if let Some(asn1_type) = attrs.asn1_type {
+            if attrs.size.is_some() && !asn1_type.has_size() {
+                panic!(
+                    "`size` constraint unsupported for ASN.1 type: {}",
+                    asn1_type
+                );
+            }
+
+            if attrs.range.is_some() {
+                panic!(
+                    "`range` constraint unsupported for ASN.1 type: {}",
+                    asn1_type
+                );
+            }
+        }
+
+        quote!(#name: u.arbitrary()?,).to_tokens(&mut self.arbitrary_fields);
+        self.derive_field_decoder(&name, &field.ty, &attrs);
+        self.derive_field_encoder(&name, &field.ty, &attrs);
     }
 
     /// Derive code for decoding a field of a message
-    fn derive_field_decoder(&mut self, name: &Ident, asn1_type: Option<Asn1Type>) {
-        let field_decoder = match asn1_type {
-            Some(Asn1Type::BitString) => quote! {
-                let #name = decoder.bit_string()?.try_into()?;
-            },
-            Some(Asn1Type::GeneralizedTime) => quote! {
-                let #name = decoder.generalized_time()?.try_into()?;
-            },
-            Some(Asn1Type::OctetString) => quote! {
-                let #name = decoder.octet_string()?.try_into()?;
-            },
-            Some(Asn1Type::PrintableString) => quote! {
-                let #name = decoder.printable_string()?.try_into()?;
-            },
-            Some(Asn1Type::UtcTime) => quote! {
-                let #name = decoder.utc_time()?.try_into()?;
-            },
-            Some(Asn1Type::Utf8String) => quote! {
-                let #name = decoder.utf8_string()?.try_into()?;
+    fn derive_field_decoder(&mut self, name: &Ident, ty: &Type, attrs: &Asn1Attrs) {
+        let size_check = attrs.size.as_ref().map(|size| {
+            quote! {
+                value.check_size(#size).or_else(|e| decoder.error(e.kind()))?;
+            }
+        });
+
+        let range_check = attrs.range.as_ref().map(|range| {
+            quote! {
+                value.check_range(#range).or_else(|e| decoder.error(e.kind()))?;
+            }
+        });
+
+        let field_decoder = match attrs.asn1_type {
+            Some(asn1_type) if attrs.encapsulates => {
+                let encapsulated = asn1_type.encapsulated();
+
+                if is_option(ty) {
+                    quote! {
+                        let #name = decoder.decode::<Option<#encapsulated<_>>>()?.map(|value| value.0);
+                    }
+                } else {
+                    quote! { let #name = decoder.decode::<#encapsulated<_>>()?.0; }
+                }
+            }
+            Some(asn1_type) => {
+                let decoder = match asn1_type {
+                    Asn1Type::BitString => quote!(decoder.bit_string()),
+                    Asn1Type::GeneralizedTime => quote!(decoder.generalized_time()),
+                    Asn1Type::OctetString => quote!(decoder.octet_string()),
+                    Asn1Type::PrintableString => quote!(decoder.printable_string()),
+                    Asn1Type::UtcTime => quote!(decoder.utc_time()),
+                    Asn1Type::Utf8String => quote!(decoder.utf8_string()),
+                };
+
+                if size_check.is_some() {
+                    quote! {
+                        let #name = {
+                            let value = #decoder?;
+                            #size_check
+                            value.try_into()?
+                        };
+                    }
+                } else {
+                    quote! { let #name = #decoder?.try_into()?; }
+                }
+            }
+            None if attrs.default.is_some() => {
+                let default = &attrs.default;
+
+                // DER forbids encoding a value equal to the default
+                quote! {
+                    let #name: #ty = match decoder.decode::<Option<#ty>>()? {
+                        Some(value) if value == (#default) => {
+                            decoder.error(::der::ErrorKind::Noncanonical)?
+                        }
+                        Some(value) => {
+                            #size_check
+                            #range_check
+                            value
+                        }
+                        None => #default,
+                    };
+                }
+            }
+            None if size_check.is_some() || range_check.is_some() => quote! {
+                let #name: #ty = {
+                    let value: #ty = decoder.decode()?;
+                    #size_check
+                    #range_check
+                    value
+                };
             },
             None => quote! { let #name = decoder.decode()?; },
         };
@@ -77,15 +251,55 @@ impl DeriveMessage {
     }
 
     /// Derive code for encoding a field of a message
-    fn derive_field_encoder(&mut self, name: &Ident, asn1_type: Option<Asn1Type>) {
+    fn derive_field_encoder(&mut self, name: &Ident, ty: &Type, attrs: &Asn1Attrs) {
         let binding = quote!(&self.#name);
-        asn1_type
-            .map(|ty| {
-                let encoder = ty.encoder(binding.clone());
-                quote!(&#encoder?,)
-            })
-            .unwrap_or_else(|| quote!(#binding,))
-            .to_tokens(&mut self.encode_fields);
+
+        match attrs.asn1_type {
+            Some(asn1_type) if attrs.encapsulates => {
+                let encapsulated = asn1_type.encapsulated();
+
+                if is_option(ty) {
+                    quote! {
+                        &self.#name.as_ref().map(|value| #encapsulated(value as &dyn ::der::Encodable)),
+                    }
+                } else {
+                    quote!(&#encapsulated(#binding as &dyn ::der::Encodable),)
+                }
+                .to_tokens(&mut self.encode_fields);
+            }
+            Some(asn1_type) => {
+                let encoder = asn1_type.encoder(binding);
+
+                if let Some(size) = &attrs.size {
+                    quote!((#encoder?).check_size(#size)?;).to_tokens(&mut self.encode_checks);
+                }
+
+                quote!(&#encoder?,).to_tokens(&mut self.encode_fields);
+            }
+            None => {
+                if let Some(size) = &attrs.size {
+                    quote!(self.#name.check_size(#size)?;).to_tokens(&mut self.encode_checks);
+                }
+
+                if let Some(range) = &attrs.range {
+                    quote!(self.#name.check_range(#range)?;).to_tokens(&mut self.encode_checks);
+                }
+
+                if let Some(default) = &attrs.default {
+                    // Values equal to the default are omitted
+                    quote! {
+                        &(if self.#name == (#default) {
+                            None
+                        } else {
+                            Some(#binding as &dyn ::der::Encodable)
+                        }),
+                    }
+                    .to_tokens(&mut self.encode_fields);
+                } else {
+                    quote!(#binding,).to_tokens(&mut self.encode_fields);
+                }
+            }
+        }
     }
 
     /// Finish deriving a struct
@@ -95,17 +309,45 @@ impl DeriveMessage {
             None => quote!('_),
         };
 
-        let decode_fields = self.decode_fields;
+        let mut decode_fields = self.decode_fields;
+
+        if let Some(extensions) = &self.extensions {
+            quote! {
+                let #extensions = core::iter::from_fn(|| {
+                    if decoder.is_finished() {
+                        None
+                    } else {
+                        Some(decoder.tlv())
+                    }
+                })
+                .collect::<::der::Result<_>>()?;
+            }
+            .to_tokens(&mut decode_fields);
+        } else if self.extensible {
+            quote! {
+                while !decoder.is_finished() {
+                    decoder.tlv()?;
+                }
+            }
+            .to_tokens(&mut decode_fields);
+        }
+
         let decode_result = self.decode_result;
+        let encode_checks = self.encode_checks;
         let encode_fields = self.encode_fields;
+        let borrowable = derive_borrowable(s);
+        let arbitrary_fields = self.arbitrary_fields;
+        let arbitrary = derive_arbitrary(s, quote!(Self { #arbitrary_fields }));
 
-        s.gen_impl(quote! {
+        let impls = s.gen_impl(quote! {
             gen impl core::convert::TryFrom<der::Any<#lifetime>> for @Self {
                 type Error = ::der::Error;
 
                 fn try_from(any: ::der::Any<#lifetime>) -> ::der::Result<Self> {
                     #[allow(unused_imports)]
                     use core::convert::TryInto;
+                    #[allow(unused_imports)]
+                    use ::der::{ConstrainedSize, ConstrainedValue};
 
                     any.sequence(|decoder| {
                         #decode_fields
@@ -121,10 +363,59 @@ impl DeriveMessage {
                 {
                     #[allow(unused_imports)]
                     use core::convert::TryFrom;
+                    #[allow(unused_imports)]
+                    use ::der::{ConstrainedSize, ConstrainedValue};
 
+                    #encode_checks
                     f(&[#encode_fields])
                 }
             }
-        })
+        });
+
+        quote! {
+            #impls
+            #borrowable
+            #arbitrary
+        }
+    }
+}
+
+/// Derive the `Borrowable` trait for structs with no generic parameters, or a
+/// single lifetime parameter, so they can be used with `der::Document`.
+fn derive_borrowable(s: &Structure<'_>) -> TokenStream {
+    let ident = &s.ast().ident;
+    let generics = &s.ast().generics;
+
+    if generics.params.is_empty() {
+        return quote! {
+            impl<'a> ::der::Borrowable<'a> for #ident {
+                type Borrowed = #ident;
+            }
+        };
+    }
+
+    match (generics.lifetimes().count(), generics.params.len()) {
+        (1, 1) => {
+            let lifetime = &generics.lifetimes().next().unwrap().lifetime;
+            quote! {
+                impl<#lifetime> ::der::Borrowable<#lifetime> for #ident<'static> {
+                    type Borrowed = #ident<#lifetime>;
+                }
+            }
+        }
+        _ => TokenStream::new(),
+    }
+}
+
+/// Is the given type an `Option`, i.e. the type of an ASN.1 `OPTIONAL` field?
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "Option")
+            .unwrap_or(false),
+        _ => false,
     }
 }
@@ -41,6 +41,21 @@ impl Asn1Type {
         }
     }
 
+    /// Can values of this type be checked against a `SIZE` constraint?
+    pub fn has_size(self) -> bool {
+        !matches!(self, Asn1Type::GeneralizedTime | Asn1Type::UtcTime)
+    }
+
+    /// Get the `::der` type which encapsulates a DER-encoded value inside
+    /// this ASN.1 type, if supported.
+    pub fn encapsulated(self) -> Option<TokenStream> {
+        match self {
+            Asn1Type::BitString => Some(quote!(::der::BitStringEncapsulated)),
+            Asn1Type::OctetString => Some(quote!(::der::OctetStringEncapsulated)),
+            _ => None,
+        }
+    }
+
     /// Get the `::der::Tag` for this ASN.1 type
     pub fn tag(&self) -> TokenStream {
         match self {
@@ -0,0 +1,415 @@
+//! Support for the `der!` macro, which encodes ASN.1 values written in a
+//! notation similar to ASN.1 value notation as DER at compile time.
+
+use proc_macro2::{Literal, Span, TokenStream, TokenTree};
+use quote::quote;
+use syn::{
+    braced, bracketed,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Ident, LitBool, LitByteStr, LitInt, LitStr, Token,
+};
+
+/// Maximum length of a value supported by the `der` crate.
+const MAX_LENGTH: usize = 0xFFFF;
+
+/// Tag class bits for context-specific tags.
+const CONTEXT_SPECIFIC: u8 = 0b10000000;
+
+/// Constructed form bit.
+const CONSTRUCTED: u8 = 0b100000;
+
+/// ASN.1 value to be encoded as DER.
+pub(crate) struct Value {
+    /// Kind of value
+    kind: Kind,
+
+    /// Location of the value (i.e. its first token), used for error reporting
+    span: Span,
+}
+
+/// Kinds of ASN.1 values.
+enum Kind {
+    /// ASN.1 `BOOLEAN`
+    Boolean(bool),
+
+    /// ASN.1 `INTEGER`
+    Integer(i128),
+
+    /// ASN.1 `BIT STRING` (with no unused bits)
+    BitString(Vec<u8>),
+
+    /// ASN.1 `OCTET STRING`
+    OctetString(Vec<u8>),
+
+    /// ASN.1 `NULL`
+    Null,
+
+    /// ASN.1 `OBJECT IDENTIFIER`
+    ObjectIdentifier(Vec<u8>),
+
+    /// ASN.1 `UTF8String`, `PrintableString` or `IA5String`
+    String { tag: u8, value: String },
+
+    /// ASN.1 `SEQUENCE`
+    Sequence(Vec<Value>),
+
+    /// Context-specific tagged value
+    ContextSpecific {
+        /// Tag number
+        number: u8,
+
+        /// Is the tag `IMPLICIT`?
+        implicit: bool,
+
+        /// Tagged value
+        value: Box<Value>,
+    },
+}
+
+impl Value {
+    /// Encode this value as DER, returning a `[u8; N]` array expression.
+    pub fn to_tokens(&self) -> syn::Result<TokenStream> {
+        let bytes = self.to_der()?;
+        let bytes = bytes.into_iter().map(Literal::u8_suffixed);
+        Ok(quote!([#(#bytes),*]))
+    }
+
+    /// Get the tag for this value.
+    fn tag(&self) -> u8 {
+        match &self.kind {
+            Kind::Boolean(_) => 0x01,
+            Kind::Integer(_) => 0x02,
+            Kind::BitString(_) => 0x03,
+            Kind::OctetString(_) => 0x04,
+            Kind::Null => 0x05,
+            Kind::ObjectIdentifier(_) => 0x06,
+            Kind::String { tag, .. } => *tag,
+            Kind::Sequence(_) => 0x10 | CONSTRUCTED,
+            Kind::ContextSpecific {
+                number,
+                implicit,
+                value,
+            } => {
+                let constructed = if *implicit {
+                    value.tag() & CONSTRUCTED
+                } else {
+                    CONSTRUCTED
+                };
+
+                CONTEXT_SPECIFIC | constructed | number
+            }
+        }
+    }
+
+    /// Encode the contents of this value, i.e. without its header.
+    fn contents(&self) -> syn::Result<Vec<u8>> {
+        Ok(match &self.kind {
+            Kind::Boolean(value) => vec![if *value { 0xFF } else { 0x00 }],
+            Kind::Integer(value) => encode_integer(*value),
+            Kind::BitString(bytes) => {
+                let mut contents = vec![0];
+                contents.extend_from_slice(bytes);
+                contents
+            }
+            Kind::OctetString(bytes) | Kind::ObjectIdentifier(bytes) => bytes.clone(),
+            Kind::Null => Vec::new(),
+            Kind::String { value, .. } => value.as_bytes().to_vec(),
+            Kind::Sequence(values) => {
+                let mut contents = Vec::new();
+
+                for value in values {
+                    contents.extend(value.to_der()?);
+                }
+
+                contents
+            }
+            Kind::ContextSpecific {
+                implicit, value, ..
+            } => {
+                if *implicit {
+                    value.contents()?
+                } else {
+                    value.to_der()?
+                }
+            }
+        })
+    }
+
+    /// Encode this value as DER.
+    fn to_der(&self) -> syn::Result<Vec<u8>> {
+        let contents = self.contents()?;
+        let len = contents.len();
+
+        let mut der = vec![self.tag()];
+
+        if len < 0x80 {
+            der.push(len as u8);
+        } else if len <= 0xFF {
+            der.extend_from_slice(&[0x81, len as u8]);
+        } else if len <= MAX_LENGTH {
+            der.extend_from_slice(&[0x82, (len >> 8) as u8, len as u8]);
+        } else {
+            return Err(syn::Error::new(
+                self.span,
+                "value exceeds maximum DER length",
+            ));
+        }
+
+        der.extend(contents);
+        Ok(der)
+    }
+}
+
+impl Parse for Value {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let span = input.span();
+        let kind = if input.peek(syn::token::Bracket) {
+            parse_context_specific(input)?
+        } else {
+            parse_kind(input)?
+        };
+
+        Ok(Value { kind, span })
+    }
+}
+
+/// Parse a value of a universal type, starting with its type keyword.
+fn parse_kind(input: ParseStream<'_>) -> syn::Result<Kind> {
+    let ident: Ident = input.parse()?;
+
+    match ident.to_string().as_str() {
+        "BOOLEAN" => Ok(Kind::Boolean(input.parse::<LitBool>()?.value)),
+        "INTEGER" => parse_integer(input).map(Kind::Integer),
+        "BIT" => {
+            parse_keyword(input, "STRING")?;
+            parse_bytes(input).map(Kind::BitString)
+        }
+        "OCTET" => {
+            parse_keyword(input, "STRING")?;
+            parse_bytes(input).map(Kind::OctetString)
+        }
+        "NULL" => Ok(Kind::Null),
+        "OBJECT" => {
+            parse_keyword(input, "IDENTIFIER")?;
+            parse_oid(input).map(Kind::ObjectIdentifier)
+        }
+        "OID" => parse_oid(input).map(Kind::ObjectIdentifier),
+        "UTF8String" => Ok(Kind::String {
+            tag: 0x0C,
+            value: input.parse::<LitStr>()?.value(),
+        }),
+        "PrintableString" => parse_string(input, 0x13, |c| {
+            c.is_ascii_alphanumeric() || " '()+,-./:=?".contains(c)
+        }),
+        "IA5String" => parse_string(input, 0x16, |c| c.is_ascii()),
+        "SEQUENCE" => {
+            let content;
+            braced!(content in input);
+            let values = Punctuated::<Value, Token![,]>::parse_terminated(&content)?;
+            Ok(Kind::Sequence(values.into_iter().collect()))
+        }
+        _ => Err(syn::Error::new(
+            ident.span(),
+            format!("unsupported ASN.1 type: {}", ident),
+        )),
+    }
+}
+
+/// Parse a context-specific tagged value, e.g. `[0] EXPLICIT INTEGER 1`.
+///
+/// Tags are `EXPLICIT` unless otherwise specified.
+fn parse_context_specific(input: ParseStream<'_>) -> syn::Result<Kind> {
+    let content;
+    bracketed!(content in input);
+    let number: LitInt = content.parse()?;
+    let number = number.base10_parse::<u8>().and_then(|n| {
+        if n <= 30 {
+            Ok(n)
+        } else {
+            Err(syn::Error::new(
+                number.span(),
+                "tag number must be 30 or less",
+            ))
+        }
+    })?;
+
+    let implicit = if input.peek(Ident) {
+        let fork = input.fork();
+        match fork.parse::<Ident>()?.to_string().as_str() {
+            "EXPLICIT" => {
+                input.parse::<Ident>()?;
+                false
+            }
+            "IMPLICIT" => {
+                input.parse::<Ident>()?;
+                true
+            }
+            _ => false,
+        }
+    } else {
+        false
+    };
+
+    Ok(Kind::ContextSpecific {
+        number,
+        implicit,
+        value: Box::new(input.parse()?),
+    })
+}
+
+/// Parse the given keyword, e.g. the `STRING` in `OCTET STRING`.
+fn parse_keyword(input: ParseStream<'_>, keyword: &str) -> syn::Result<()> {
+    let ident: Ident = input.parse()?;
+
+    if ident == keyword {
+        Ok(())
+    } else {
+        Err(syn::Error::new(
+            ident.span(),
+            format!("expected `{}`", keyword),
+        ))
+    }
+}
+
+/// Parse a (possibly negative) integer literal.
+fn parse_integer(input: ParseStream<'_>) -> syn::Result<i128> {
+    let negative = input.parse::<Option<Token![-]>>()?.is_some();
+    let lit: LitInt = input.parse()?;
+    let value = lit.base10_parse::<i128>()?;
+
+    if negative {
+        value
+            .checked_neg()
+            .ok_or_else(|| syn::Error::new(lit.span(), "integer out of range"))
+    } else {
+        Ok(value)
+    }
+}
+
+/// Parse the contents of a string type, ensuring all characters are allowed.
+fn parse_string(input: ParseStream<'_>, tag: u8, allowed: fn(char) -> bool) -> syn::Result<Kind> {
+    let lit: LitStr = input.parse()?;
+    let value = lit.value();
+
+    match value.chars().find(|&c| !allowed(c)) {
+        Some(c) => Err(syn::Error::new(
+            lit.span(),
+            format!("invalid character in string: {:?}", c),
+        )),
+        None => Ok(Kind::String { tag, value }),
+    }
+}
+
+/// Parse the contents of a `BIT STRING` or `OCTET STRING`: either a byte
+/// string literal (e.g. `b"..."`) or a list of bytes (e.g. `[0x01, 0x02]`).
+fn parse_bytes(input: ParseStream<'_>) -> syn::Result<Vec<u8>> {
+    if input.peek(syn::token::Bracket) {
+        let content;
+        bracketed!(content in input);
+        Punctuated::<LitInt, Token![,]>::parse_terminated(&content)?
+            .iter()
+            .map(LitInt::base10_parse::<u8>)
+            .collect()
+    } else {
+        Ok(input.parse::<LitByteStr>()?.value())
+    }
+}
+
+/// Parse an `OBJECT IDENTIFIER` in dotted notation, returning its encoded
+/// contents.
+///
+/// The arcs are either written directly (e.g. `1.2.840.10045.2.1`) or as a
+/// string literal.
+fn parse_oid(input: ParseStream<'_>) -> syn::Result<Vec<u8>> {
+    let (oid, span) = if input.peek(LitStr) {
+        let lit: LitStr = input.parse()?;
+        (lit.value(), lit.span())
+    } else {
+        // Dotted notation is lexed as a mixture of integer and float literals
+        // and `.` punctuation: concatenate them up to the next `,`
+        input.step(|cursor| {
+            let mut oid = String::new();
+            let mut rest = *cursor;
+            let span = cursor.span();
+
+            while let Some((tt, next)) = rest.token_tree() {
+                match &tt {
+                    TokenTree::Punct(punct) if punct.as_char() == ',' => break,
+                    TokenTree::Punct(punct) if punct.as_char() == '.' => oid.push('.'),
+                    TokenTree::Literal(literal) => oid.push_str(&literal.to_string()),
+                    _ => return Err(syn::Error::new(tt.span(), "malformed OID")),
+                }
+
+                rest = next;
+            }
+
+            Ok(((oid, span), rest))
+        })?
+    };
+
+    encode_oid(&oid).ok_or_else(|| syn::Error::new(span, format!("malformed OID: {}", oid)))
+}
+
+/// Encode an OID given in dotted notation, returning `None` if it's invalid.
+fn encode_oid(oid: &str) -> Option<Vec<u8>> {
+    let arcs = oid
+        .split('.')
+        .map(|arc| {
+            // Arcs must be non-empty and written without leading zeros
+            if arc.is_empty()
+                || !arc.bytes().all(|b| b.is_ascii_digit())
+                || (arc.len() > 1 && arc.starts_with('0'))
+            {
+                None
+            } else {
+                arc.parse::<u32>().ok()
+            }
+        })
+        .collect::<Option<Vec<u32>>>()?;
+
+    if arcs.len() < 2 || arcs[0] > 2 || (arcs[0] < 2 && arcs[1] >= 40) {
+        return None;
+    }
+
+    let mut bytes = Vec::new();
+    let first = arcs[0].checked_mul(40)?.checked_add(arcs[1])?;
+
+    for &arc in [first].iter().chain(&arcs[2..]) {
+        let mut n = 1;
+
+        while arc >> (7 * n) != 0 {
+            n += 1;
+        }
+
+        for i in (0..n).rev() {
+            let byte = ((arc >> (7 * i)) & 0x7F) as u8;
+            bytes.push(if i > 0 { byte | 0x80 } else { byte });
+        }
+    }
+
+    Some(bytes)
+}
+
+/// Encode the contents of an `INTEGER` as two's complement using the
+/// minimum number of bytes.
+fn encode_integer(value: i128) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let mut start = 0;
+
+    while start < bytes.len() - 1 {
+        let redundant = match bytes[start] {
+            0x00 => bytes[start + 1] & 0x80 == 0,
+            0xFF => bytes[start + 1] & 0x80 != 0,
+            _ => false,
+        };
+
+        if !redundant {
+            break;
+        }
+
+        start += 1;
+    }
+
+    bytes[start..].to_vec()
+}
@@ -3,11 +3,16 @@
 //! Includes built-in ASN.1 types and helper types for modeling ASN.1 concepts.
 
 pub(crate) mod any;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 #[cfg(feature = "big-uint")]
 pub(crate) mod big_uint;
 pub(crate) mod bit_string;
 pub(crate) mod boolean;
 pub(crate) mod choice;
+#[cfg(feature = "big-uint")]
+pub(crate) mod ecdsa_signature;
+pub(crate) mod encapsulated;
 pub(crate) mod generalized_time;
 pub(crate) mod ia5_string;
 pub(crate) mod integer;
@@ -1,11 +1,14 @@
 //! ASN.1 `ANY` type.
 
 use crate::{
-    BitString, ByteSlice, Choice, Decodable, Decoder, Encodable, Encoder, Error, ErrorKind,
-    GeneralizedTime, Header, Ia5String, Length, Null, OctetString, PrintableString, Result,
-    Sequence, Tag, UtcTime, Utf8String,
+    limits::Nesting, BitString, ByteSlice, Choice, Decodable, Decoder, Encodable, Encoder, Error,
+    ErrorKind, GeneralizedTime, Header, Ia5String, Length, Null, OctetString, PrintableString,
+    Result, Tag, UtcTime, Utf8String,
+};
+use core::{
+    convert::{TryFrom, TryInto},
+    fmt,
 };
-use core::convert::{TryFrom, TryInto};
 
 #[cfg(feature = "oid")]
 use crate::ObjectIdentifier;
@@ -19,13 +22,17 @@ use crate::ObjectIdentifier;
 /// Nevertheless, this crate defines an [`Any`] type as it remains a familiar
 /// and useful concept, although the usage within arguably resembles the
 /// type system concept more than the original ASN.1 concept.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone)]
 pub struct Any<'a> {
     /// Tag representing the type of the encoded value
     pub(crate) tag: Tag,
 
     /// Inner value encoded as bytes
     pub(crate) value: ByteSlice<'a>,
+
+    /// Limits and nesting depth of the [`Decoder`] this value was decoded
+    /// with, enforced on messages encapsulated inside of it
+    pub(crate) nesting: Nesting,
 }
 
 impl<'a> Any<'a> {
@@ -34,6 +41,7 @@ impl<'a> Any<'a> {
         Ok(Self {
             tag,
             value: ByteSlice::new(value).map_err(|_| ErrorKind::Length { tag })?,
+            nesting: Nesting::default(),
         })
     }
 
@@ -106,7 +114,10 @@ impl<'a> Any<'a> {
     where
         F: FnOnce(&mut Decoder<'a>) -> Result<T>,
     {
-        Sequence::try_from(self)?.decode_nested(f)
+        self.tag.assert_eq(Tag::Sequence)?;
+        let mut decoder = self.decoder();
+        let result = f(&mut decoder)?;
+        decoder.finish(result)
     }
 
     /// Attempt to decode an ASN.1 `UTCTime`.
@@ -119,6 +130,18 @@ impl<'a> Any<'a> {
         self.try_into()
     }
 
+    /// Create a [`Decoder`] for the contents of this value, which must be
+    /// constructed.
+    pub(crate) fn decoder(self) -> Decoder<'a> {
+        Decoder::nested(self.as_bytes(), self.nesting.contents())
+    }
+
+    /// Create a [`Decoder`] for a message encapsulated in the given contents
+    /// of this value, e.g. in the case of an `OCTET STRING`.
+    pub(crate) fn encapsulated_decoder(self, contents: &'a [u8]) -> Decoder<'a> {
+        Decoder::encapsulated(contents, self.nesting.contents())
+    }
+
     /// Get the ASN.1 DER [`Header`] for this [`Any`] value
     pub(crate) fn header(self) -> Header {
         Header {
@@ -140,7 +163,28 @@ impl<'a> Decodable<'a> for Any<'a> {
         let tag = header.tag;
         let len = header.length.to_usize();
         let value = decoder.bytes(len).map_err(|_| ErrorKind::Length { tag })?;
-        Self::new(tag, value)
+
+        Ok(Self {
+            nesting: decoder.nesting(),
+            ..Self::new(tag, value)?
+        })
+    }
+}
+
+impl fmt::Debug for Any<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Any")
+            .field("tag", &self.tag)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl Eq for Any<'_> {}
+
+impl PartialEq for Any<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.tag == other.tag && self.value == other.value
     }
 }
 
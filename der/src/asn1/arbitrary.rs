@@ -0,0 +1,154 @@
+//! Support for generating arbitrary ASN.1 values using the [`arbitrary`]
+//! crate, e.g. for fuzzing.
+//!
+//! Generated values are always valid, and re-encode to the same DER after
+//! being decoded.
+//!
+//! [`arbitrary`]: https://docs.rs/arbitrary/
+
+use crate::{
+    asn1::{generalized_time, utc_time},
+    Any, BitString, Decoder, GeneralizedTime, Ia5String, Length, Null, OctetString,
+    PrintableString, Result, Sequence, UtcTime, Utf8String,
+};
+use arbitrary_crate::{Arbitrary, Error, Unstructured};
+use core::time::Duration;
+
+#[cfg(feature = "big-uint")]
+use crate::{BigUInt, BigUIntSize, EcdsaSignature};
+
+impl<'a> Arbitrary<'a> for Any<'a> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary_crate::Result<Self> {
+        Ok(match u.int_in_range(0..=6)? {
+            0 => Null.into(),
+            1 => BitString::arbitrary(u)?.into(),
+            2 => Ia5String::arbitrary(u)?.into(),
+            3 => OctetString::arbitrary(u)?.into(),
+            4 => PrintableString::arbitrary(u)?.into(),
+            5 => Sequence::arbitrary(u)?.into(),
+            _ => Utf8String::arbitrary(u)?.into(),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for BitString<'a> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary_crate::Result<Self> {
+        // One byte of the maximum length is used for the unused bits
+        let bytes = arbitrary_bytes(u, Length::max() - 1)?;
+        convert(Self::new(bytes))
+    }
+}
+
+impl<'a> Arbitrary<'a> for GeneralizedTime {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary_crate::Result<Self> {
+        let secs = u.int_in_range(0..=generalized_time::MAX_UNIX_DURATION.as_secs() - 1)?;
+        convert(Self::new(Duration::from_secs(secs)))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Ia5String<'a> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary_crate::Result<Self> {
+        let bytes = valid_prefix(arbitrary_bytes(u, Length::max())?, |b| {
+            Ia5String::new(b).is_ok()
+        });
+        convert(Self::new(bytes))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Null {
+    fn arbitrary(_u: &mut Unstructured<'a>) -> arbitrary_crate::Result<Self> {
+        Ok(Null)
+    }
+}
+
+impl<'a> Arbitrary<'a> for OctetString<'a> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary_crate::Result<Self> {
+        convert(Self::new(arbitrary_bytes(u, Length::max())?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for PrintableString<'a> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary_crate::Result<Self> {
+        let bytes = valid_prefix(arbitrary_bytes(u, Length::max())?, |b| {
+            PrintableString::new(b).is_ok()
+        });
+        convert(Self::new(bytes))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Sequence<'a> {
+    /// Generate a `SEQUENCE` containing the longest run of well-formed
+    /// elements found at the start of the arbitrary input.
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary_crate::Result<Self> {
+        let bytes = arbitrary_bytes(u, Length::max())?;
+        let mut decoder = Decoder::new(bytes);
+        let mut len = 0;
+
+        while decoder.decode::<Any<'_>>().is_ok() {
+            len = decoder.position().to_usize();
+        }
+
+        convert(Self::new(&bytes[..len]))
+    }
+}
+
+impl<'a> Arbitrary<'a> for UtcTime {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary_crate::Result<Self> {
+        let secs = u.int_in_range(0..=utc_time::MAX_UNIX_DURATION.as_secs() - 1)?;
+        convert(Self::new(Duration::from_secs(secs)))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Utf8String<'a> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary_crate::Result<Self> {
+        let s = <&str>::arbitrary(u)?;
+        let mut len = s.len().min(Length::max());
+
+        while !s.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        convert(Self::new(&s[..len]))
+    }
+}
+
+#[cfg(feature = "big-uint")]
+impl<'a, N: BigUIntSize> Arbitrary<'a> for BigUInt<'a, N> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary_crate::Result<Self> {
+        let bytes = arbitrary_bytes(u, N::to_usize())?;
+        convert(Self::new(bytes))
+    }
+}
+
+#[cfg(feature = "big-uint")]
+impl<'a, N: BigUIntSize> Arbitrary<'a> for EcdsaSignature<'a, N> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary_crate::Result<Self> {
+        let bytes = u.bytes(N::to_usize() * 2)?;
+        convert(Self::from_fixed(bytes))
+    }
+}
+
+/// Take an arbitrary number of bytes from the input, up to `max_len`.
+fn arbitrary_bytes<'a>(
+    u: &mut Unstructured<'a>,
+    max_len: usize,
+) -> arbitrary_crate::Result<&'a [u8]> {
+    let len = u.arbitrary_len::<u8>()?.min(max_len);
+    u.bytes(len)
+}
+
+/// Get the longest prefix of `bytes` where every byte is valid according to
+/// the given predicate.
+fn valid_prefix(bytes: &[u8], is_valid: impl Fn(&[u8]) -> bool) -> &[u8] {
+    let len = bytes
+        .iter()
+        .position(|b| !is_valid(core::slice::from_ref(b)))
+        .unwrap_or(bytes.len());
+
+    &bytes[..len]
+}
+
+/// Convert the result of constructing an ASN.1 value.
+fn convert<T>(result: Result<T>) -> arbitrary_crate::Result<T> {
+    result.map_err(|_| Error::IncorrectFormat)
+}
@@ -34,7 +34,7 @@ impl<'a, N: BigUIntSize> BigUInt<'a, N> {
     /// Slice may be less than or equal to `N` bytes.
     pub fn new(mut bytes: &'a [u8]) -> Result<Self> {
         // Remove leading zeroes
-        while bytes.get(0).cloned() == Some(0) {
+        while bytes.first().cloned() == Some(0) {
             bytes = &bytes[1..];
         }
 
@@ -70,7 +70,7 @@ impl<'a, N: BigUIntSize> BigUInt<'a, N> {
     /// Get the length of the inner integer value when encoded.
     fn inner_len(self) -> Result<Length> {
         self.len()
-            + match self.inner.as_ref().get(0).cloned() {
+            + match self.inner.as_ref().first().cloned() {
                 Some(n) if n >= 0x80 => 1u8, // Needs leading `0`
                 None => 1u8,                 // Needs leading `0`
                 _ => 0u8,                    // No leading `0`
@@ -103,21 +103,25 @@ impl<'a, N: BigUIntSize> TryFrom<Any<'a>> for BigUInt<'a, N> {
         // ASN.1 integer (since this is a "uint" type).
         // We expect all such cases to have a leading `0x00` byte
         // (see comment below)
-        if let Some(byte) = bytes.get(0).cloned() {
-            if byte > 0x80 {
+        match bytes {
+            [byte, ..] if *byte >= 0x80 => {
                 return Err(ErrorKind::Value { tag: Self::TAG }.into());
             }
+            // A leading `0x00` byte is only allowed when the following byte
+            // has its high bit set, i.e. when it's needed to keep the value
+            // positive
+            [0x00, next, ..] if *next < 0x80 => return Err(ErrorKind::Noncanonical.into()),
+            _ => (),
         }
 
         // The `INTEGER` type always encodes a signed value, so for unsigned
         // values the leading `0x00` byte may need to be removed.
-        // TODO(tarcieri): validate leading 0 byte was required
         if bytes.len() > N::to_usize() {
             if bytes.len() != N::to_usize().checked_add(1).unwrap() {
                 return Err(ErrorKind::Length { tag: Self::TAG }.into());
             }
 
-            if bytes.get(0).cloned() != Some(0) {
+            if bytes.first().cloned() != Some(0) {
                 return Err(ErrorKind::Value { tag: Self::TAG }.into());
             }
 
@@ -245,5 +249,19 @@ mod tests {
     fn reject_oversize_without_extra_zero() {
         let err = parse_bigu8_from_any(&[0x81]).err().unwrap();
         assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Integer });
+
+        let err = parse_bigu8_from_any(&[0x80]).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Integer });
+    }
+
+    #[test]
+    fn reject_unnecessary_leading_zero() {
+        let err = parse_bigu8_from_any(&[0x00, 0x7F]).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Noncanonical);
+
+        let err = BigU16::from_bytes(&[0x02, 0x03, 0x00, 0x01, 0x00])
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Noncanonical);
     }
 }
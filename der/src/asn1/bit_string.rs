@@ -1,30 +1,61 @@
 //! ASN.1 `BIT STRING` support.
 
 use crate::{
-    Any, ByteSlice, Encodable, Encoder, Error, ErrorKind, Header, Length, Result, Tag, Tagged,
+    limits::Nesting, Any, ByteSlice, Encodable, Encoder, Error, ErrorKind, Header, Length, Result,
+    Tag, Tagged,
 };
 use core::convert::TryFrom;
 
 /// ASN.1 `BIT STRING` type.
+///
+/// The bit string is stored as bytes along with the number of unused bits in
+/// the last byte, which must themselves be zero as required by DER.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct BitString<'a> {
+    /// Number of unused bits in the last byte
+    unused_bits: u8,
+
     /// Inner value
     inner: ByteSlice<'a>,
 }
 
 impl<'a> BitString<'a> {
-    /// Create a new ASN.1 `BIT STRING` from a byte slice.
+    /// Create a new ASN.1 `BIT STRING` from a byte slice, with no unused
+    /// bits.
     pub fn new(slice: &'a [u8]) -> Result<Self> {
+        Self::with_unused_bits(slice, 0)
+    }
+
+    /// Create a new ASN.1 `BIT STRING` from a byte slice, the last byte of
+    /// which has the given number of unused (least significant) bits.
+    ///
+    /// Returns an error unless there are fewer than 8 unused bits which are
+    /// all zero, and none if the slice is empty.
+    pub fn with_unused_bits(slice: &'a [u8], unused_bits: u8) -> Result<Self> {
+        if !is_valid(unused_bits, slice) {
+            return Err(ErrorKind::Value { tag: Self::TAG }.into());
+        }
+
         ByteSlice::new(slice)
-            .map(|inner| Self { inner })
+            .map(|inner| Self { unused_bits, inner })
             .map_err(|_| ErrorKind::Length { tag: Self::TAG }.into())
     }
 
-    /// Borrow the inner byte slice.
+    /// Borrow the inner byte slice, including any unused bits.
     pub fn as_bytes(&self) -> &'a [u8] {
         self.inner.as_bytes()
     }
 
+    /// Get the number of unused bits in the last byte.
+    pub fn unused_bits(&self) -> u8 {
+        self.unused_bits
+    }
+
+    /// Get the length of this `BIT STRING` in bits.
+    pub fn bit_len(&self) -> usize {
+        self.as_bytes().len() * 8 - self.unused_bits as usize
+    }
+
     /// Get the length of the inner byte slice.
     pub fn len(&self) -> Length {
         self.inner.len()
@@ -62,17 +93,11 @@ impl<'a> TryFrom<Any<'a>> for BitString<'a> {
     fn try_from(any: Any<'a>) -> Result<BitString<'a>> {
         any.tag().assert_eq(Tag::BitString)?;
 
-        if let Some(bs) = any.as_bytes().get(1..) {
-            // The first octet of a BIT STRING encodes the number of unused bits.
-            // We presently constrain this to 0.
-            if any.as_bytes()[0] == 0 {
-                return ByteSlice::new(bs)
-                    .map(|inner| Self { inner })
-                    .map_err(|_| ErrorKind::Length { tag: Self::TAG }.into());
-            }
+        // The first octet of a BIT STRING encodes the number of unused bits
+        match any.as_bytes().split_first() {
+            Some((&unused_bits, bytes)) => Self::with_unused_bits(bytes, unused_bits),
+            None => Err(ErrorKind::Length { tag: Self::TAG }.into()),
         }
-
-        Err(ErrorKind::Length { tag: Self::TAG }.into())
     }
 }
 
@@ -81,6 +106,7 @@ impl<'a> From<BitString<'a>> for Any<'a> {
         Any {
             tag: Tag::BitString,
             value: bit_string.inner,
+            nesting: Nesting::default(),
         }
     }
 }
@@ -98,7 +124,7 @@ impl<'a> Encodable for BitString<'a> {
 
     fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
         self.header()?.encode(encoder)?;
-        encoder.byte(0)?;
+        encoder.byte(self.unused_bits)?;
         encoder.bytes(self.as_bytes())
     }
 }
@@ -107,9 +133,18 @@ impl<'a> Tagged for BitString<'a> {
     const TAG: Tag = Tag::BitString;
 }
 
+/// Is the given `BIT STRING` canonically encoded, i.e. are there fewer than
+/// 8 unused bits which are all zero (and none for an empty `BIT STRING`)?
+pub(crate) fn is_valid(unused_bits: u8, bytes: &[u8]) -> bool {
+    match bytes.last() {
+        Some(last) => unused_bits < 8 && last & ((1 << unused_bits) - 1) == 0,
+        None => unused_bits == 0,
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{Any, BitString, ErrorKind, Result, Tag};
+    use super::{Any, BitString, Encodable, ErrorKind, Result, Tag};
     use core::convert::TryInto;
 
     /// Parse a `BitString` from an ASN.1 `Any` value to test decoding behaviors.
@@ -129,16 +164,43 @@ mod tests {
     }
 
     #[test]
-    fn reject_non_zero_prefix() {
+    fn reject_non_zero_unused_bits() {
         let err = parse_bitstring_from_any(&[1, 1, 2, 3]).err().unwrap();
         assert_eq!(
             err.kind(),
-            ErrorKind::Length {
+            ErrorKind::Value {
                 tag: Tag::BitString
             }
         );
     }
 
+    #[test]
+    fn reject_invalid_unused_bits() {
+        for bytes in &[&[8, 0][..], &[1][..]] {
+            let err = parse_bitstring_from_any(bytes).err().unwrap();
+            assert_eq!(
+                err.kind(),
+                ErrorKind::Value {
+                    tag: Tag::BitString
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn decode_unused_bits() {
+        let bs = parse_bitstring_from_any(&[3, 0xA5, 0xF8]).unwrap();
+        assert_eq!(bs.as_bytes(), &[0xA5, 0xF8]);
+        assert_eq!(bs.unused_bits(), 3);
+        assert_eq!(bs.bit_len(), 13);
+
+        let mut buf = [0u8; 5];
+        assert_eq!(
+            bs.encode_to_slice(&mut buf).unwrap(),
+            &[0x03, 0x03, 0x03, 0xA5, 0xF8]
+        );
+    }
+
     #[test]
     fn decode_empty_bitstring() {
         let bs = parse_bitstring_from_any(&[0]).unwrap();
@@ -0,0 +1,244 @@
+//! ECDSA signatures (`Ecdsa-Sig-Value`).
+
+use crate::{
+    Any, BigUInt, BigUIntSize, Decodable, Encodable, Error, ErrorKind, Message, Result, Tag,
+};
+use core::convert::TryFrom;
+
+/// ECDSA signature for the NIST P-256 elliptic curve.
+pub type EcdsaSignatureP256<'a> = EcdsaSignature<'a, typenum::U32>;
+
+/// ECDSA signature for the NIST P-384 elliptic curve.
+pub type EcdsaSignatureP384<'a> = EcdsaSignature<'a, typenum::U48>;
+
+/// ECDSA signature for the NIST P-521 elliptic curve.
+pub type EcdsaSignatureP521<'a> = EcdsaSignature<'a, typenum::U66>;
+
+/// ECDSA signature as defined in [RFC 3279 Section 2.2.3].
+///
+/// ```text
+/// Ecdsa-Sig-Value  ::=  SEQUENCE  {
+///      r     INTEGER,
+///      s     INTEGER  }
+/// ```
+///
+/// Generic over the size of the curve's scalars in bytes (e.g.
+/// [`der::consts::U32`][`typenum::U32`] for P-256), which is also the size
+/// of each half of the fixed-width `r || s` encoding used by
+/// [`EcdsaSignature::from_fixed`] and [`EcdsaSignature::to_fixed`].
+///
+/// When decoding, `r` and `s` must be minimally encoded, non-zero, and no
+/// larger than `N` bytes.
+///
+/// [RFC 3279 Section 2.2.3]: https://tools.ietf.org/html/rfc3279#section-2.2.3
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "big-uint")))]
+pub struct EcdsaSignature<'a, N: BigUIntSize> {
+    /// `r` component of the signature.
+    pub r: BigUInt<'a, N>,
+
+    /// `s` component of the signature.
+    pub s: BigUInt<'a, N>,
+}
+
+impl<'a, N: BigUIntSize> EcdsaSignature<'a, N> {
+    /// Create a new [`EcdsaSignature`] from its `r` and `s` components.
+    ///
+    /// Returns an error if either component is zero.
+    pub fn new(r: BigUInt<'a, N>, s: BigUInt<'a, N>) -> Result<Self> {
+        if r.is_empty() || s.is_empty() {
+            return Err(ErrorKind::Value { tag: Tag::Integer }.into());
+        }
+
+        Ok(Self { r, s })
+    }
+
+    /// Parse a fixed-width signature, i.e. the big endian `r` and `s`
+    /// components each zero-padded to `N` bytes and concatenated.
+    pub fn from_fixed(bytes: &'a [u8]) -> Result<Self> {
+        let size = N::to_usize();
+
+        if bytes.len() != size * 2 {
+            return Err(ErrorKind::Length { tag: Tag::Sequence }.into());
+        }
+
+        let (r, s) = bytes.split_at(size);
+        Self::new(BigUInt::new(r)?, BigUInt::new(s)?)
+    }
+
+    /// Serialize this signature in fixed-width `r || s` form into the
+    /// provided buffer, returning the `2 * N` bytes written.
+    pub fn to_fixed<'o>(&self, out: &'o mut [u8]) -> Result<&'o [u8]> {
+        let size = N::to_usize();
+        let out = out.get_mut(..size * 2).ok_or(ErrorKind::Overlength)?;
+
+        for (chunk, component) in out.chunks_mut(size).zip(&[self.r, self.s]) {
+            let bytes = component.as_bytes();
+            let (padding, value) = chunk.split_at_mut(size - bytes.len());
+
+            for byte in padding {
+                *byte = 0;
+            }
+
+            value.copy_from_slice(bytes);
+        }
+
+        Ok(out)
+    }
+}
+
+impl<'a, N: BigUIntSize> TryFrom<&'a [u8]> for EcdsaSignature<'a, N> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl<'a, N: BigUIntSize> TryFrom<Any<'a>> for EcdsaSignature<'a, N> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        any.sequence(|decoder| {
+            let r = decode_component(decoder.decode()?)?;
+            let s = decode_component(decoder.decode()?)?;
+            Self::new(r, s)
+        })
+    }
+}
+
+impl<'a, N: BigUIntSize> Message<'a> for EcdsaSignature<'a, N> {
+    fn fields<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> Result<T>,
+    {
+        f(&[&self.r, &self.s])
+    }
+}
+
+/// Decode the `r` or `s` component of a signature, rejecting the empty
+/// `INTEGER` encoding which [`BigUInt`] otherwise accepts as zero.
+fn decode_component<N: BigUIntSize>(any: Any<'_>) -> Result<BigUInt<'_, N>> {
+    if any.as_bytes().is_empty() {
+        return Err(ErrorKind::Length { tag: Tag::Integer }.into());
+    }
+
+    BigUInt::try_from(any)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EcdsaSignatureP256, EcdsaSignatureP521};
+    use crate::{Decodable, Encodable, ErrorKind, Tag};
+    use hex_literal::hex;
+
+    /// P-256 signature with a leading `0x00` byte on `r`.
+    const P256_DER: &[u8] = &hex!("3045022100EA0A4B1A5E8C5B0D1CF2C0FEE1C3C1CB0F2B6B6B1D5A2E0B3C4D5E6F7A8B9C0D022012AC3E4D5F60718293A4B5C6D7E8F90A1B2C3D4E5F60718293A4B5C6D7E8F90A");
+
+    const P256_FIXED: &[u8] = &hex!("EA0A4B1A5E8C5B0D1CF2C0FEE1C3C1CB0F2B6B6B1D5A2E0B3C4D5E6F7A8B9C0D12AC3E4D5F60718293A4B5C6D7E8F90A1B2C3D4E5F60718293A4B5C6D7E8F90A");
+
+    #[test]
+    fn p256_der_to_fixed() {
+        let sig = EcdsaSignatureP256::from_bytes(P256_DER).unwrap();
+        assert_eq!(sig.r.as_bytes(), &P256_FIXED[..32]);
+        assert_eq!(sig.s.as_bytes(), &P256_FIXED[32..]);
+
+        let mut buf = [0u8; 64];
+        assert_eq!(sig.to_fixed(&mut buf).unwrap(), P256_FIXED);
+
+        let mut buf = [0u8; 128];
+        assert_eq!(sig.encode_to_slice(&mut buf).unwrap(), P256_DER);
+    }
+
+    #[test]
+    fn p256_fixed_to_der() {
+        let sig = EcdsaSignatureP256::from_fixed(P256_FIXED).unwrap();
+        let mut buf = [0u8; 128];
+        assert_eq!(sig.encode_to_slice(&mut buf).unwrap(), P256_DER);
+    }
+
+    #[test]
+    fn short_components_are_padded() {
+        let der = hex!("3006 020101 020180");
+        assert!(EcdsaSignatureP256::from_bytes(&der).is_err());
+
+        let der = hex!("3007 020101 02020080");
+        let sig = EcdsaSignatureP256::from_bytes(&der).unwrap();
+
+        let mut fixed = [0u8; 64];
+        fixed[31] = 0x01;
+        fixed[63] = 0x80;
+
+        let mut buf = [0xFFu8; 64];
+        assert_eq!(sig.to_fixed(&mut buf).unwrap(), &fixed[..]);
+        assert_eq!(EcdsaSignatureP256::from_fixed(&fixed).unwrap(), sig);
+
+        let mut buf = [0u8; 16];
+        assert_eq!(sig.encode_to_slice(&mut buf).unwrap(), &der[..]);
+    }
+
+    #[test]
+    fn p521_round_trip() {
+        let mut fixed = [0u8; 132];
+        fixed[0] = 0x01;
+        fixed[65] = 0xFF;
+        fixed[66..].copy_from_slice(&[0xAB; 66]);
+        fixed[66] = 0x00;
+
+        let sig = EcdsaSignatureP521::from_fixed(&fixed).unwrap();
+        assert_eq!(sig.r.as_bytes().len(), 66);
+        assert_eq!(sig.s.as_bytes().len(), 65);
+
+        let mut buf = [0u8; 256];
+        let der = sig.encode_to_slice(&mut buf).unwrap();
+        let decoded = EcdsaSignatureP521::from_bytes(der).unwrap();
+        assert_eq!(decoded, sig);
+
+        let mut out = [0u8; 132];
+        assert_eq!(decoded.to_fixed(&mut out).unwrap(), &fixed[..]);
+    }
+
+    #[test]
+    fn reject_noncanonical_integers() {
+        // Unnecessary leading zero
+        let err = EcdsaSignatureP256::from_bytes(&hex!("3007 02020001 020101"))
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Noncanonical);
+
+        // Empty `INTEGER`
+        let err = EcdsaSignatureP256::from_bytes(&hex!("3005 0200 020101"))
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Length { tag: Tag::Integer });
+
+        // Zero
+        let err = EcdsaSignatureP256::from_bytes(&hex!("3006 020100 020101"))
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Integer });
+    }
+
+    #[test]
+    fn reject_oversized_integers() {
+        // 33-byte `r` without a leading zero
+        let mut der = [0u8; 40];
+        der[..4].copy_from_slice(&hex!("3026 0221"));
+        der[4] = 0x01;
+        der[37..].copy_from_slice(&hex!("020101"));
+        assert!(EcdsaSignatureP256::from_bytes(&der).is_err());
+
+        assert!(EcdsaSignatureP256::from_fixed(&[1u8; 63]).is_err());
+        assert!(EcdsaSignatureP256::from_fixed(&[1u8; 65]).is_err());
+    }
+
+    #[test]
+    fn to_fixed_buffer_too_small() {
+        let sig = EcdsaSignatureP256::from_fixed(P256_FIXED).unwrap();
+        let mut buf = [0u8; 63];
+        assert_eq!(
+            sig.to_fixed(&mut buf).err().unwrap(),
+            ErrorKind::Overlength.into()
+        );
+    }
+}
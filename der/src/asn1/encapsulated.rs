@@ -0,0 +1,326 @@
+//! ASN.1 `OCTET STRING` and `BIT STRING` values encapsulating DER-encoded
+//! messages.
+
+use crate::{
+    Any, BitString, Decodable, Encodable, Encoder, Error, ErrorKind, Header, Length, OctetString,
+    Result, ReverseEncoder, Tag, Tagged,
+};
+use core::convert::TryFrom;
+
+/// ASN.1 `OCTET STRING` whose contents are a DER-encoded value of type `T`.
+///
+/// This is a common pattern for embedding messages inside of other messages,
+/// e.g. the `privateKey` field of PKCS#8 `PrivateKeyInfo` or the `extnValue`
+/// field of an X.509 `Extension`. The inner value is decoded and encoded
+/// transparently.
+///
+/// Borrowed values can be encoded using `OctetStringEncapsulated<&dyn Encodable>`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct OctetStringEncapsulated<T>(pub T);
+
+/// ASN.1 `BIT STRING` whose contents are a DER-encoded value of type `T`.
+///
+/// The `BIT STRING` must not have any unused bits.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BitStringEncapsulated<T>(pub T);
+
+impl<T> OctetStringEncapsulated<T> {
+    /// Get the encapsulated value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> BitStringEncapsulated<T> {
+    /// Get the encapsulated value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<'a, T> TryFrom<Any<'a>> for OctetStringEncapsulated<T>
+where
+    T: Decodable<'a>,
+{
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        let octets = OctetString::try_from(any)?;
+        decode(any, octets.as_bytes()).map(Self)
+    }
+}
+
+impl<'a, T> TryFrom<Any<'a>> for BitStringEncapsulated<T>
+where
+    T: Decodable<'a>,
+{
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        let bits = BitString::try_from(any)?;
+
+        if bits.unused_bits() != 0 {
+            return Err(ErrorKind::Value {
+                tag: Tag::BitString,
+            }
+            .into());
+        }
+
+        decode(any, bits.as_bytes()).map(Self)
+    }
+}
+
+impl<T: Encodable> Encodable for OctetStringEncapsulated<T> {
+    fn encoded_len(&self) -> Result<Length> {
+        encoded_len(Tag::OctetString, &self.0, 0)
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        encoder.encode(&Header::new(Tag::OctetString, self.0.encoded_len()?)?)?;
+        encoder.encode(&self.0)
+    }
+
+    fn encode_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        let start = encoder.position();
+        encoder.encode(&self.0)?;
+        let length = encoder.position().to_usize() - start.to_usize();
+        encoder.header(Header::new(Tag::OctetString, length)?)
+    }
+}
+
+impl Encodable for OctetStringEncapsulated<&dyn Encodable> {
+    fn encoded_len(&self) -> Result<Length> {
+        encoded_len(Tag::OctetString, self.0, 0)
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        OctetStringEncapsulated(Dyn(self.0)).encode(encoder)
+    }
+
+    fn encode_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        OctetStringEncapsulated(Dyn(self.0)).encode_reverse(encoder)
+    }
+}
+
+impl<T: Encodable> Encodable for BitStringEncapsulated<T> {
+    fn encoded_len(&self) -> Result<Length> {
+        encoded_len(Tag::BitString, &self.0, 1)
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        let length = (self.0.encoded_len()? + 1u8)?;
+        encoder.encode(&Header::new(Tag::BitString, length)?)?;
+        encoder.byte(0)?;
+        encoder.encode(&self.0)
+    }
+
+    fn encode_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        let start = encoder.position();
+        encoder.encode(&self.0)?;
+        encoder.bytes(&[0])?;
+        let length = encoder.position().to_usize() - start.to_usize();
+        encoder.header(Header::new(Tag::BitString, length)?)
+    }
+}
+
+impl Encodable for BitStringEncapsulated<&dyn Encodable> {
+    fn encoded_len(&self) -> Result<Length> {
+        encoded_len(Tag::BitString, self.0, 1)
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        BitStringEncapsulated(Dyn(self.0)).encode(encoder)
+    }
+
+    fn encode_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        BitStringEncapsulated(Dyn(self.0)).encode_reverse(encoder)
+    }
+}
+
+impl<T> Tagged for OctetStringEncapsulated<T> {
+    const TAG: Tag = Tag::OctetString;
+}
+
+impl<T> Tagged for BitStringEncapsulated<T> {
+    const TAG: Tag = Tag::BitString;
+}
+
+/// Wrapper for borrowed trait objects, allowing them to be used with the
+/// generic [`Encodable`] impls above.
+struct Dyn<'a>(&'a dyn Encodable);
+
+impl Encodable for Dyn<'_> {
+    fn encoded_len(&self) -> Result<Length> {
+        self.0.encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.0.encode(encoder)
+    }
+
+    fn encode_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        self.0.encode_reverse(encoder)
+    }
+}
+
+/// Decode a value of type `T` encapsulated in the given `contents` of `any`,
+/// enforcing the [`Limits`][`crate::Limits`] of the message `any` is part of.
+fn decode<'a, T: Decodable<'a>>(any: Any<'a>, contents: &'a [u8]) -> Result<T> {
+    let mut decoder = any.encapsulated_decoder(contents);
+    let value = T::decode(&mut decoder)?;
+    decoder.finish(value)
+}
+
+/// Compute the encoded length of a value with the given `tag` which contains
+/// `prefix_len` bytes followed by the encapsulated `value`.
+fn encoded_len(tag: Tag, value: &(impl Encodable + ?Sized), prefix_len: u8) -> Result<Length> {
+    let length = (value.encoded_len()? + prefix_len)?;
+    Header::new(tag, length)?.encoded_len()? + length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BitStringEncapsulated, OctetStringEncapsulated};
+    use crate::{
+        Decodable, Decoder, Encodable, ErrorKind, Length, Limit, Limits, Null, OctetString,
+        Sequence, Tag,
+    };
+    use hex_literal::hex;
+
+    /// Decode `T` enforcing the given limits.
+    fn decode_with_limits<'a, T: Decodable<'a>>(
+        bytes: &'a [u8],
+        limits: Limits,
+    ) -> crate::Result<T> {
+        let mut decoder = Decoder::with_limits(bytes, limits);
+        let value = decoder.decode()?;
+        decoder.finish(value)
+    }
+
+    #[test]
+    fn octet_string_round_trip() {
+        let bytes = hex!("04 05 04 03 01 02 03");
+        let encapsulated = OctetStringEncapsulated::<OctetString<'_>>::from_bytes(&bytes).unwrap();
+        assert_eq!(encapsulated.0.as_bytes(), &[1, 2, 3]);
+
+        let mut buf = [0u8; 16];
+        assert_eq!(encapsulated.encode_to_slice(&mut buf).unwrap(), &bytes);
+        assert_eq!(encapsulated.encoded_len().unwrap(), Length::from(7u8));
+
+        let mut encoder = crate::Encoder::new(&mut buf);
+        encapsulated.encode(&mut encoder).unwrap();
+        assert_eq!(encoder.finish().unwrap(), &bytes);
+    }
+
+    #[test]
+    fn bit_string_round_trip() {
+        let bytes = hex!("03 03 00 05 00");
+        let encapsulated = BitStringEncapsulated::<Null>::from_bytes(&bytes).unwrap();
+
+        let mut buf = [0u8; 16];
+        assert_eq!(encapsulated.encode_to_slice(&mut buf).unwrap(), &bytes);
+
+        let borrowed = BitStringEncapsulated(&Null as &dyn Encodable);
+        assert_eq!(borrowed.encode_to_slice(&mut buf).unwrap(), &bytes);
+
+        let mut encoder = crate::Encoder::new(&mut buf);
+        borrowed.encode(&mut encoder).unwrap();
+        assert_eq!(encoder.finish().unwrap(), &bytes);
+    }
+
+    #[test]
+    fn bit_string_with_unused_bits() {
+        let err = BitStringEncapsulated::<Null>::from_bytes(&hex!("03 03 01 05 00")).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Value {
+                tag: Tag::BitString
+            }
+        );
+    }
+
+    #[test]
+    fn malformed_inner_value() {
+        let err = OctetStringEncapsulated::<Null>::from_bytes(&hex!("04 02 01 00")).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::UnexpectedTag {
+                expected: Some(Tag::Null),
+                actual: Tag::Boolean
+            }
+        );
+    }
+
+    #[test]
+    fn limits_enforced_on_encapsulated_message() {
+        // `OCTET STRING` encapsulating `SEQUENCE { SEQUENCE { NULL } }`
+        let bytes = hex!("04 06 30 04 30 02 05 00");
+
+        let limits = Limits {
+            max_depth: 2,
+            ..Default::default()
+        };
+
+        let err = decode_with_limits::<OctetStringEncapsulated<Sequence<'_>>>(&bytes, limits)
+            .unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::LimitExceeded {
+                limit: Limit::Depth
+            }
+        );
+
+        let limits = Limits {
+            max_depth: 3,
+            ..Default::default()
+        };
+
+        assert!(
+            decode_with_limits::<OctetStringEncapsulated<Sequence<'_>>>(&bytes, limits).is_ok()
+        );
+
+        // `OCTET STRING` encapsulating `SEQUENCE { NULL, NULL }`
+        let bytes = hex!("04 06 30 04 05 00 05 00");
+
+        let limits = Limits {
+            max_elements: 1,
+            ..Default::default()
+        };
+
+        let err = decode_with_limits::<OctetStringEncapsulated<Sequence<'_>>>(&bytes, limits)
+            .unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::LimitExceeded {
+                limit: Limit::Elements
+            }
+        );
+    }
+
+    #[test]
+    fn nested_encapsulations_count_towards_depth() {
+        // `OCTET STRING` encapsulating `BIT STRING` encapsulating `NULL`
+        let bytes = hex!("04 05 03 03 00 05 00");
+        type Nested = OctetStringEncapsulated<BitStringEncapsulated<Null>>;
+
+        let limits = Limits {
+            max_depth: 1,
+            ..Default::default()
+        };
+
+        let err = decode_with_limits::<Nested>(&bytes, limits).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::LimitExceeded {
+                limit: Limit::Depth
+            }
+        );
+
+        let limits = Limits {
+            max_depth: 2,
+            ..Default::default()
+        };
+
+        assert!(decode_with_limits::<Nested>(&bytes, limits).is_ok());
+    }
+}
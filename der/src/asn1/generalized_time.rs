@@ -13,7 +13,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 const LENGTH: usize = 15;
 
 /// Maximum duration since `UNIX_EPOCH` allowable as `GeneralizedTime`.
-const MAX_UNIX_DURATION: Duration = Duration::from_secs(253_402_300_800);
+pub(crate) const MAX_UNIX_DURATION: Duration = Duration::from_secs(253_402_300_800);
 
 /// ASN.1 `GeneralizedTime` type.
 ///
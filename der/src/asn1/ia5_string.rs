@@ -1,8 +1,8 @@
 //! ASN.1 `IA5String` support.
 
 use crate::{
-    str_slice::StrSlice, Any, ByteSlice, Encodable, Encoder, Error, ErrorKind, Length, Result, Tag,
-    Tagged,
+    limits::Nesting, str_slice::StrSlice, Any, ByteSlice, Encodable, Encoder, Error, ErrorKind,
+    Length, Result, Tag, Tagged,
 };
 use core::{convert::TryFrom, fmt, str};
 
@@ -93,6 +93,7 @@ impl<'a> From<Ia5String<'a>> for Any<'a> {
         Any {
             tag: Tag::Ia5String,
             value: ByteSlice::new(printable_string.as_bytes()).expect("overlength string"),
+            nesting: Nesting::default(),
         }
     }
 }
@@ -1,6 +1,6 @@
 //! ASN.1 `INTEGER` support.
 
-// TODO(tarcieri): add support for `i32`/`u32`
+// TODO(tarcieri): add support for `u32`/`u64`
 
 use crate::{Any, Encodable, Encoder, Error, ErrorKind, Header, Length, Result, Tag, Tagged};
 use core::convert::TryFrom;
@@ -98,6 +98,109 @@ impl Tagged for i16 {
     const TAG: Tag = Tag::Integer;
 }
 
+//
+// i32
+//
+
+impl TryFrom<Any<'_>> for i32 {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<i32> {
+        let tag = any.tag().assert_eq(Tag::Integer)?;
+
+        if any.as_bytes().len() > 4 {
+            return Err(ErrorKind::Length { tag }.into());
+        }
+
+        i64::try_from(any).map(|x| x as i32)
+    }
+}
+
+impl Encodable for i32 {
+    fn encoded_len(&self) -> Result<Length> {
+        i64::from(*self).encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        i64::from(*self).encode(encoder)
+    }
+}
+
+impl Tagged for i32 {
+    const TAG: Tag = Tag::Integer;
+}
+
+//
+// i64
+//
+
+impl TryFrom<Any<'_>> for i64 {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<i64> {
+        let tag = any.tag().assert_eq(Tag::Integer)?;
+        let bytes = any.as_bytes();
+
+        match *bytes {
+            [0, x, ..] if x < 0x80 => Err(ErrorKind::Noncanonical.into()),
+            [0xFF, x, ..] if x >= 0x80 => Err(ErrorKind::Noncanonical.into()),
+            [first, ..] if bytes.len() <= 8 => {
+                // Sign-extend the two's complement encoding to 8 bytes
+                let mut buffer = [if first >= 0x80 { 0xFF } else { 0 }; 8];
+                buffer[(8 - bytes.len())..].copy_from_slice(bytes);
+                Ok(i64::from_be_bytes(buffer))
+            }
+            _ => Err(ErrorKind::Length { tag }.into()),
+        }
+    }
+}
+
+impl Encodable for i64 {
+    fn encoded_len(&self) -> Result<Length> {
+        let inner_len = signed_len(*self);
+
+        Header {
+            tag: Tag::Integer,
+            length: inner_len.into(),
+        }
+        .encoded_len()?
+            + inner_len
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        let inner_len = signed_len(*self);
+
+        Header {
+            tag: Tag::Integer,
+            length: inner_len.into(),
+        }
+        .encode(encoder)?;
+
+        encoder.bytes(&self.to_be_bytes()[(8 - inner_len as usize)..])
+    }
+}
+
+impl Tagged for i64 {
+    const TAG: Tag = Tag::Integer;
+}
+
+/// Get the number of bytes in the minimal two's complement encoding of a
+/// signed integer, i.e. without redundant leading `0x00` or `0xFF` bytes.
+fn signed_len(value: i64) -> u8 {
+    let bytes = value.to_be_bytes();
+    let mut len = 8;
+
+    while len > 1 {
+        match bytes[(8 - len)..] {
+            [0, x, ..] if x < 0x80 => len -= 1,
+            [0xFF, x, ..] if x >= 0x80 => len -= 1,
+            _ => break,
+        }
+    }
+
+    len as u8
+}
+
 //
 // u8
 //
@@ -227,6 +330,13 @@ pub(crate) mod tests {
     pub(crate) const I32767_BYTES: &[u8] = &[0x02, 0x02, 0x7F, 0xFF];
     pub(crate) const I65535_BYTES: &[u8] = &[0x02, 0x03, 0x00, 0xFF, 0xFF];
     pub(crate) const INEG32768_BYTES: &[u8] = &[0x02, 0x02, 0x80, 0x00];
+    pub(crate) const I65536_BYTES: &[u8] = &[0x02, 0x03, 0x01, 0x00, 0x00];
+    pub(crate) const IMAX32_BYTES: &[u8] = &[0x02, 0x04, 0x7F, 0xFF, 0xFF, 0xFF];
+    pub(crate) const INEGMAX32_BYTES: &[u8] = &[0x02, 0x04, 0x80, 0x00, 0x00, 0x00];
+    pub(crate) const IMAX64_BYTES: &[u8] =
+        &[0x02, 0x08, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+    pub(crate) const INEGMAX64_BYTES: &[u8] =
+        &[0x02, 0x08, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
 
     #[test]
     fn decode_i8() {
@@ -248,6 +358,29 @@ pub(crate) mod tests {
         assert_eq!(-32768, i16::from_bytes(INEG32768_BYTES).unwrap());
     }
 
+    #[test]
+    fn decode_i32() {
+        assert_eq!(0, i32::from_bytes(I0_BYTES).unwrap());
+        assert_eq!(128, i32::from_bytes(I128_BYTES).unwrap());
+        assert_eq!(65535, i32::from_bytes(I65535_BYTES).unwrap());
+        assert_eq!(65536, i32::from_bytes(I65536_BYTES).unwrap());
+        assert_eq!(i32::MAX, i32::from_bytes(IMAX32_BYTES).unwrap());
+        assert_eq!(-129, i32::from_bytes(INEG129_BYTES).unwrap());
+        assert_eq!(i32::MIN, i32::from_bytes(INEGMAX32_BYTES).unwrap());
+        assert!(i32::from_bytes(IMAX64_BYTES).is_err());
+    }
+
+    #[test]
+    fn decode_i64() {
+        assert_eq!(0, i64::from_bytes(I0_BYTES).unwrap());
+        assert_eq!(128, i64::from_bytes(I128_BYTES).unwrap());
+        assert_eq!(65536, i64::from_bytes(I65536_BYTES).unwrap());
+        assert_eq!(-32768, i64::from_bytes(INEG32768_BYTES).unwrap());
+        assert_eq!(i32::MIN as i64, i64::from_bytes(INEGMAX32_BYTES).unwrap());
+        assert_eq!(i64::MAX, i64::from_bytes(IMAX64_BYTES).unwrap());
+        assert_eq!(i64::MIN, i64::from_bytes(INEGMAX64_BYTES).unwrap());
+    }
+
     #[test]
     fn decode_u8() {
         assert_eq!(0, u8::from_bytes(I0_BYTES).unwrap());
@@ -304,6 +437,50 @@ pub(crate) mod tests {
         );
     }
 
+    #[test]
+    fn encode_i32() {
+        let mut buffer = [0u8; 6];
+        assert_eq!(I0_BYTES, 0i32.encode_to_slice(&mut buffer).unwrap());
+        assert_eq!(I128_BYTES, 128i32.encode_to_slice(&mut buffer).unwrap());
+        assert_eq!(I65536_BYTES, 65536i32.encode_to_slice(&mut buffer).unwrap());
+        assert_eq!(IMAX32_BYTES, i32::MAX.encode_to_slice(&mut buffer).unwrap());
+
+        assert_eq!(
+            INEG129_BYTES,
+            (-129i32).encode_to_slice(&mut buffer).unwrap()
+        );
+
+        assert_eq!(
+            INEGMAX32_BYTES,
+            i32::MIN.encode_to_slice(&mut buffer).unwrap()
+        );
+    }
+
+    #[test]
+    fn encode_i64() {
+        let mut buffer = [0u8; 10];
+        assert_eq!(I0_BYTES, 0i64.encode_to_slice(&mut buffer).unwrap());
+        assert_eq!(I127_BYTES, 127i64.encode_to_slice(&mut buffer).unwrap());
+        assert_eq!(I128_BYTES, 128i64.encode_to_slice(&mut buffer).unwrap());
+        assert_eq!(I65535_BYTES, 65535i64.encode_to_slice(&mut buffer).unwrap());
+        assert_eq!(IMAX64_BYTES, i64::MAX.encode_to_slice(&mut buffer).unwrap());
+
+        assert_eq!(
+            INEG128_BYTES,
+            (-128i64).encode_to_slice(&mut buffer).unwrap()
+        );
+
+        assert_eq!(
+            INEG32768_BYTES,
+            (-32768i64).encode_to_slice(&mut buffer).unwrap()
+        );
+
+        assert_eq!(
+            INEGMAX64_BYTES,
+            i64::MIN.encode_to_slice(&mut buffer).unwrap()
+        );
+    }
+
     #[test]
     fn encode_u8() {
         let mut buffer = [0u8; 4];
@@ -329,6 +506,9 @@ pub(crate) mod tests {
     fn reject_non_canonical() {
         assert!(i8::from_bytes(&[0x02, 0x02, 0x00, 0x00]).is_err());
         assert!(i16::from_bytes(&[0x02, 0x02, 0x00, 0x00]).is_err());
+        assert!(i32::from_bytes(&[0x02, 0x02, 0x00, 0x00]).is_err());
+        assert!(i64::from_bytes(&[0x02, 0x02, 0x00, 0x00]).is_err());
+        assert!(i64::from_bytes(&[0x02, 0x02, 0xFF, 0x80]).is_err());
         assert!(u8::from_bytes(&[0x02, 0x02, 0x00, 0x00]).is_err());
         assert!(u16::from_bytes(&[0x02, 0x02, 0x00, 0x00]).is_err());
     }
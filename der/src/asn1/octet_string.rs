@@ -1,6 +1,9 @@
 //! ASN.1 `OCTET STRING` support.
 
-use crate::{Any, ByteSlice, Encodable, Encoder, Error, ErrorKind, Length, Result, Tag, Tagged};
+use crate::{
+    limits::Nesting, Any, ByteSlice, Encodable, Encoder, Error, ErrorKind, Length, Result, Tag,
+    Tagged,
+};
 use core::convert::TryFrom;
 
 /// ASN.1 `OCTET STRING` type.
@@ -60,6 +63,7 @@ impl<'a> From<OctetString<'a>> for Any<'a> {
         Any {
             tag: Tag::OctetString,
             value: octet_string.inner,
+            nesting: Nesting::default(),
         }
     }
 }
@@ -1,8 +1,13 @@
 //! ASN.1 `OBJECT IDENTIFIER`
 
-use crate::{Any, Encodable, Encoder, Error, Length, ObjectIdentifier, Result, Tag, Tagged};
+use crate::{
+    limits::Nesting, Any, Encodable, Encoder, Error, Length, ObjectIdentifier, Result, Tag, Tagged,
+};
 use core::convert::{TryFrom, TryInto};
 
+#[cfg(feature = "alloc")]
+use crate::ObjectIdentifierBuf;
+
 impl TryFrom<Any<'_>> for ObjectIdentifier {
     type Error = Error;
 
@@ -26,6 +31,7 @@ impl<'a> From<&'a ObjectIdentifier> for Any<'a> {
         Any {
             tag: Tag::ObjectIdentifier,
             value,
+            nesting: Nesting::default(),
         }
     }
 }
@@ -44,9 +50,44 @@ impl<'a> Tagged for ObjectIdentifier {
     const TAG: Tag = Tag::ObjectIdentifier;
 }
 
+#[cfg(feature = "alloc")]
+impl TryFrom<Any<'_>> for ObjectIdentifierBuf {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<ObjectIdentifierBuf> {
+        any.tag().assert_eq(Tag::ObjectIdentifier)?;
+        Ok(ObjectIdentifierBuf::from_ber(any.as_bytes())?)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> TryFrom<&'a ObjectIdentifierBuf> for Any<'a> {
+    type Error = Error;
+
+    fn try_from(oid: &'a ObjectIdentifierBuf) -> Result<Any<'a>> {
+        Any::new(Tag::ObjectIdentifier, oid.as_bytes())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Encodable for ObjectIdentifierBuf {
+    fn encoded_len(&self) -> Result<Length> {
+        Any::try_from(self)?.encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Any::try_from(self)?.encode(encoder)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Tagged for ObjectIdentifierBuf {
+    const TAG: Tag = Tag::ObjectIdentifier;
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Decodable, Encodable, Length, ObjectIdentifier};
+    use crate::{Decodable, Encodable, ErrorKind, Length, ObjectIdentifier};
 
     const EXAMPLE_OID: ObjectIdentifier = ObjectIdentifier::parse("1.2.840.113549");
     const EXAMPLE_OID_BYTES: &[u8; 8] = &[0x06, 0x06, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d];
@@ -59,6 +100,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn decode_malformed() {
+        let err = ObjectIdentifier::from_bytes(&[0x06, 0x03, 0x2a, 0x80, 0x01])
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Oid(const_oid::Error::NonMinimal { position: 1 })
+        );
+    }
+
     #[test]
     fn encode() {
         let mut buffer = [0u8; 8];
@@ -68,6 +121,26 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn decode_and_encode_buf() {
+        use crate::ObjectIdentifierBuf;
+
+        // Longer than `const_oid::MAX_LEN`, so only representable as a buffer
+        let oid = "1.3.6.1.4.1.311.21.8.16357212.11829124.4215683.8924829.3418345.160.1.1"
+            .parse::<ObjectIdentifierBuf>()
+            .unwrap();
+        assert!(oid.as_bytes().len() > const_oid::MAX_LEN);
+
+        let der_bytes = oid.to_vec().unwrap();
+        assert_eq!(der_bytes[..2], [0x06, oid.as_bytes().len() as u8]);
+        assert_eq!(ObjectIdentifierBuf::from_bytes(&der_bytes).unwrap(), oid);
+        assert!(ObjectIdentifier::from_bytes(&der_bytes).is_err());
+
+        let short = ObjectIdentifierBuf::from_bytes(EXAMPLE_OID_BYTES).unwrap();
+        assert_eq!(short, ObjectIdentifierBuf::from(EXAMPLE_OID));
+    }
+
     #[test]
     fn length() {
         // Ensure an infallible `From` conversion to `Any` will never panic
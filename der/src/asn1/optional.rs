@@ -1,6 +1,6 @@
 //! ASN.1 `OPTIONAL` as mapped to Rust's `Option` type
 
-use crate::{Choice, Decodable, Decoder, Encodable, Encoder, Length, Result, Tag};
+use crate::{Choice, Decodable, Decoder, Encodable, Encoder, Length, Result, ReverseEncoder, Tag};
 use core::convert::TryFrom;
 
 impl<'a, T> Decodable<'a> for Option<T>
@@ -8,8 +8,10 @@ where
     T: Choice<'a>, // NOTE: all `Decodable + Tagged` types receive a blanket `Choice` impl
 {
     fn decode(decoder: &mut Decoder<'a>) -> Result<Option<T>> {
-        if let Some(byte) = decoder.peek() {
-            if T::can_decode(Tag::try_from(byte)?) {
+        // Unsupported tags can't belong to `T`, so leave them to the caller
+        // (e.g. to be skipped as an extension of an extensible `SEQUENCE`)
+        if let Some(tag) = decoder.peek().and_then(|byte| Tag::try_from(byte).ok()) {
+            if T::can_decode(tag) {
                 return T::decode(decoder).map(Some);
             }
         }
@@ -37,4 +39,38 @@ where
             Ok(())
         }
     }
+
+    fn encode_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        if let Some(encodable) = self {
+            encodable.encode_reverse(encoder)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Encodable for Option<&dyn Encodable> {
+    fn encoded_len(&self) -> Result<Length> {
+        if let Some(encodable) = self {
+            encodable.encoded_len()
+        } else {
+            Ok(0u8.into())
+        }
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        if let Some(encodable) = self {
+            encodable.encode(encoder)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn encode_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        if let Some(encodable) = self {
+            encodable.encode_reverse(encoder)
+        } else {
+            Ok(())
+        }
+    }
 }
@@ -1,8 +1,8 @@
 //! ASN.1 `PrintableString` support.
 
 use crate::{
-    str_slice::StrSlice, Any, ByteSlice, Encodable, Encoder, Error, ErrorKind, Length, Result, Tag,
-    Tagged,
+    limits::Nesting, str_slice::StrSlice, Any, ByteSlice, Encodable, Encoder, Error, ErrorKind,
+    Length, Result, Tag, Tagged,
 };
 use core::{convert::TryFrom, fmt, str};
 
@@ -126,6 +126,7 @@ impl<'a> From<PrintableString<'a>> for Any<'a> {
         Any {
             tag: Tag::PrintableString,
             value: ByteSlice::new(printable_string.as_bytes()).expect("overlength string"),
+            nesting: Nesting::default(),
         }
     }
 }
@@ -1,10 +1,10 @@
 //! ASN.1 `SEQUENCE` support.
 
 use crate::{
-    Any, ByteSlice, Decoder, Encodable, Encoder, Error, ErrorKind, Header, Length, Result, Tag,
-    Tagged,
+    limits::Nesting, Any, ByteSlice, Decoder, Encodable, Encoder, Error, ErrorKind, Header, Length,
+    Result, Tag, Tagged,
 };
-use core::convert::TryFrom;
+use core::{convert::TryFrom, fmt};
 
 /// Obtain the length of an ASN.1 `SEQUENCE` of [`Encodable`] values when
 /// serialized as ASN.1 DER, including the `SEQUENCE` tag and length prefix.
@@ -24,17 +24,24 @@ pub(crate) fn encoded_len_inner(encodables: &[&dyn Encodable]) -> Result<Length>
 }
 
 /// ASN.1 `SEQUENCE` type.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone)]
 pub struct Sequence<'a> {
     /// Inner value
     inner: ByteSlice<'a>,
+
+    /// Limits and nesting depth of the [`Decoder`] this value was decoded
+    /// with, enforced when decoding its contents
+    nesting: Nesting,
 }
 
 impl<'a> Sequence<'a> {
     /// Create a new [`Sequence`] from a slice
     pub fn new(slice: &'a [u8]) -> Result<Self> {
         ByteSlice::new(slice)
-            .map(|inner| Self { inner })
+            .map(|inner| Self {
+                inner,
+                nesting: Nesting::default(),
+            })
             .map_err(|_| ErrorKind::Length { tag: Self::TAG }.into())
     }
 
@@ -50,7 +57,7 @@ impl<'a> Sequence<'a> {
     where
         F: FnOnce(&mut Decoder<'a>) -> Result<T>,
     {
-        let mut seq_decoder = Decoder::new(self.as_bytes());
+        let mut seq_decoder = Decoder::nested(self.as_bytes(), self.nesting.contents());
         let result = f(&mut seq_decoder)?;
         seq_decoder.finish(result)
     }
@@ -67,7 +74,11 @@ impl<'a> TryFrom<Any<'a>> for Sequence<'a> {
 
     fn try_from(any: Any<'a>) -> Result<Sequence<'a>> {
         any.tag().assert_eq(Tag::Sequence)?;
-        Self::new(any.as_bytes())
+
+        Ok(Self {
+            inner: any.value,
+            nesting: any.nesting,
+        })
     }
 }
 
@@ -76,10 +87,27 @@ impl<'a> From<Sequence<'a>> for Any<'a> {
         Any {
             tag: Tag::Sequence,
             value: seq.inner,
+            nesting: seq.nesting,
         }
     }
 }
 
+impl fmt::Debug for Sequence<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sequence")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl Eq for Sequence<'_> {}
+
+impl PartialEq for Sequence<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
 impl<'a> Encodable for Sequence<'a> {
     fn encoded_len(&self) -> Result<Length> {
         Any::from(*self).encoded_len()
@@ -16,7 +16,7 @@ const LENGTH: usize = 13;
 /// (non-inclusive) according to RFC 5280 rules.
 ///
 /// This corresponds to the RFC3339 date: `2050-01-01T00:00:00Z`
-const MAX_UNIX_DURATION: Duration = Duration::from_secs(2_524_608_000);
+pub(crate) const MAX_UNIX_DURATION: Duration = Duration::from_secs(2_524_608_000);
 
 /// ASN.1 `UTCTime` type.
 ///
@@ -137,7 +137,7 @@ impl Encodable for UtcTime {
             DateTime::from_unix_duration(self.0).ok_or(ErrorKind::Value { tag: Tag::UtcTime })?;
 
         debug_assert!((1950..2050).contains(&datetime.year()));
-        datetime::encode_decimal(encoder, Tag::UtcTime, datetime.year() - 1900)?;
+        datetime::encode_decimal(encoder, Tag::UtcTime, datetime.year() % 100)?;
         datetime::encode_decimal(encoder, Tag::UtcTime, datetime.month())?;
         datetime::encode_decimal(encoder, Tag::UtcTime, datetime.day())?;
         datetime::encode_decimal(encoder, Tag::UtcTime, datetime.hour())?;
@@ -168,4 +168,16 @@ mod tests {
         utc_time.encode(&mut encoder).unwrap();
         assert_eq!(example_bytes, encoder.finish().unwrap());
     }
+
+    #[test]
+    fn round_trip_21st_century() {
+        let example_bytes = hex!("17 0d 32 31 30 35 30 36 32 33 34 35 34 30 5a");
+        let utc_time = UtcTime::from_bytes(&example_bytes).unwrap();
+        assert_eq!(utc_time.unix_duration().as_secs(), 1620344740);
+
+        let mut buf = [0u8; 128];
+        let mut encoder = Encoder::new(&mut buf);
+        utc_time.encode(&mut encoder).unwrap();
+        assert_eq!(example_bytes, encoder.finish().unwrap());
+    }
 }
@@ -1,7 +1,8 @@
 //! ASN.1 `UTF8String` support.
 
 use crate::{
-    str_slice::StrSlice, Any, ByteSlice, Encodable, Encoder, Error, Length, Result, Tag, Tagged,
+    limits::Nesting, str_slice::StrSlice, Any, ByteSlice, Encodable, Encoder, Error, Length,
+    Result, Tag, Tagged,
 };
 use core::{convert::TryFrom, fmt, str};
 
@@ -85,6 +86,7 @@ impl<'a> From<Utf8String<'a>> for Any<'a> {
         Any {
             tag: Tag::Utf8String,
             value: ByteSlice::new(utf8_string.as_bytes()).expect("overlength string"),
+            nesting: Nesting::default(),
         }
     }
 }
@@ -0,0 +1,158 @@
+//! ASN.1 subtype constraints.
+//!
+//! These traits are used by the `#[asn1(size = "...")]` and
+//! `#[asn1(range = "...")]` attributes of `der`'s custom derive support.
+
+use crate::{
+    Any, BitString, Decoder, ErrorKind, Ia5String, OctetString, PrintableString, Result, Sequence,
+    Tag, Tagged, Utf8String,
+};
+use core::ops::RangeBounds;
+
+/// Types which can be checked against an ASN.1 `SIZE` constraint, e.g.
+/// `OCTET STRING (SIZE (16))`.
+///
+/// The size of a value is defined by X.680 as the number of bits in a
+/// `BIT STRING`, octets in an `OCTET STRING`, characters in a character
+/// string, or elements in a `SEQUENCE OF`.
+pub trait ConstrainedSize {
+    /// Check that the size of this value is within the given range, returning
+    /// [`ErrorKind::Constraint`] if it isn't.
+    fn check_size<R: RangeBounds<usize>>(&self, range: &R) -> Result<()>;
+}
+
+/// Types which can be checked against an ASN.1 value range constraint, e.g.
+/// `INTEGER (0..255)`.
+pub trait ConstrainedValue<T> {
+    /// Check that this value is within the given range, returning
+    /// [`ErrorKind::Constraint`] if it isn't.
+    fn check_range<R: RangeBounds<T>>(&self, range: &R) -> Result<()>;
+}
+
+impl<T: ConstrainedSize> ConstrainedSize for Option<T> {
+    fn check_size<R: RangeBounds<usize>>(&self, range: &R) -> Result<()> {
+        self.as_ref()
+            .map_or(Ok(()), |value| value.check_size(range))
+    }
+}
+
+impl<T, V: ConstrainedValue<T>> ConstrainedValue<T> for Option<V> {
+    fn check_range<R: RangeBounds<T>>(&self, range: &R) -> Result<()> {
+        self.as_ref()
+            .map_or(Ok(()), |value| value.check_range(range))
+    }
+}
+
+impl ConstrainedSize for BitString<'_> {
+    fn check_size<R: RangeBounds<usize>>(&self, range: &R) -> Result<()> {
+        check(Self::TAG, range, self.bit_len())
+    }
+}
+
+impl ConstrainedSize for Ia5String<'_> {
+    fn check_size<R: RangeBounds<usize>>(&self, range: &R) -> Result<()> {
+        check(Self::TAG, range, self.as_bytes().len())
+    }
+}
+
+impl ConstrainedSize for OctetString<'_> {
+    fn check_size<R: RangeBounds<usize>>(&self, range: &R) -> Result<()> {
+        check(Self::TAG, range, self.as_bytes().len())
+    }
+}
+
+impl ConstrainedSize for PrintableString<'_> {
+    fn check_size<R: RangeBounds<usize>>(&self, range: &R) -> Result<()> {
+        check(Self::TAG, range, self.as_bytes().len())
+    }
+}
+
+impl ConstrainedSize for Sequence<'_> {
+    fn check_size<R: RangeBounds<usize>>(&self, range: &R) -> Result<()> {
+        let mut decoder = Decoder::new(self.as_bytes());
+        let mut elements = 0usize;
+
+        while !decoder.is_finished() {
+            decoder.decode::<Any<'_>>()?;
+            elements += 1;
+        }
+
+        check(Self::TAG, range, elements)
+    }
+}
+
+impl ConstrainedSize for Utf8String<'_> {
+    fn check_size<R: RangeBounds<usize>>(&self, range: &R) -> Result<()> {
+        self.as_str().check_size(range)
+    }
+}
+
+impl ConstrainedSize for &str {
+    fn check_size<R: RangeBounds<usize>>(&self, range: &R) -> Result<()> {
+        check(Tag::Utf8String, range, self.chars().count())
+    }
+}
+
+macro_rules! impl_constrained_value {
+    ($($int:ty),+) => {
+        $(
+            impl ConstrainedValue<$int> for $int {
+                fn check_range<R: RangeBounds<$int>>(&self, range: &R) -> Result<()> {
+                    check(Self::TAG, range, *self)
+                }
+            }
+        )+
+    };
+}
+
+impl_constrained_value!(i8, i16, i32, i64, u8, u16);
+
+/// Check `value` is contained in `range`.
+fn check<T: PartialOrd>(tag: Tag, range: &impl RangeBounds<T>, value: T) -> Result<()> {
+    if range.contains(&value) {
+        Ok(())
+    } else {
+        Err(ErrorKind::Constraint { tag }.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConstrainedSize, ConstrainedValue};
+    use crate::{BitString, ErrorKind, OctetString, Sequence, Tag, Utf8String};
+
+    #[test]
+    fn size_constraints() {
+        let octets = OctetString::new(&[1, 2, 3]).unwrap();
+        assert!(octets.check_size(&(1..=3)).is_ok());
+        assert_eq!(
+            octets.check_size(&(4..)).unwrap_err().kind(),
+            ErrorKind::Constraint {
+                tag: Tag::OctetString
+            }
+        );
+
+        let bits = BitString::new(&[0xFF, 0xFF]).unwrap();
+        assert!(bits.check_size(&(16..=16)).is_ok());
+        assert!(bits.check_size(&(..16)).is_err());
+
+        let bits = BitString::with_unused_bits(&[0xFF, 0xF0], 4).unwrap();
+        assert!(bits.check_size(&(12..=12)).is_ok());
+
+        let utf8 = Utf8String::new("Ünïcödë").unwrap();
+        assert!(utf8.check_size(&(..=7)).is_ok());
+
+        let seq = Sequence::new(&[0x02, 0x01, 0x00, 0x05, 0x00]).unwrap();
+        assert!(seq.check_size(&(2..=2)).is_ok());
+        assert!(seq.check_size(&(1..2)).is_err());
+
+        assert!(None::<OctetString<'_>>.check_size(&(1..)).is_ok());
+    }
+
+    #[test]
+    fn value_constraints() {
+        assert!(42u8.check_range(&(0..=100)).is_ok());
+        assert!(Some(-1i16).check_range(&(0..)).is_err());
+        assert!(None::<i8>.check_range(&(0..)).is_ok());
+    }
+}
@@ -1,8 +1,11 @@
 //! Trait definition for [`Decodable`].
 
-use crate::{Any, Decoder, Error, Result};
+use crate::{Any, Decoder, Encodable, Error, Result};
 use core::convert::TryFrom;
 
+#[cfg(feature = "std")]
+use crate::{io, Limits};
+
 /// Decoding trait.
 ///
 /// This trait provides the core abstraction upon which all decoding operations
@@ -23,6 +26,25 @@ pub trait Decodable<'a>: Sized {
         let result = Self::decode(&mut decoder)?;
         decoder.finish(result)
     }
+
+    /// Read exactly one DER-encoded message from the provided reader into
+    /// `buf` (replacing its previous contents) and parse `Self` from it.
+    ///
+    /// The message header is read first, and the length it declares is
+    /// checked against [`Limits::max_length`] before the rest of the message
+    /// is read. The message is then decoded enforcing the given `limits`.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn decode_from_reader(
+        reader: impl std::io::Read,
+        buf: &'a mut std::vec::Vec<u8>,
+        limits: Limits,
+    ) -> Result<Self> {
+        let bytes = io::read_tlv(reader, buf, limits.max_length)?;
+        let mut decoder = Decoder::with_limits(bytes, limits);
+        let result = Self::decode(&mut decoder)?;
+        decoder.finish(result)
+    }
 }
 
 impl<'a, T> Decodable<'a> for T
@@ -35,3 +57,15 @@ where
             .or_else(|e| decoder.error(e.kind()))
     }
 }
+
+/// Types with a lifetime which can be decoded by borrowing data, e.g. from a
+/// [`Document`][`crate::Document`].
+///
+/// This trait is impl'd on the `'static` form of a type (e.g.
+/// `AlgorithmIdentifier<'static>`), mapping it to the same type borrowing
+/// data with lifetime `'a`. It's impl'd automatically by
+/// `#[derive(Message)]`.
+pub trait Borrowable<'a> {
+    /// Type borrowing data with lifetime `'a`.
+    type Borrowed: Decodable<'a> + Encodable;
+}
@@ -1,8 +1,8 @@
 //! DER decoder.
 
 use crate::{
-    Any, BitString, Choice, Decodable, ErrorKind, GeneralizedTime, Ia5String, Length, Null,
-    OctetString, PrintableString, Result, Sequence, UtcTime, Utf8String,
+    limits::Nesting, Any, BitString, Choice, Decodable, ErrorKind, GeneralizedTime, Ia5String,
+    Length, Limits, Null, OctetString, PrintableString, Result, UtcTime, Utf8String,
 };
 use core::convert::TryInto;
 
@@ -23,14 +23,53 @@ pub struct Decoder<'a> {
 
     /// Position within the decoded slice.
     position: Length,
+
+    /// Resource limits, along with the nesting depth of `bytes` within the
+    /// outermost message.
+    nesting: Nesting,
+
+    /// Have `bytes` been checked against the limits yet?
+    ///
+    /// Checked the first time data is read from the decoder.
+    checked: bool,
 }
 
 impl<'a> Decoder<'a> {
     /// Create a new decoder for the given byte slice.
+    ///
+    /// The decoder enforces the default [`Limits`].
     pub fn new(bytes: &'a [u8]) -> Self {
+        Self::with_limits(bytes, Limits::default())
+    }
+
+    /// Create a new decoder for the given byte slice which enforces the
+    /// provided resource [`Limits`].
+    pub fn with_limits(bytes: &'a [u8], limits: Limits) -> Self {
+        Self::encapsulated(bytes, Nesting { limits, depth: 0 })
+    }
+
+    /// Create a new decoder for the contents of a constructed value, which
+    /// have already been checked against the decoder's [`Limits`].
+    pub(crate) fn nested(bytes: &'a [u8], nesting: Nesting) -> Self {
+        Self {
+            bytes: Some(bytes),
+            position: Length::zero(),
+            nesting,
+            checked: true,
+        }
+    }
+
+    /// Create a new decoder for a message encapsulated inside of another
+    /// message at the given nesting, e.g. in an `OCTET STRING`.
+    ///
+    /// The encapsulated message is checked against the [`Limits`] of the
+    /// outer message, taking its nesting depth into account.
+    pub(crate) fn encapsulated(bytes: &'a [u8], nesting: Nesting) -> Self {
         Self {
             bytes: Some(bytes),
             position: Length::zero(),
+            nesting,
+            checked: false,
         }
     }
 
@@ -87,6 +126,28 @@ impl<'a> Decoder<'a> {
         self.decode()
     }
 
+    /// Decode the next element without interpreting its tag, returning its
+    /// complete DER encoding (tag, length and value).
+    ///
+    /// This can be used to skip elements with tags which aren't supported by
+    /// [`Tag`][`crate::Tag`], e.g. unknown extensions of an extensible `SEQUENCE`. Only
+    /// single-byte tags are supported.
+    pub fn tlv(&mut self) -> Result<&'a [u8]> {
+        let start = self.remaining()?;
+        let tag = self.byte()?;
+
+        // High tag number form (i.e. multi-byte tags)
+        if tag & 0x1F == 0x1F {
+            return self.error(ErrorKind::UnknownTag { byte: tag });
+        }
+
+        let length = self.decode::<Length>()?;
+        self.bytes(length)?;
+
+        let len = start.len() - self.remaining()?.len();
+        Ok(&start[..len])
+    }
+
     /// Attempt to decode ASN.1 `INTEGER` as `i8`
     pub fn int8(&mut self) -> Result<i8> {
         self.decode()
@@ -97,6 +158,16 @@ impl<'a> Decoder<'a> {
         self.decode()
     }
 
+    /// Attempt to decode ASN.1 `INTEGER` as `i32`
+    pub fn int32(&mut self) -> Result<i32> {
+        self.decode()
+    }
+
+    /// Attempt to decode ASN.1 `INTEGER` as `i64`
+    pub fn int64(&mut self) -> Result<i64> {
+        self.decode()
+    }
+
     /// Attempt to decode unsigned ASN.1 `INTEGER` as `u8`
     pub fn uint8(&mut self) -> Result<u8> {
         self.decode()
@@ -175,7 +246,7 @@ impl<'a> Decoder<'a> {
     where
         F: FnOnce(&mut Decoder<'a>) -> Result<T>,
     {
-        Sequence::decode(self)?.decode_nested(f).map_err(|e| {
+        self.any()?.sequence(f).map_err(|e| {
             self.bytes.take();
             e.nested(self.position)
         })
@@ -196,6 +267,16 @@ impl<'a> Decoder<'a> {
             self.error(ErrorKind::Failed)?;
         }
 
+        if !self.checked {
+            self.checked = true;
+            let Nesting { limits, depth } = self.nesting;
+
+            limits.check_nested(self.remaining()?, depth).map_err(|e| {
+                self.bytes.take();
+                e.nested(self.position)
+            })?;
+        }
+
         let len = len
             .try_into()
             .or_else(|_| self.error(ErrorKind::Overflow))?;
@@ -209,6 +290,16 @@ impl<'a> Decoder<'a> {
         Ok(result)
     }
 
+    /// Get the resource limits and nesting depth of this decoder.
+    pub(crate) fn nesting(&self) -> Nesting {
+        self.nesting
+    }
+
+    /// Get the current position of the cursor within the decoded slice.
+    pub(crate) fn position(&self) -> Length {
+        self.position
+    }
+
     /// Peek at the next byte in the decoder without modifying the cursor.
     pub(crate) fn peek(&self) -> Option<u8> {
         self.remaining()
@@ -273,4 +364,19 @@ mod tests {
         );
         assert_eq!(Some(Length::from(3u8)), err.position());
     }
+
+    #[test]
+    fn tlv() {
+        let mut decoder = Decoder::new(&[0x80, 0x01, 0xFF, 0x31, 0x00, 0x05, 0x00]);
+        assert_eq!(decoder.tlv().unwrap(), &[0x80, 0x01, 0xFF]);
+        assert_eq!(decoder.tlv().unwrap(), &[0x31, 0x00]);
+        assert_eq!(decoder.tlv().unwrap(), &[0x05, 0x00]);
+        assert!(decoder.is_finished());
+
+        let err = Decoder::new(&[0x9F, 0x1F, 0x00]).tlv().unwrap_err();
+        assert_eq!(ErrorKind::UnknownTag { byte: 0x9F }, err.kind());
+
+        let err = Decoder::new(&[0xA4, 0x02, 0x05]).tlv().unwrap_err();
+        assert_eq!(ErrorKind::Truncated, err.kind());
+    }
 }
@@ -0,0 +1,310 @@
+//! Owned ASN.1 DER documents.
+
+use crate::{Borrowable, Decodable, Encodable, Error, Result};
+use alloc::vec::Vec;
+use core::{
+    convert::{TryFrom, TryInto},
+    fmt,
+    marker::PhantomData,
+};
+
+#[cfg(feature = "pem")]
+use {
+    crate::{pem, PemLabel},
+    alloc::string::String,
+    core::str::FromStr,
+};
+
+#[cfg(feature = "std")]
+use std::path::Path;
+
+/// Owned ASN.1 DER document containing a message of type `T`.
+///
+/// Documents are guaranteed to be well-formed, i.e. the contained DER is
+/// checked to decode successfully as `T` when the document is constructed.
+/// Typed access to the message is provided by [`Document::decode`].
+///
+/// `T` is the `'static` form of a [`Borrowable`] type, which makes defining
+/// documents for new types a one-liner:
+///
+/// ```
+/// # #[cfg(all(feature = "derive", feature = "oid"))]
+/// # {
+/// use der::{Any, Message, ObjectIdentifier};
+///
+/// #[derive(Copy, Clone, Debug, Eq, PartialEq, Message)]
+/// pub struct AlgorithmIdentifier<'a> {
+///     pub algorithm: ObjectIdentifier,
+///     pub parameters: Option<Any<'a>>,
+/// }
+///
+/// pub type AlgorithmIdentifierDocument = der::Document<AlgorithmIdentifier<'static>>;
+///
+/// let doc = AlgorithmIdentifierDocument::from_der(&[
+///     0x30, 0x09, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01,
+/// ])
+/// .unwrap();
+///
+/// assert_eq!(doc.decode().algorithm.to_string(), "1.2.840.10045.2.1");
+/// # }
+/// ```
+///
+/// When `T` impls [`PemLabel`], documents can also be converted to and from
+/// PEM (with the `pem` feature).
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct Document<T> {
+    /// ASN.1 DER encoded message.
+    der_bytes: Vec<u8>,
+
+    /// Type of the message.
+    message: PhantomData<fn() -> T>,
+}
+
+impl<T> Document<T>
+where
+    T: for<'a> Borrowable<'a>,
+{
+    /// Create a new document from ASN.1 DER, checking it decodes as `T`.
+    pub fn from_der(bytes: &[u8]) -> Result<Self> {
+        bytes.to_vec().try_into()
+    }
+
+    /// Create a new document by encoding the given message as ASN.1 DER,
+    /// checking the encoding decodes as `T`.
+    ///
+    /// Messages whose encoding doesn't decode (e.g. because a field holds a
+    /// value its decoder rejects) produce an error rather than a document
+    /// which panics in [`Document::decode`].
+    pub fn from_msg<'a>(msg: &<T as Borrowable<'a>>::Borrowed) -> Result<Self> {
+        msg.to_vec()?.try_into()
+    }
+
+    /// Decode the message contained in this document.
+    pub fn decode(&self) -> <T as Borrowable<'_>>::Borrowed {
+        <T as Borrowable<'_>>::Borrowed::from_bytes(&self.der_bytes)
+            .expect("malformed DER document")
+    }
+
+    /// Borrow the ASN.1 DER encoding of this document.
+    pub fn as_der(&self) -> &[u8] {
+        &self.der_bytes
+    }
+
+    /// Convert this document into its ASN.1 DER encoding.
+    pub fn into_der(self) -> Vec<u8> {
+        self.der_bytes
+    }
+
+    /// Load a document from an ASN.1 DER-encoded file on the local
+    /// filesystem (binary format).
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read_der_file(path: impl AsRef<Path>) -> Result<Self> {
+        std::fs::read(path)?.try_into()
+    }
+
+    /// Write the ASN.1 DER encoding of this document to the given path.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write_der_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        std::fs::write(path, &self.der_bytes)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl<T> Document<T>
+where
+    T: for<'a> Borrowable<'a> + PemLabel,
+{
+    /// Parse a PEM-encoded document, checking its type label matches
+    /// [`PemLabel::TYPE_LABEL`].
+    pub fn from_pem(s: &str) -> Result<Self> {
+        pem::decode(s, T::TYPE_LABEL)?.try_into()
+    }
+
+    /// Serialize this document as PEM.
+    pub fn to_pem(&self) -> String {
+        pem::encode(&self.der_bytes, T::TYPE_LABEL)
+    }
+
+    /// Load a document from a PEM-encoded file on the local filesystem.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read_pem_file(path: impl AsRef<Path>) -> Result<Self> {
+        let pem = std::fs::read_to_string(path)?;
+        Self::from_pem(&pem)
+    }
+
+    /// Write the PEM encoding of this document to the given path.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write_pem_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        std::fs::write(path, self.to_pem())?;
+        Ok(())
+    }
+}
+
+impl<T> AsRef<[u8]> for Document<T> {
+    fn as_ref(&self) -> &[u8] {
+        &self.der_bytes
+    }
+}
+
+impl<T> Clone for Document<T> {
+    fn clone(&self) -> Self {
+        Self {
+            der_bytes: self.der_bytes.clone(),
+            message: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for Document<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Document").field(&self.der_bytes).finish()
+    }
+}
+
+impl<T> Eq for Document<T> {}
+
+impl<T> PartialEq for Document<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.der_bytes == other.der_bytes
+    }
+}
+
+impl<T> TryFrom<&[u8]> for Document<T>
+where
+    T: for<'a> Borrowable<'a>,
+{
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+impl<T> TryFrom<Vec<u8>> for Document<T>
+where
+    T: for<'a> Borrowable<'a>,
+{
+    type Error = Error;
+
+    fn try_from(der_bytes: Vec<u8>) -> Result<Self> {
+        // Ensure document is well-formed
+        <T as Borrowable<'_>>::Borrowed::from_bytes(&der_bytes)?;
+
+        Ok(Self {
+            der_bytes,
+            message: PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl<T> FromStr for Document<T>
+where
+    T: for<'a> Borrowable<'a> + PemLabel,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_pem(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Document;
+    use crate::{
+        Any, Borrowable, Encodable, Encoder, Error, ErrorKind, Length, Null, OctetString, PemLabel,
+        Result, Tag,
+    };
+    use core::convert::TryFrom;
+    use hex_literal::hex;
+
+    /// Example type for testing documents.
+    struct Example;
+
+    impl<'a> Borrowable<'a> for Example {
+        type Borrowed = OctetString<'a>;
+    }
+
+    impl PemLabel for Example {
+        const TYPE_LABEL: &'static str = "EXAMPLE";
+    }
+
+    const EXAMPLE_DER: &[u8] = &hex!("04 03 01 02 03");
+
+    /// Message which is encoded as a `BOOLEAN` but decoded as a `NULL`.
+    struct Mismatched;
+
+    impl<'a> Borrowable<'a> for Mismatched {
+        type Borrowed = Mismatched;
+    }
+
+    impl TryFrom<Any<'_>> for Mismatched {
+        type Error = Error;
+
+        fn try_from(any: Any<'_>) -> Result<Mismatched> {
+            Null::try_from(any).map(|_| Mismatched)
+        }
+    }
+
+    impl Encodable for Mismatched {
+        fn encoded_len(&self) -> Result<Length> {
+            true.encoded_len()
+        }
+
+        fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+            true.encode(encoder)
+        }
+    }
+
+    #[test]
+    fn from_der() {
+        let doc = Document::<Example>::from_der(EXAMPLE_DER).unwrap();
+        assert_eq!(doc.decode().as_bytes(), &[1, 2, 3]);
+        assert_eq!(doc.as_der(), EXAMPLE_DER);
+
+        let err = Document::<Example>::from_der(&hex!("05 00")).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::UnexpectedTag {
+                expected: Some(Tag::OctetString),
+                actual: Tag::Null
+            }
+        );
+    }
+
+    #[test]
+    fn from_msg() {
+        let msg = OctetString::new(&[1, 2, 3]).unwrap();
+        let doc = Document::<Example>::from_msg(&msg).unwrap();
+        assert_eq!(doc.as_der(), EXAMPLE_DER);
+
+        let err = Document::<Mismatched>::from_msg(&Mismatched).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::UnexpectedTag {
+                expected: Some(Tag::Null),
+                actual: Tag::Boolean
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "pem")]
+    fn pem() {
+        let doc = Document::<Example>::from_der(EXAMPLE_DER).unwrap();
+        let pem = doc.to_pem();
+        assert_eq!(
+            pem,
+            "-----BEGIN EXAMPLE-----\nBAMBAgM=\n-----END EXAMPLE-----\n"
+        );
+        assert_eq!(pem.parse::<Document<Example>>().unwrap(), doc);
+    }
+}
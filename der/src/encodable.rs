@@ -1,13 +1,10 @@
 //! Trait definition for [`Encodable`].
 
-use crate::{Encoder, Length, Result};
+use crate::{Encoder, ErrorKind, Length, Result, ReverseEncoder};
+use core::convert::TryInto;
 
 #[cfg(feature = "alloc")]
-use {
-    crate::ErrorKind,
-    alloc::vec::Vec,
-    core::{convert::TryInto, iter},
-};
+use {alloc::vec::Vec, core::iter};
 
 /// Encoding trait.
 pub trait Encodable {
@@ -17,12 +14,43 @@ pub trait Encodable {
     /// Encode this value as ASN.1 DER using the provided [`Encoder`].
     fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()>;
 
+    /// Encode this value as ASN.1 DER in front of the values previously
+    /// encoded by the provided [`ReverseEncoder`].
+    ///
+    /// The default implementation computes the length of this value and then
+    /// encodes it using [`Encodable::encode`]. Constructed types should
+    /// override it to encode their contents in reverse order followed by
+    /// their header, avoiding the need to compute the length of their
+    /// contents in advance.
+    fn encode_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        let len = self.encoded_len()?;
+        let mut nested_encoder = Encoder::new(encoder.reserve(len)?);
+        self.encode(&mut nested_encoder)?;
+
+        let actual_len = nested_encoder.finish()?.len();
+
+        if actual_len == len.to_usize() {
+            Ok(())
+        } else {
+            encoder.error(ErrorKind::Underlength {
+                expected: len,
+                actual: actual_len.try_into()?,
+            })
+        }
+    }
+
     /// Encode this value to the provided byte slice, returning a sub-slice
     /// containing the encoded message.
+    ///
+    /// The message is encoded using a [`ReverseEncoder`] and then moved to
+    /// the start of the buffer.
     fn encode_to_slice<'a>(&self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
-        let mut encoder = Encoder::new(buf);
-        self.encode(&mut encoder)?;
-        Ok(encoder.finish()?)
+        let mut encoder = ReverseEncoder::new(buf);
+        encoder.encode(self)?;
+        let len = encoder.finish()?.len();
+        let start = buf.len() - len;
+        buf.copy_within(start.., 0);
+        Ok(&buf[..len])
     }
 
     /// Encode this message as ASN.1 DER, appending it to the provided
@@ -31,11 +59,12 @@ pub trait Encodable {
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
     fn encode_to_vec(&self, buf: &mut Vec<u8>) -> Result<Length> {
         let expected_len = self.encoded_len()?.to_usize();
+        let start = buf.len();
         buf.reserve(expected_len);
         buf.extend(iter::repeat(0).take(expected_len));
 
-        let mut encoder = Encoder::new(buf);
-        self.encode(&mut encoder)?;
+        let mut encoder = ReverseEncoder::new(&mut buf[start..]);
+        encoder.encode(self)?;
         let actual_len = encoder.finish()?.len();
 
         if expected_len != actual_len {
@@ -57,4 +86,15 @@ pub trait Encodable {
         self.encode_to_vec(&mut buf)?;
         Ok(buf)
     }
+
+    /// Encode this message as ASN.1 DER, writing it to the provided writer.
+    ///
+    /// Returns the number of bytes written.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn encode_to_writer(&self, writer: &mut dyn std::io::Write) -> Result<Length> {
+        let buf = self.to_vec()?;
+        writer.write_all(&buf)?;
+        buf.len().try_into()
+    }
 }
@@ -2,7 +2,7 @@
 
 pub use core::str::Utf8Error;
 
-use crate::{Length, Tag};
+use crate::{Length, Limit, Tag};
 use core::{convert::Infallible, fmt};
 
 #[cfg(feature = "oid")]
@@ -91,8 +91,15 @@ impl From<Utf8Error> for Error {
 
 #[cfg(feature = "oid")]
 impl From<const_oid::Error> for Error {
-    fn from(_: const_oid::Error) -> Error {
-        ErrorKind::Oid.into()
+    fn from(err: const_oid::Error) -> Error {
+        ErrorKind::Oid(err).into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Error {
+        ErrorKind::Io(err.kind()).into()
     }
 }
 
@@ -103,6 +110,12 @@ impl std::error::Error for ErrorKind {}
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum ErrorKind {
+    /// Value violates an ASN.1 subtype constraint (e.g. `SIZE (1..MAX)`).
+    Constraint {
+        /// Tag of the constrained value.
+        tag: Tag,
+    },
+
     /// This error indicates a previous DER parsing operation resulted in
     /// an error and tainted the state of a `Decoder` or `Encoder`.
     ///
@@ -116,12 +129,33 @@ pub enum ErrorKind {
         tag: Tag,
     },
 
+    /// Resource limit configured on a `Decoder` was exceeded.
+    LimitExceeded {
+        /// Limit which was exceeded.
+        limit: Limit,
+    },
+
     /// Message is not canonically encoded.
     Noncanonical,
 
+    /// I/O errors.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    Io(std::io::ErrorKind),
+
+    /// Malformed JSON when converting JER into DER.
+    #[cfg(feature = "jer")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "jer")))]
+    Jer {
+        /// Byte offset of the malformed value within the JSON input.
+        position: usize,
+    },
+
     /// Malformed OID
     // TODO(tarcieri): rename this to `MalformedOid` in next breaking release
-    Oid,
+    #[cfg(feature = "oid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
+    Oid(const_oid::Error),
 
     /// Integer overflow occurred (library bug!).
     Overflow,
@@ -138,6 +172,11 @@ pub enum ErrorKind {
         remaining: Length,
     },
 
+    /// PEM encoding errors.
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    Pem,
+
     /// Unexpected end-of-message/nested field when decoding.
     Truncated,
 
@@ -206,10 +245,19 @@ impl ErrorKind {
 impl fmt::Display for ErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            ErrorKind::Constraint { tag } => write!(f, "constraint violated by {}", tag),
             ErrorKind::Failed => write!(f, "operation failed"),
             ErrorKind::Length { tag } => write!(f, "incorrect length for {}", tag),
+            ErrorKind::LimitExceeded { limit } => {
+                write!(f, "DER decoder limit exceeded: {}", limit.as_str())
+            }
             ErrorKind::Noncanonical => write!(f, "DER is not canonically encoded"),
-            ErrorKind::Oid => write!(f, "malformed OID"),
+            #[cfg(feature = "std")]
+            ErrorKind::Io(err) => write!(f, "I/O error: {:?}", err),
+            #[cfg(feature = "jer")]
+            ErrorKind::Jer { position } => write!(f, "malformed JER at offset {}", position),
+            #[cfg(feature = "oid")]
+            ErrorKind::Oid(err) => write!(f, "malformed OID: {}", err),
             ErrorKind::Overflow => write!(f, "integer overflow"),
             ErrorKind::Overlength => write!(f, "DER message is too long"),
             ErrorKind::TrailingData { decoded, remaining } => {
@@ -219,6 +267,8 @@ impl fmt::Display for ErrorKind {
                     decoded, remaining
                 )
             }
+            #[cfg(feature = "pem")]
+            ErrorKind::Pem => write!(f, "PEM encoding error"),
             ErrorKind::Truncated => write!(f, "DER message is truncated"),
             ErrorKind::Underlength { expected, actual } => write!(
                 f,
@@ -0,0 +1,126 @@
+//! Support for reading DER-encoded messages from [`std::io::Read`] streams.
+
+use crate::{Decodable, ErrorKind, Header, Length, Limit, Result};
+use core::convert::TryInto;
+use std::{io::Read, vec::Vec};
+
+/// Maximum length of a DER header supported by this crate: a single-byte tag
+/// followed by a length encoded in at most three bytes.
+const MAX_HEADER_LEN: usize = 4;
+
+/// Read exactly one tag-length-value encoded message from the given reader
+/// into `buf`, replacing its previous contents.
+///
+/// The header is read first, and the length it declares is checked against
+/// `max_length` before reading the value, so a malicious peer can't cause
+/// an arbitrarily large allocation.
+pub(crate) fn read_tlv(
+    mut reader: impl Read,
+    buf: &mut Vec<u8>,
+    max_length: Length,
+) -> Result<&[u8]> {
+    buf.clear();
+
+    // Tag byte and initial length byte
+    let mut header = [0u8; MAX_HEADER_LEN];
+    reader.read_exact(&mut header[..2])?;
+
+    // Long form lengths (up to the maximum supported by `Length`) are
+    // followed by additional length bytes. Other invalid length bytes are
+    // rejected when decoding the header.
+    let header_len = match header[1] {
+        0x81 => 3,
+        0x82 => 4,
+        _ => 2,
+    };
+
+    reader.read_exact(&mut header[2..header_len])?;
+    let header = &header[..header_len];
+    let value_len = Header::from_bytes(header)?.length;
+    let total_len = (value_len + header_len)?;
+
+    if total_len > max_length {
+        return Err(ErrorKind::LimitExceeded {
+            limit: Limit::Length,
+        }
+        .at(Length::zero()));
+    }
+
+    buf.reserve_exact(total_len.to_usize());
+    buf.extend_from_slice(header);
+    reader.take(value_len.to_usize() as u64).read_to_end(buf)?;
+
+    if buf.len() == total_len.to_usize() {
+        Ok(buf.as_slice())
+    } else {
+        Err(ErrorKind::Truncated.at(buf.len().try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Decodable, Encodable, ErrorKind, Length, Limit, Limits, OctetString};
+    use hex_literal::hex;
+
+    #[test]
+    fn round_trip() {
+        let mut stream = std::vec::Vec::new();
+        let octets = OctetString::new(&[1, 2, 3]).unwrap();
+        octets.encode_to_writer(&mut stream).unwrap();
+        true.encode_to_writer(&mut stream).unwrap();
+        assert_eq!(stream, hex!("04 03 01 02 03 01 01 FF"));
+
+        let mut reader = stream.as_slice();
+        let mut buf = std::vec::Vec::new();
+        let decoded =
+            OctetString::decode_from_reader(&mut reader, &mut buf, Limits::default()).unwrap();
+        assert_eq!(decoded, octets);
+
+        let mut buf = std::vec::Vec::new();
+        let decoded = bool::decode_from_reader(&mut reader, &mut buf, Limits::default()).unwrap();
+        assert!(decoded);
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn long_form_length() {
+        let mut bytes = std::vec![0x04, 0x81, 0x80];
+        bytes.extend_from_slice(&[0xAA; 0x80]);
+
+        let mut buf = std::vec::Vec::new();
+        let decoded =
+            OctetString::decode_from_reader(bytes.as_slice(), &mut buf, Limits::default()).unwrap();
+        assert_eq!(decoded.as_bytes(), &[0xAA; 0x80][..]);
+    }
+
+    #[test]
+    fn length_limit() {
+        let limits = Limits {
+            max_length: Length::from(4u8),
+            ..Limits::default()
+        };
+
+        let mut buf = std::vec::Vec::new();
+        let err = OctetString::decode_from_reader(&hex!("04 03 01 02 03")[..], &mut buf, limits)
+            .unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::LimitExceeded {
+                limit: Limit::Length
+            }
+        );
+    }
+
+    #[test]
+    fn truncated() {
+        let mut buf = std::vec::Vec::new();
+        let err =
+            OctetString::decode_from_reader(&hex!("04 03 01 02")[..], &mut buf, Limits::default())
+                .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Truncated);
+
+        let err =
+            OctetString::decode_from_reader(&[][..], &mut buf, Limits::default()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Io(std::io::ErrorKind::UnexpectedEof));
+    }
+}
@@ -0,0 +1,113 @@
+//! JSON Encoding Rules (JER) support: render DER-encoded values as JSON in
+//! the style of [X.697], and convert the same JSON back into DER.
+//!
+//! This is intended for logging, debugging and authoring test fixtures, e.g.
+//! writing a `PrivateKeyInfo` in JSON and converting it to DER with
+//! [`from_str`] before decoding it.
+//!
+//! # Data model
+//!
+//! Since DER doesn't carry the names of `SEQUENCE` components, this module
+//! operates on the tree of [`Any`][`crate::Any`] values making up a message
+//! rather than a schema. The components of a `SEQUENCE` are therefore
+//! rendered in order as a JSON array, and values which can't be told apart
+//! by their JSON type alone are wrapped in an object whose only member is
+//! named after their ASN.1 type:
+//!
+//! | ASN.1 type          | JSON                                              |
+//! |---------------------|---------------------------------------------------|
+//! | `BOOLEAN`           | `true` or `false`                                 |
+//! | `INTEGER`           | number (of any size)                              |
+//! | `NULL`              | `null`                                            |
+//! | `SEQUENCE`          | array of components                               |
+//! | `BIT STRING`        | `{"BIT STRING": {"value": "<hex>", "length": n}}` |
+//! | `OCTET STRING`      | `{"OCTET STRING": "<hex>"}`                       |
+//! | `OBJECT IDENTIFIER` | `{"OBJECT IDENTIFIER": "1.2.840.10045.2.1"}`      |
+//! | `UTF8String`        | `{"UTF8String": "..."}`                           |
+//! | `PrintableString`   | `{"PrintableString": "..."}`                      |
+//! | `IA5String`         | `{"IA5String": "..."}`                            |
+//! | `UTCTime`           | `{"UTCTime": "2021-05-06T23:45:40Z"}`             |
+//! | `GeneralizedTime`   | `{"GeneralizedTime": "2021-05-06T23:45:40Z"}`     |
+//! | `[n]`               | `{"[n]": [<contents>]}`                           |
+//!
+//! Hex strings are rendered in uppercase, the `length` of a `BIT STRING` is
+//! its number of bits, and times are rendered as [RFC 3339] timestamps in
+//! UTC. The contents of context-specific tags are rendered as an array of
+//! the DER elements they contain, i.e. a single element when the tag is
+//! `EXPLICIT`.
+//!
+//! # Example
+//!
+//! ```
+//! use der::{jer, Any, Decodable};
+//!
+//! let der = jer::from_str(r#"[
+//!     {"OBJECT IDENTIFIER": "1.2.840.10045.2.1"},
+//!     {"OBJECT IDENTIFIER": "1.2.840.10045.3.1.7"}
+//! ]"#)?;
+//!
+//! assert_eq!(der[..2], [0x30, 0x13]);
+//!
+//! let any = Any::from_bytes(&der)?;
+//! assert_eq!(
+//!     jer::to_string(&any)?,
+//!     r#"[{"OBJECT IDENTIFIER":"1.2.840.10045.2.1"},{"OBJECT IDENTIFIER":"1.2.840.10045.3.1.7"}]"#
+//! );
+//! # Ok::<(), der::Error>(())
+//! ```
+//!
+//! [X.697]: https://www.itu.int/rec/T-REC-X.697/
+//! [RFC 3339]: https://tools.ietf.org/html/rfc3339
+
+mod reader;
+mod writer;
+
+use crate::{Encodable, Result};
+use alloc::{string::String, vec::Vec};
+
+/// Render the given value as compact JSON.
+pub fn to_string(value: &impl Encodable) -> Result<String> {
+    writer::Writer::new(false).render(&value.to_vec()?)
+}
+
+/// Render the given value as JSON indented with two spaces.
+pub fn to_string_pretty(value: &impl Encodable) -> Result<String> {
+    writer::Writer::new(true).render(&value.to_vec()?)
+}
+
+/// Convert JSON in the format rendered by [`to_string`] into DER.
+///
+/// Insignificant whitespace is ignored. Errors in the JSON input are reported
+/// as [`ErrorKind::Jer`][`crate::ErrorKind::Jer`] with the byte offset of the
+/// offending value.
+pub fn from_str(json: &str) -> Result<Vec<u8>> {
+    reader::Reader::new(json).finish()
+}
+
+/// Negate a big endian two's complement integer in place.
+fn negate(bytes: &mut [u8]) {
+    let mut carry = true;
+
+    for byte in bytes.iter_mut().rev() {
+        let (sum, overflow) = (!*byte).overflowing_add(carry as u8);
+        *byte = sum;
+        carry = overflow;
+    }
+}
+
+/// Names of the ASN.1 types which are rendered as a single-member object,
+/// which are used as the name of that member.
+mod names {
+    pub(super) const BIT_STRING: &str = "BIT STRING";
+    pub(super) const OCTET_STRING: &str = "OCTET STRING";
+    pub(super) const OBJECT_IDENTIFIER: &str = "OBJECT IDENTIFIER";
+    pub(super) const UTF8_STRING: &str = "UTF8String";
+    pub(super) const PRINTABLE_STRING: &str = "PrintableString";
+    pub(super) const IA5_STRING: &str = "IA5String";
+    pub(super) const UTC_TIME: &str = "UTCTime";
+    pub(super) const GENERALIZED_TIME: &str = "GeneralizedTime";
+
+    /// Members of a `BIT STRING` object.
+    pub(super) const VALUE: &str = "value";
+    pub(super) const LENGTH: &str = "length";
+}
@@ -0,0 +1,542 @@
+//! Conversion of JSON into DER.
+
+use super::{names, negate};
+use crate::{
+    asn1::bit_string::is_valid as is_valid_bit_string, datetime::DateTime, Encodable, Error,
+    ErrorKind, GeneralizedTime, Header, Ia5String, Limit, Limits, Null, ObjectIdentifier,
+    PrintableString, Result, Tag, UtcTime, Utf8String,
+};
+use alloc::{string::String, vec, vec::Vec};
+use core::{char, str, time::Duration};
+
+/// JSON reader which converts values into DER as they're parsed.
+pub(super) struct Reader<'i> {
+    /// JSON input.
+    input: &'i [u8],
+
+    /// Current position within the input.
+    position: usize,
+
+    /// Current nesting depth of arrays.
+    depth: usize,
+}
+
+impl<'i> Reader<'i> {
+    /// Create a new reader for the given JSON.
+    pub(super) fn new(json: &'i str) -> Self {
+        Self {
+            input: json.as_bytes(),
+            position: 0,
+            depth: 0,
+        }
+    }
+
+    /// Convert the input into DER, ensuring it contains a single value.
+    pub(super) fn finish(mut self) -> Result<Vec<u8>> {
+        let der = self.value()?;
+        self.whitespace();
+
+        if self.position == self.input.len() {
+            Ok(der)
+        } else {
+            Err(self.error())
+        }
+    }
+
+    /// Convert a single value into DER.
+    fn value(&mut self) -> Result<Vec<u8>> {
+        self.whitespace();
+        let start = self.position;
+
+        match self.peek() {
+            Some(b't') => {
+                self.literal("true")?;
+                true.to_vec()
+            }
+            Some(b'f') => {
+                self.literal("false")?;
+                false.to_vec()
+            }
+            Some(b'n') => {
+                self.literal("null")?;
+                Null.to_vec()
+            }
+            Some(b'-') | Some(b'0'..=b'9') => {
+                let contents = self.integer()?;
+                self.tlv(start, Tag::Integer, &contents)
+            }
+            Some(b'[') => {
+                let contents = self.array()?;
+                self.tlv(start, Tag::Sequence, &contents)
+            }
+            Some(b'{') => self.object(),
+            _ => Err(self.error()),
+        }
+    }
+
+    /// Convert an array into the DER encodings of its elements.
+    fn array(&mut self) -> Result<Vec<u8>> {
+        self.whitespace();
+        self.expect(b'[')?;
+
+        if self.depth >= Limits::DEFAULT_MAX_DEPTH {
+            return Err(ErrorKind::LimitExceeded {
+                limit: Limit::Depth,
+            }
+            .into());
+        }
+
+        self.depth += 1;
+        let mut contents = Vec::new();
+        self.whitespace();
+
+        if self.peek() == Some(b']') {
+            self.position += 1;
+        } else {
+            loop {
+                contents.extend(self.value()?);
+                self.whitespace();
+
+                match self.next() {
+                    Some(b',') => (),
+                    Some(b']') => break,
+                    _ => return Err(self.error_at(self.position.saturating_sub(1))),
+                }
+            }
+        }
+
+        self.depth -= 1;
+        Ok(contents)
+    }
+
+    /// Convert a single-member object named after an ASN.1 type into DER.
+    fn object(&mut self) -> Result<Vec<u8>> {
+        self.expect(b'{')?;
+        let key_start = self.whitespace();
+        let key = self.string()?;
+        self.whitespace();
+        self.expect(b':')?;
+        let start = self.whitespace();
+
+        let der = match key.as_str() {
+            names::BIT_STRING => self.bit_string()?,
+            names::OCTET_STRING => {
+                let contents = self.hex()?;
+                self.tlv(start, Tag::OctetString, &contents)?
+            }
+            names::OBJECT_IDENTIFIER => {
+                let oid = self.string()?;
+                self.convert(start, oid.parse::<ObjectIdentifier>().map_err(Error::from))?
+            }
+            names::UTF8_STRING => {
+                let s = self.string()?;
+                self.convert(start, Utf8String::new(&s))?
+            }
+            names::PRINTABLE_STRING => {
+                let s = self.string()?;
+                self.convert(start, PrintableString::new(&s))?
+            }
+            names::IA5_STRING => {
+                let s = self.string()?;
+                self.convert(start, Ia5String::new(&s))?
+            }
+            names::UTC_TIME => {
+                let time = self.time()?;
+                self.convert(start, UtcTime::new(time))?
+            }
+            names::GENERALIZED_TIME => {
+                let time = self.time()?;
+                self.convert(start, GeneralizedTime::new(time))?
+            }
+            "[0]" => self.context_specific(start, Tag::ContextSpecific0)?,
+            "[1]" => self.context_specific(start, Tag::ContextSpecific1)?,
+            "[2]" => self.context_specific(start, Tag::ContextSpecific2)?,
+            "[3]" => self.context_specific(start, Tag::ContextSpecific3)?,
+            _ => return Err(self.error_at(key_start)),
+        };
+
+        self.whitespace();
+        self.expect(b'}')?;
+        Ok(der)
+    }
+
+    /// Convert the contents of a context-specific tag into DER.
+    fn context_specific(&mut self, start: usize, tag: Tag) -> Result<Vec<u8>> {
+        let contents = self.array()?;
+        self.tlv(start, tag, &contents)
+    }
+
+    /// Convert a `BIT STRING` object into DER.
+    fn bit_string(&mut self) -> Result<Vec<u8>> {
+        let start = self.position;
+        let mut value = None;
+        let mut length = None;
+
+        self.expect(b'{')?;
+
+        loop {
+            let key_start = self.whitespace();
+
+            match self.string()?.as_str() {
+                names::VALUE if value.is_none() => {
+                    self.whitespace();
+                    self.expect(b':')?;
+                    self.whitespace();
+                    value = Some(self.hex()?);
+                }
+                names::LENGTH if length.is_none() => {
+                    self.whitespace();
+                    self.expect(b':')?;
+                    self.whitespace();
+                    length = Some(self.unsigned()?);
+                }
+                _ => return Err(self.error_at(key_start)),
+            }
+
+            self.whitespace();
+
+            match self.next() {
+                Some(b',') => (),
+                Some(b'}') => break,
+                _ => return Err(self.error_at(self.position.saturating_sub(1))),
+            }
+        }
+
+        let (bytes, length) = match (value, length) {
+            (Some(bytes), Some(length)) => (bytes, length),
+            _ => return Err(self.error_at(start)),
+        };
+
+        let unused_bits = (bytes.len() * 8)
+            .checked_sub(length)
+            .filter(|&unused_bits| unused_bits < 8)
+            .ok_or_else(|| self.error_at(start))? as u8;
+
+        if !is_valid_bit_string(unused_bits, &bytes) {
+            return Err(self.error_at(start));
+        }
+
+        let mut contents = vec![unused_bits];
+        contents.extend_from_slice(&bytes);
+        self.tlv(start, Tag::BitString, &contents)
+    }
+
+    /// Parse an integer, returning the contents of its DER encoding.
+    fn integer(&mut self) -> Result<Vec<u8>> {
+        let negative = self.peek() == Some(b'-');
+
+        if negative {
+            self.position += 1;
+        }
+
+        // Magnitude of the integer, preceded by a zero sign byte
+        let mut bytes = vec![0];
+
+        for digit in self.digits()? {
+            let mut carry = (digit - b'0') as u16;
+
+            for byte in bytes.iter_mut().rev() {
+                let n = *byte as u16 * 10 + carry;
+                *byte = n as u8;
+                carry = n >> 8;
+            }
+
+            if carry != 0 || bytes[0] & 0x80 != 0 {
+                bytes.insert(0, carry as u8);
+            }
+        }
+
+        if negative {
+            negate(&mut bytes);
+        }
+
+        // Remove redundant leading bytes
+        let redundant = bytes
+            .windows(2)
+            .take_while(|pair| match pair {
+                [0x00, next] => next & 0x80 == 0,
+                [0xFF, next] => next & 0x80 != 0,
+                _ => false,
+            })
+            .count();
+
+        bytes.drain(..redundant);
+        Ok(bytes)
+    }
+
+    /// Parse a non-negative integer which fits in a `usize`.
+    fn unsigned(&mut self) -> Result<usize> {
+        let start = self.position;
+
+        self.digits()?
+            .iter()
+            .try_fold(0usize, |n, digit| {
+                n.checked_mul(10)?.checked_add((digit - b'0') as usize)
+            })
+            .ok_or_else(|| self.error_at(start))
+    }
+
+    /// Parse the digits of a JSON number, which must be an integer.
+    fn digits(&mut self) -> Result<&'i [u8]> {
+        let start = self.position;
+        let len = self.input[start..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+
+        self.position += len;
+        let digits = &self.input[start..self.position];
+
+        match (digits, self.peek()) {
+            ([], _) | ([b'0', _, ..], _) => Err(self.error_at(start)),
+            (_, Some(b'.')) | (_, Some(b'e')) | (_, Some(b'E')) => Err(self.error()),
+            _ => Ok(digits),
+        }
+    }
+
+    /// Parse a string containing hex-encoded bytes.
+    fn hex(&mut self) -> Result<Vec<u8>> {
+        let start = self.position;
+        let s = self.string()?;
+
+        if s.len() % 2 != 0 {
+            return Err(self.error_at(start));
+        }
+
+        s.as_bytes()
+            .chunks(2)
+            .map(|pair| {
+                str::from_utf8(pair)
+                    .ok()
+                    .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+            })
+            .collect::<Option<Vec<u8>>>()
+            .ok_or_else(|| self.error_at(start))
+    }
+
+    /// Parse a string containing an RFC 3339 timestamp in UTC, returning the
+    /// duration since `UNIX_EPOCH`.
+    fn time(&mut self) -> Result<Duration> {
+        let start = self.position;
+        let s = self.string()?;
+
+        let datetime = match *s.as_bytes() {
+            [y1, y2, y3, y4, b'-', mon1, mon2, b'-', day1, day2, b'T', hour1, hour2, b':', min1, min2, b':', sec1, sec2, b'Z'] => {
+                decimal(&[y1, y2, y3, y4]).and_then(|year| {
+                    DateTime::new(
+                        year,
+                        decimal(&[mon1, mon2])?,
+                        decimal(&[day1, day2])?,
+                        decimal(&[hour1, hour2])?,
+                        decimal(&[min1, min2])?,
+                        decimal(&[sec1, sec2])?,
+                    )
+                })
+            }
+            _ => None,
+        };
+
+        datetime
+            .and_then(|datetime| datetime.unix_duration())
+            .ok_or_else(|| self.error_at(start))
+    }
+
+    /// Parse a string, unescaping its contents.
+    fn string(&mut self) -> Result<String> {
+        self.expect(b'"')?;
+        let mut s = String::new();
+
+        loop {
+            let start = self.position;
+            let len = self.input[start..]
+                .iter()
+                .take_while(|&&b| b != b'"' && b != b'\\' && b >= 0x20)
+                .count();
+
+            self.position += len;
+
+            // Runs of unescaped characters end on an ASCII character, so they
+            // are always valid UTF-8
+            s.push_str(
+                str::from_utf8(&self.input[start..self.position]).map_err(|_| self.error())?,
+            );
+
+            match self.next() {
+                Some(b'"') => return Ok(s),
+                Some(b'\\') => s.push(self.escape()?),
+                _ => return Err(self.error_at(self.position.saturating_sub(1))),
+            }
+        }
+    }
+
+    /// Parse the remainder of an escape sequence following a `\`.
+    fn escape(&mut self) -> Result<char> {
+        let start = self.position - 1;
+
+        let c = match self.next() {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'b') => '\u{8}',
+            Some(b'f') => '\u{c}',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'u') => {
+                let high = self.hex_u16()?;
+
+                let code_point = if (0xD800..0xDC00).contains(&high) {
+                    // Surrogate pair
+                    if !self.input[self.position..].starts_with(b"\\u") {
+                        return Err(self.error_at(start));
+                    }
+
+                    self.position += 2;
+                    let low = self.hex_u16()?;
+
+                    if !(0xDC00..0xE000).contains(&low) {
+                        return Err(self.error_at(start));
+                    }
+
+                    0x10000 + ((high as u32 - 0xD800) << 10 | (low as u32 - 0xDC00))
+                } else {
+                    high as u32
+                };
+
+                char::from_u32(code_point).ok_or_else(|| self.error_at(start))?
+            }
+            _ => return Err(self.error_at(start)),
+        };
+
+        Ok(c)
+    }
+
+    /// Parse the 4 hex digits of a `\u` escape.
+    fn hex_u16(&mut self) -> Result<u16> {
+        let start = self.position;
+
+        let n = self
+            .input
+            .get(start..start + 4)
+            .and_then(|digits| str::from_utf8(digits).ok())
+            .filter(|digits| digits.bytes().all(|b| b.is_ascii_hexdigit()))
+            .and_then(|digits| u16::from_str_radix(digits, 16).ok())
+            .ok_or_else(|| self.error_at(start))?;
+
+        self.position += 4;
+        Ok(n)
+    }
+
+    /// Parse the given literal.
+    fn literal(&mut self, literal: &str) -> Result<()> {
+        if self.input[self.position..].starts_with(literal.as_bytes()) {
+            self.position += literal.len();
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    /// Encode the given contents as DER with a header.
+    fn tlv(&self, start: usize, tag: Tag, contents: &[u8]) -> Result<Vec<u8>> {
+        let mut der = self.convert(start, Header::new(tag, contents.len()))?;
+        der.extend_from_slice(contents);
+        Ok(der)
+    }
+
+    /// Encode a value which was constructed from the JSON value at the given
+    /// position as DER.
+    fn convert<T: Encodable>(&self, start: usize, value: Result<T>) -> Result<Vec<u8>> {
+        value
+            .and_then(|value| value.to_vec())
+            .map_err(|_| self.error_at(start))
+    }
+
+    /// Skip whitespace, returning the new position.
+    fn whitespace(&mut self) -> usize {
+        while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') = self.peek() {
+            self.position += 1;
+        }
+
+        self.position
+    }
+
+    /// Consume the expected byte.
+    fn expect(&mut self, byte: u8) -> Result<()> {
+        if self.peek() == Some(byte) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    /// Get the next byte without consuming it.
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.position).cloned()
+    }
+
+    /// Consume the next byte.
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.peek();
+
+        if byte.is_some() {
+            self.position += 1;
+        }
+
+        byte
+    }
+
+    /// Error at the current position.
+    fn error(&self) -> Error {
+        self.error_at(self.position)
+    }
+
+    /// Error at the given position.
+    fn error_at(&self, position: usize) -> Error {
+        ErrorKind::Jer { position }.into()
+    }
+}
+
+/// Parse ASCII decimal digits.
+fn decimal(digits: &[u8]) -> Option<u16> {
+    digits.iter().try_fold(0u16, |n, &digit| {
+        if digit.is_ascii_digit() {
+            Some(n * 10 + (digit - b'0') as u16)
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Reader;
+    use alloc::vec::Vec;
+
+    fn integer(json: &str) -> Vec<u8> {
+        Reader::new(json).integer().unwrap()
+    }
+
+    #[test]
+    fn integers() {
+        assert_eq!(integer("0"), [0x00]);
+        assert_eq!(integer("-0"), [0x00]);
+        assert_eq!(integer("127"), [0x7F]);
+        assert_eq!(integer("128"), [0x00, 0x80]);
+        assert_eq!(integer("-128"), [0x80]);
+        assert_eq!(integer("-129"), [0xFF, 0x7F]);
+        assert_eq!(integer("256"), [0x01, 0x00]);
+        assert_eq!(
+            integer("18446744073709551615"),
+            [0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]
+        );
+    }
+
+    #[test]
+    fn malformed_integers() {
+        for json in &["-", "01", "1.5", "1e3"] {
+            assert!(Reader::new(json).integer().is_err(), "{}", json);
+        }
+    }
+}
@@ -0,0 +1,332 @@
+//! Rendering of DER as JSON.
+
+use super::{names, negate};
+use crate::asn1::bit_string::is_valid as is_valid_bit_string;
+use crate::{datetime::DateTime, Any, Decoder, ErrorKind, Null, Result, Tag};
+use alloc::{string::String, vec::Vec};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Write},
+    time::Duration,
+};
+
+/// JSON writer which renders a tree of DER values.
+pub(super) struct Writer {
+    /// Rendered JSON.
+    out: String,
+
+    /// Should the output be indented?
+    pretty: bool,
+
+    /// Current nesting depth of arrays.
+    depth: usize,
+}
+
+impl Writer {
+    /// Create a new writer.
+    pub(super) fn new(pretty: bool) -> Self {
+        Self {
+            out: String::new(),
+            pretty,
+            depth: 0,
+        }
+    }
+
+    /// Render the given DER message as JSON.
+    pub(super) fn render(mut self, der: &[u8]) -> Result<String> {
+        let mut decoder = Decoder::new(der);
+        let any = decoder.decode()?;
+        decoder.finish(())?;
+        self.value(any)?;
+        Ok(self.out)
+    }
+
+    /// Render a single value.
+    fn value(&mut self, any: Any<'_>) -> Result<()> {
+        match any.tag() {
+            Tag::Boolean => self.out.push_str(if bool::try_from(any)? {
+                "true"
+            } else {
+                "false"
+            }),
+            Tag::Integer => {
+                let decimal = decimal(any.as_bytes())?;
+                self.out.push_str(&decimal);
+            }
+            Tag::Null => {
+                Null::try_from(any)?;
+                self.out.push_str("null");
+            }
+            Tag::BitString => {
+                let (unused_bits, bytes) = match any.as_bytes().split_first() {
+                    Some((&unused_bits, bytes)) if is_valid_bit_string(unused_bits, bytes) => {
+                        (unused_bits, bytes)
+                    }
+                    _ => return Err(ErrorKind::Value { tag: any.tag() }.into()),
+                };
+
+                self.begin_object(names::BIT_STRING);
+                self.out.push('{');
+                self.key(names::VALUE);
+                self.hex(bytes);
+                self.out.push(',');
+                self.space();
+                self.key(names::LENGTH);
+                let len = bytes.len() * 8 - unused_bits as usize;
+                self.write(format_args!("{}", len));
+                self.out.push('}');
+                self.end_object();
+            }
+            Tag::OctetString => {
+                self.begin_object(names::OCTET_STRING);
+                self.hex(any.as_bytes());
+                self.end_object();
+            }
+            Tag::ObjectIdentifier => {
+                let oid = any.oid()?;
+                self.begin_object(names::OBJECT_IDENTIFIER);
+                self.write(format_args!("\"{}\"", oid));
+                self.end_object();
+            }
+            Tag::Utf8String => {
+                self.begin_object(names::UTF8_STRING);
+                self.string(any.utf8_string()?.as_str());
+                self.end_object();
+            }
+            Tag::PrintableString => {
+                self.begin_object(names::PRINTABLE_STRING);
+                self.string(any.printable_string()?.as_str());
+                self.end_object();
+            }
+            Tag::Ia5String => {
+                self.begin_object(names::IA5_STRING);
+                self.string(any.ia5_string()?.as_str());
+                self.end_object();
+            }
+            Tag::UtcTime => {
+                let time = any.utc_time()?;
+                self.begin_object(names::UTC_TIME);
+                self.time(any.tag(), time.unix_duration())?;
+                self.end_object();
+            }
+            Tag::GeneralizedTime => {
+                let time = any.generalized_time()?;
+                self.begin_object(names::GENERALIZED_TIME);
+                self.time(any.tag(), time.unix_duration())?;
+                self.end_object();
+            }
+            Tag::Sequence => self.array(any)?,
+            Tag::ContextSpecific0
+            | Tag::ContextSpecific1
+            | Tag::ContextSpecific2
+            | Tag::ContextSpecific3 => {
+                let number = any.tag() as u8 & 0b11111;
+                self.out.push('{');
+                self.write(format_args!("\"[{}]\":", number));
+                self.space();
+                self.array(any)?;
+                self.end_object();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Render the DER elements in the contents of the given constructed value
+    /// as an array.
+    fn array(&mut self, any: Any<'_>) -> Result<()> {
+        let mut decoder = any.decoder();
+        let mut empty = true;
+
+        self.out.push('[');
+        self.depth += 1;
+
+        while !decoder.is_finished() {
+            if !empty {
+                self.out.push(',');
+            }
+
+            self.newline();
+            self.value(decoder.decode()?)?;
+            empty = false;
+        }
+
+        self.depth -= 1;
+
+        if !empty {
+            self.newline();
+        }
+
+        self.out.push(']');
+        Ok(())
+    }
+
+    /// Begin a single-member object named after an ASN.1 type.
+    fn begin_object(&mut self, name: &str) {
+        self.out.push('{');
+        self.key(name);
+    }
+
+    /// End a single-member object.
+    fn end_object(&mut self) {
+        self.out.push('}');
+    }
+
+    /// Render the key of an object member.
+    fn key(&mut self, name: &str) {
+        self.string(name);
+        self.out.push(':');
+        self.space();
+    }
+
+    /// Render a string, escaping it as needed.
+    fn string(&mut self, s: &str) {
+        self.out.push('"');
+
+        for c in s.chars() {
+            match c {
+                '"' => self.out.push_str("\\\""),
+                '\\' => self.out.push_str("\\\\"),
+                '\n' => self.out.push_str("\\n"),
+                '\r' => self.out.push_str("\\r"),
+                '\t' => self.out.push_str("\\t"),
+                c if (c as u32) < 0x20 => self.write(format_args!("\\u{:04x}", c as u32)),
+                c => self.out.push(c),
+            }
+        }
+
+        self.out.push('"');
+    }
+
+    /// Render bytes as an uppercase hex string.
+    fn hex(&mut self, bytes: &[u8]) {
+        self.out.push('"');
+
+        for byte in bytes {
+            self.write(format_args!("{:02X}", byte));
+        }
+
+        self.out.push('"');
+    }
+
+    /// Render a time as an RFC 3339 timestamp.
+    fn time(&mut self, tag: Tag, unix_duration: Duration) -> Result<()> {
+        let datetime =
+            DateTime::from_unix_duration(unix_duration).ok_or(ErrorKind::Value { tag })?;
+
+        self.write(format_args!(
+            "\"{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z\"",
+            datetime.year(),
+            datetime.month(),
+            datetime.day(),
+            datetime.hour(),
+            datetime.minute(),
+            datetime.second()
+        ));
+
+        Ok(())
+    }
+
+    /// Write formatted output.
+    fn write(&mut self, args: fmt::Arguments<'_>) {
+        // Writing to a `String` is infallible
+        let _ = self.out.write_fmt(args);
+    }
+
+    /// Separate a key from its value when pretty printing.
+    fn space(&mut self) {
+        if self.pretty {
+            self.out.push(' ');
+        }
+    }
+
+    /// Begin a new line at the current depth when pretty printing.
+    fn newline(&mut self) {
+        if self.pretty {
+            self.out.push('\n');
+
+            for _ in 0..self.depth {
+                self.out.push_str("  ");
+            }
+        }
+    }
+}
+
+/// Convert the contents of an `INTEGER` (big endian two's complement) into
+/// its decimal representation.
+fn decimal(bytes: &[u8]) -> Result<String> {
+    match bytes {
+        [] => return Err(ErrorKind::Length { tag: Tag::Integer }.into()),
+        [0x00, next, ..] if next & 0x80 == 0 => return Err(ErrorKind::Noncanonical.into()),
+        [0xFF, next, ..] if next & 0x80 != 0 => return Err(ErrorKind::Noncanonical.into()),
+        _ => (),
+    }
+
+    let negative = bytes[0] & 0x80 != 0;
+    let mut magnitude = bytes.to_vec();
+
+    if negative {
+        negate(&mut magnitude);
+    }
+
+    // Repeatedly divide by 10^9, collecting 9 decimal digits at a time
+    const CHUNK: u64 = 1_000_000_000;
+    let mut chunks = Vec::new();
+
+    while magnitude.iter().any(|&byte| byte != 0) {
+        let mut remainder = 0u64;
+
+        for byte in magnitude.iter_mut() {
+            let n = remainder << 8 | *byte as u64;
+            *byte = (n / CHUNK) as u8;
+            remainder = n % CHUNK;
+        }
+
+        chunks.push(remainder);
+    }
+
+    let mut decimal = String::from(if negative { "-" } else { "" });
+
+    match chunks.split_last() {
+        Some((most_significant, rest)) => {
+            // Writing to a `String` is infallible
+            let _ = write!(decimal, "{}", most_significant);
+
+            for chunk in rest.iter().rev() {
+                let _ = write!(decimal, "{:09}", chunk);
+            }
+        }
+        None => decimal.push('0'),
+    }
+
+    Ok(decimal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decimal;
+
+    #[test]
+    fn decimal_integers() {
+        assert_eq!(decimal(&[0x00]).unwrap(), "0");
+        assert_eq!(decimal(&[0x7F]).unwrap(), "127");
+        assert_eq!(decimal(&[0x00, 0x80]).unwrap(), "128");
+        assert_eq!(decimal(&[0x80]).unwrap(), "-128");
+        assert_eq!(decimal(&[0xFF, 0x7F]).unwrap(), "-129");
+        assert_eq!(
+            decimal(&[0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]).unwrap(),
+            "18446744073709551615"
+        );
+        assert_eq!(
+            decimal(&[0x0D, 0xE0, 0xB6, 0xB3, 0xA7, 0x64, 0x00, 0x00]).unwrap(),
+            "1000000000000000000"
+        );
+    }
+
+    #[test]
+    fn noncanonical_integers() {
+        assert!(decimal(&[]).is_err());
+        assert!(decimal(&[0x00, 0x7F]).is_err());
+        assert!(decimal(&[0xFF, 0x80]).is_err());
+    }
+}
@@ -35,7 +35,7 @@
 //!
 //! - `()`: ASN.1 `NULL` (see also [`Null`])
 //! - [`bool`]: ASN.1 `BOOLEAN`
-//! - [`i8`], [`i16`], [`u8`], [`u16`]: ASN.1 `INTEGER`
+//! - [`i8`], [`i16`], [`i32`], [`i64`], [`u8`], [`u16`]: ASN.1 `INTEGER`
 //! - [`str`]: ASN.1 `UTF8String` (see also [`Utf8String`])
 //! - [`Option`]: ASN.1 `OPTIONAL`
 //! - [`SystemTime`][`std::time::SystemTime`]: ASN.1 `GeneralizedTime` (requires `std` feature)
@@ -45,10 +45,12 @@
 //! - [`Any`]: ASN.1 `ANY`
 //! - [`BigUInt`]: ASN.1 unsigned `INTEGER` with raw access to encoded bytes
 //! - [`BitString`]: ASN.1 `BIT STRING`
+//! - [`EcdsaSignature`]: ECDSA `Ecdsa-Sig-Value`, i.e. `SEQUENCE { r INTEGER, s INTEGER }`
 //! - [`GeneralizedTime`]: ASN.1 `GeneralizedTime`
 //! - [`Ia5String`]: ASN.1 `IA5String`
 //! - [`Null`]: ASN.1 `NULL`
 //! - [`ObjectIdentifier`]: ASN.1 `OBJECT IDENTIFIER`
+//! - [`ObjectIdentifierBuf`]: heap-backed ASN.1 `OBJECT IDENTIFIER` of any length
 //! - [`OctetString`]: ASN.1 `OCTET STRING`
 //! - [`PrintableString`]: ASN.1 `PrintableString` (ASCII subset)
 //! - [`Sequence`]: ASN.1 `SEQUENCE`
@@ -228,6 +230,8 @@
 //! - [`Choice`]: derive for `CHOICE` enum (see [`der_derive::Choice`])
 //! - [`Message`]: derive for `SEQUENCE` struct (see [`der_derive::Message`])
 //!
+//! It also provides the [`der!`] macro (see [`der_derive::der`]).
+//!
 //! ### Derive [`Message`] for `SEQUENCE` struct
 //!
 //! The following is a code example of how to use the [`Message`] custom derive:
@@ -309,6 +313,74 @@
 //! # }
 //! ```
 //!
+//! ### Constraints
+//!
+//! ASN.1 subtype constraints can be enforced using the `#[asn1(size = "...")]`
+//! and `#[asn1(range = "...")]` attributes, which accept inclusive Rust range
+//! syntax (or a single value). Values which violate a constraint are
+//! rejected with [`ErrorKind::Constraint`] when both decoding and encoding:
+//!
+//! ```rust
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use der::Message;
+//!
+//! /// `KeyInfo ::= SEQUENCE { id OCTET STRING (SIZE (16)), version INTEGER (0..2) }`
+//! #[derive(Copy, Clone, Debug, Eq, PartialEq, Message)]
+//! pub struct KeyInfo<'a> {
+//!     #[asn1(type = "OCTET STRING", size = "16")]
+//!     pub id: &'a [u8],
+//!
+//!     #[asn1(range = "0..=2")]
+//!     pub version: u8,
+//! }
+//! # }
+//! ```
+//!
+//! See the [`ConstrainedSize`] and [`ConstrainedValue`] traits for the types
+//! which support each kind of constraint.
+//!
+//! ### Compile-time DER constants
+//!
+//! The [`der!`] macro encodes an ASN.1 value as DER at compile time,
+//! expanding to a byte array, which is handy for embedding fixed values like
+//! `AlgorithmIdentifier`s without resorting to opaque hex:
+//!
+//! ```rust
+//! # #[cfg(feature = "derive")]
+//! # {
+//! const ALGORITHM_ID: [u8; 21] = der::der!(SEQUENCE {
+//!     OID 1.2.840.10045.2.1,
+//!     OID 1.2.840.10045.3.1.7
+//! });
+//!
+//! assert_eq!(ALGORITHM_ID[..2], [0x30, 0x13]);
+//! # }
+//! ```
+//!
+//! ## Serde support
+//!
+//! When the `serde` feature of this crate is enabled, the [`serde`] module
+//! provides a `Serializer` and `Deserializer` which can be used with any type
+//! that impls serde's `Serialize` and `Deserialize` traits, mapping structs to
+//! `SEQUENCE`, enums to `CHOICE` and so on. See the module documentation for
+//! the full mapping.
+//!
+//! ## JSON support
+//!
+//! When the `jer` feature of this crate is enabled, the [`jer`] module can
+//! render any DER-encoded value (e.g. a [`Message`] or [`Any`]) as JSON in
+//! the style of the JSON Encoding Rules (X.697) for logging and debugging,
+//! and convert the same JSON back into DER, e.g. for authoring test fixtures.
+//!
+//! ## Fuzzing support
+//!
+//! When the `arbitrary` feature of this crate is enabled, the types in this
+//! crate impl the [`arbitrary`] crate's `Arbitrary` trait, as do types using
+//! the [`Message`] and [`Choice`] custom derives. Generated values are always
+//! valid and re-encode to identical DER after being decoded, which makes them
+//! suitable for round-trip fuzz targets.
+//!
 //! # See also
 //!
 //! For more information about ASN.1 DER we recommend the following guides:
@@ -329,12 +401,12 @@
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
     html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
-    html_root_url = "https://docs.rs/der/0.2.10"
+    html_root_url = "https://docs.rs/der/0.3.0"
 )]
 #![forbid(unsafe_code)]
 #![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
 
-#[cfg(feature = "alloc")]
+#[cfg(any(feature = "alloc", feature = "serde"))]
 extern crate alloc;
 
 #[cfg(feature = "std")]
@@ -342,6 +414,7 @@ extern crate std;
 
 mod asn1;
 mod byte_slice;
+mod constraint;
 mod datetime;
 mod decodable;
 mod decoder;
@@ -350,15 +423,31 @@ mod encoder;
 mod error;
 mod header;
 mod length;
+mod limits;
 mod message;
+mod pem;
+mod reverse_encoder;
 mod str_slice;
 mod tag;
 
+#[cfg(feature = "alloc")]
+mod document;
+#[cfg(feature = "std")]
+mod io;
+
+#[cfg(feature = "jer")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jer")))]
+pub mod jer;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
+
 pub use crate::{
     asn1::{
         any::Any,
         bit_string::BitString,
         choice::Choice,
+        encapsulated::{BitStringEncapsulated, OctetStringEncapsulated},
         generalized_time::GeneralizedTime,
         ia5_string::Ia5String,
         null::Null,
@@ -368,30 +457,72 @@ pub use crate::{
         utc_time::UtcTime,
         utf8_string::Utf8String,
     },
-    decodable::Decodable,
+    constraint::{ConstrainedSize, ConstrainedValue},
+    decodable::{Borrowable, Decodable},
     decoder::Decoder,
     encodable::Encodable,
     encoder::Encoder,
     error::{Error, ErrorKind, Result},
     header::Header,
     length::Length,
+    limits::{Limit, Limits},
     message::Message,
+    pem::PemLabel,
+    reverse_encoder::ReverseEncoder,
     tag::{Tag, Tagged},
 };
 
 pub(crate) use crate::byte_slice::ByteSlice;
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use crate::document::Document;
+
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+pub use arbitrary_crate as arbitrary;
+
 #[cfg(feature = "big-uint")]
 #[cfg_attr(docsrs, doc(cfg(feature = "big-uint")))]
 pub use {
-    crate::asn1::big_uint::{BigUInt, BigUIntSize},
+    crate::asn1::{
+        big_uint::{BigUInt, BigUIntSize},
+        ecdsa_signature::{
+            EcdsaSignature, EcdsaSignatureP256, EcdsaSignatureP384, EcdsaSignatureP521,
+        },
+    },
     typenum::consts,
 };
 
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
-pub use der_derive::{Choice, Message};
+pub use der_derive::{der, Choice, Message};
 
 #[cfg(feature = "oid")]
 #[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
-pub use const_oid::ObjectIdentifier;
+pub use const_oid::{oid_map, oid_set, ObjectIdentifier, OidMap, OidSet};
+
+#[cfg(all(feature = "alloc", feature = "oid"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "oid"))))]
+pub use const_oid::ObjectIdentifierBuf;
+
+/// Emit the given items only when the `arbitrary` feature is enabled.
+///
+/// Used by `der_derive` to generate `Arbitrary` impls for custom derived
+/// types. Not a public API.
+#[cfg(feature = "arbitrary")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_arbitrary {
+    ($($item:item)*) => {
+        $($item)*
+    };
+}
+
+/// Emit the given items only when the `arbitrary` feature is enabled.
+#[cfg(not(feature = "arbitrary"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_arbitrary {
+    ($($item:item)*) => {};
+}
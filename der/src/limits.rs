@@ -0,0 +1,313 @@
+//! Resource limits enforced when decoding untrusted input.
+
+use crate::{Decodable, Decoder, ErrorKind, Length, Result};
+use core::convert::TryInto;
+
+/// Indicator bit for constructed form encoding (i.e. vs primitive form)
+const CONSTRUCTED_FLAG: u8 = 0b100000;
+
+/// Resource limits enforced by a [`Decoder`].
+///
+/// Decoding ASN.1 DER is recursive: every nested `SEQUENCE` adds another
+/// level of nesting to the decoder's call stack. When parsing untrusted
+/// input, these limits bound how deeply nested and how large a message
+/// can be before the [`Decoder`] gives up with an
+/// [`ErrorKind::LimitExceeded`] error.
+///
+/// Limits are checked with a single pass over the input before any values
+/// are decoded, so they apply to the entire message passed to the
+/// [`Decoder`] regardless of which types are ultimately used to decode it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Limits {
+    /// Maximum nesting depth of constructed values (e.g. `SEQUENCE`).
+    pub max_depth: usize,
+
+    /// Maximum number of elements inside of a single constructed value
+    /// (e.g. the items of a `SEQUENCE OF` or `SET OF`).
+    pub max_elements: usize,
+
+    /// Maximum total length of the message being decoded.
+    pub max_length: Length,
+}
+
+impl Limits {
+    /// Default maximum nesting depth.
+    pub const DEFAULT_MAX_DEPTH: usize = 32;
+
+    /// Default maximum number of elements inside of a single constructed
+    /// value: unlimited, as the number of elements is already bounded by
+    /// [`Limits::max_length`].
+    pub const DEFAULT_MAX_ELEMENTS: usize = usize::MAX;
+
+    /// Check that the given DER-encoded message does not exceed these limits.
+    ///
+    /// Only violations of the limits are reported: malformed messages are
+    /// left for the decoder to reject with a more specific error.
+    pub fn check(&self, bytes: &[u8]) -> Result<()> {
+        self.check_nested(bytes, 0)
+    }
+
+    /// Check a DER-encoded message which is nested at the given `depth`
+    /// inside of another message, e.g. encapsulated in an `OCTET STRING`.
+    pub(crate) fn check_nested(&self, bytes: &[u8], depth: usize) -> Result<()> {
+        if bytes.len() > self.max_length.to_usize() {
+            return Err(ErrorKind::LimitExceeded {
+                limit: Limit::Length,
+            }
+            .at(Length::zero()));
+        }
+
+        if depth > self.max_depth {
+            return Err(ErrorKind::LimitExceeded {
+                limit: Limit::Depth,
+            }
+            .at(Length::zero()));
+        }
+
+        self.check_contents(bytes, depth)
+    }
+
+    /// Check the TLV-encoded contents of a constructed value at the given
+    /// nesting `depth`.
+    fn check_contents(&self, bytes: &[u8], depth: usize) -> Result<()> {
+        let mut decoder = Decoder::nested(bytes, Nesting::default());
+        let mut elements = 0usize;
+
+        while !decoder.is_finished() {
+            let position = decoder.position();
+            elements = elements.saturating_add(1);
+
+            if elements > self.max_elements {
+                return Err(ErrorKind::LimitExceeded {
+                    limit: Limit::Elements,
+                }
+                .at(position));
+            }
+
+            let (constructed, value) = match Self::next_value(&mut decoder) {
+                Ok(tlv) => tlv,
+                // Malformed messages are the decoder's responsibility
+                Err(_) => return Ok(()),
+            };
+
+            if constructed {
+                if depth >= self.max_depth {
+                    return Err(ErrorKind::LimitExceeded {
+                        limit: Limit::Depth,
+                    }
+                    .at(position));
+                }
+
+                let value_position = (decoder.position().to_usize() - value.len()).try_into()?;
+
+                self.check_contents(value, depth + 1)
+                    .map_err(|e| e.nested(value_position))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Read the next TLV-encoded value, returning whether it's constructed
+    /// along with its contents.
+    fn next_value<'a>(decoder: &mut Decoder<'a>) -> Result<(bool, &'a [u8])> {
+        let tag = decoder.byte()?;
+        let length = Length::decode(decoder)?;
+        let value = decoder.bytes(length)?;
+        Ok((tag & CONSTRUCTED_FLAG != 0, value))
+    }
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_depth: Self::DEFAULT_MAX_DEPTH,
+            max_elements: Self::DEFAULT_MAX_ELEMENTS,
+            max_length: Length::from(u16::MAX),
+        }
+    }
+}
+
+/// [`Limits`] in force at a particular nesting depth of a message.
+///
+/// Carried from a [`Decoder`] to the [`Any`][`crate::Any`] values it decodes,
+/// so the limits of a message are also enforced on messages encapsulated
+/// inside of it (e.g. in an `OCTET STRING`).
+#[derive(Copy, Clone, Debug, Default)]
+pub(crate) struct Nesting {
+    /// Limits of the outermost message.
+    pub(crate) limits: Limits,
+
+    /// Depth of the value within the outermost message.
+    pub(crate) depth: usize,
+}
+
+impl Nesting {
+    /// Get the nesting of the contents of a value at this nesting.
+    pub(crate) fn contents(self) -> Self {
+        Self {
+            limits: self.limits,
+            depth: self.depth.saturating_add(1),
+        }
+    }
+}
+
+/// Resource limit which was exceeded while decoding (see [`Limits`]).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Limit {
+    /// Maximum nesting depth ([`Limits::max_depth`]).
+    Depth,
+
+    /// Maximum number of elements ([`Limits::max_elements`]).
+    Elements,
+
+    /// Maximum message length ([`Limits::max_length`]).
+    Length,
+}
+
+impl Limit {
+    /// Get a description of this limit.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Limit::Depth => "nesting depth",
+            Limit::Elements => "element count",
+            Limit::Length => "message length",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Limit, Limits};
+    use crate::{Decodable, Decoder, ErrorKind, Length, Null, OctetStringEncapsulated, Sequence};
+
+    /// `SEQUENCE { SEQUENCE { SEQUENCE { NULL } } }`
+    const NESTED_SEQUENCES: &[u8] = &[0x30, 0x06, 0x30, 0x04, 0x30, 0x02, 0x05, 0x00];
+
+    /// `SEQUENCE { NULL, NULL, NULL, NULL }`
+    const FOUR_NULLS: &[u8] = &[0x30, 0x08, 0x05, 0x00, 0x05, 0x00, 0x05, 0x00, 0x05, 0x00];
+
+    fn limits(max_depth: usize, max_elements: usize) -> Limits {
+        Limits {
+            max_depth,
+            max_elements,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn within_limits() {
+        assert!(limits(3, 1).check(NESTED_SEQUENCES).is_ok());
+        assert!(limits(1, 4).check(FOUR_NULLS).is_ok());
+
+        let mut decoder = Decoder::with_limits(FOUR_NULLS, limits(1, 4));
+        let seq = decoder.decode::<Sequence<'_>>().unwrap();
+        decoder.finish(seq).unwrap();
+    }
+
+    #[test]
+    fn depth_exceeded() {
+        let err = limits(2, 1).check(NESTED_SEQUENCES).err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::LimitExceeded {
+                limit: Limit::Depth
+            }
+        );
+        assert_eq!(err.position(), Some(Length::from(4u8)));
+    }
+
+    #[test]
+    fn elements_exceeded() {
+        let err = limits(1, 3).check(FOUR_NULLS).err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::LimitExceeded {
+                limit: Limit::Elements
+            }
+        );
+        assert_eq!(err.position(), Some(Length::from(8u8)));
+    }
+
+    #[test]
+    fn length_exceeded() {
+        let limits = Limits {
+            max_length: Length::from(7u8),
+            ..Default::default()
+        };
+
+        let err = limits.check(NESTED_SEQUENCES).err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::LimitExceeded {
+                limit: Limit::Length
+            }
+        );
+    }
+
+    #[test]
+    fn decoder_enforces_limits() {
+        let mut decoder = Decoder::with_limits(NESTED_SEQUENCES, limits(2, 1));
+        let err = decoder.decode::<Sequence<'_>>().err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::LimitExceeded {
+                limit: Limit::Depth
+            }
+        );
+        assert!(decoder.is_failed());
+    }
+
+    #[test]
+    fn default_limits_reject_deep_nesting() {
+        let depth = Limits::DEFAULT_MAX_DEPTH + 1;
+        let mut bytes = [0u8; 2 * (Limits::DEFAULT_MAX_DEPTH + 2)];
+
+        for i in 0..depth {
+            bytes[2 * i] = 0x30;
+            bytes[2 * i + 1] = (2 * (depth - i)) as u8;
+        }
+
+        bytes[2 * depth] = 0x05;
+
+        let err = Sequence::from_bytes(&bytes).err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::LimitExceeded {
+                limit: Limit::Depth
+            }
+        );
+    }
+
+    #[test]
+    fn sequence_contents_keep_limits() {
+        // `SEQUENCE { OCTET STRING { SEQUENCE { SEQUENCE { NULL } } } }`
+        let bytes = [0x30, 0x08, 0x04, 0x06, 0x30, 0x04, 0x30, 0x02, 0x05, 0x00];
+
+        let mut decoder = Decoder::with_limits(&bytes, limits(2, 1));
+        let seq = decoder.decode::<Sequence<'_>>().unwrap();
+        let err = seq
+            .decode_nested(|decoder| decoder.decode::<OctetStringEncapsulated<Sequence<'_>>>())
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            err.kind(),
+            ErrorKind::LimitExceeded {
+                limit: Limit::Depth
+            }
+        );
+
+        // The same message is within the default limits
+        let seq = Sequence::from_bytes(&bytes).unwrap();
+        assert!(seq
+            .decode_nested(|decoder| decoder.decode::<OctetStringEncapsulated<Sequence<'_>>>())
+            .is_ok());
+    }
+
+    #[test]
+    fn malformed_messages_left_to_decoder() {
+        assert!(Limits::default().check(&[0x30, 0x05, 0x05]).is_ok());
+        assert!(Null::from_bytes(&[0x05, 0x01]).is_err());
+    }
+}
@@ -1,7 +1,9 @@
 //! The [`Message`] pattern provided by this crate simplifies writing ASN.1 DER
 //! decoders and encoders which map ASN.1 `SEQUENCE` types to Rust structs.
 
-use crate::{asn1::sequence, Decodable, Encodable, Encoder, Length, Result, Tag, Tagged};
+use crate::{
+    asn1::sequence, Decodable, Encodable, Encoder, Length, Result, ReverseEncoder, Tag, Tagged,
+};
 
 /// Messages encoded as an ASN.1 `SEQUENCE`.
 ///
@@ -34,6 +36,10 @@ where
     fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
         self.fields(|fields| encoder.sequence(fields))
     }
+
+    fn encode_reverse(&self, encoder: &mut ReverseEncoder<'_>) -> Result<()> {
+        self.fields(|fields| encoder.sequence(fields))
+    }
 }
 
 impl<'a, M> Tagged for M
@@ -0,0 +1,98 @@
+//! PEM encoding support (RFC 7468).
+
+#[cfg(feature = "pem")]
+use {
+    crate::{ErrorKind, Result},
+    alloc::{borrow::ToOwned, string::String, vec::Vec},
+    base64ct::{Base64, Encoding},
+    core::str,
+};
+
+/// Types with an associated PEM type label, i.e. the `LABEL` in the
+/// `-----BEGIN LABEL-----` encapsulation boundary (see [RFC 7468]).
+///
+/// [RFC 7468]: https://tools.ietf.org/html/rfc7468
+pub trait PemLabel {
+    /// PEM type label, e.g. `CERTIFICATE`.
+    const TYPE_LABEL: &'static str;
+}
+
+/// Size of Base64 "chunks" i.e. how many Base64 encoded characters to include
+/// on a single line.
+#[cfg(feature = "pem")]
+const CHUNK_SIZE: usize = 64;
+
+/// Parse "PEM encoding" as described in RFC 7468, checking that the type
+/// label of the encapsulation boundaries matches `label`.
+///
+/// Like the [RFC 7468] "strict" textual encoding, explanatory text and
+/// headers are not supported.
+///
+/// [RFC 7468]: https://tools.ietf.org/html/rfc7468
+#[cfg(feature = "pem")]
+pub(crate) fn decode(s: &str, label: &str) -> Result<Vec<u8>> {
+    let s = s.trim();
+
+    let s = s
+        .strip_prefix("-----BEGIN ")
+        .and_then(|s| s.strip_prefix(label))
+        .and_then(|s| s.strip_prefix("-----"))
+        .ok_or(ErrorKind::Pem)?;
+
+    let s = s
+        .strip_suffix("-----")
+        .and_then(|s| s.strip_suffix(label))
+        .and_then(|s| s.strip_suffix("-----END "))
+        .ok_or(ErrorKind::Pem)?;
+
+    let mut s = s.to_owned();
+    s.retain(|c| !c.is_whitespace());
+    Base64::decode_vec(&s).map_err(|_| ErrorKind::Pem.into())
+}
+
+/// Serialize "PEM encoding" as described in RFC 7468 using the given type
+/// label.
+#[cfg(feature = "pem")]
+pub(crate) fn encode(data: &[u8], label: &str) -> String {
+    let mut output = String::new();
+    output.push_str("-----BEGIN ");
+    output.push_str(label);
+    output.push_str("-----\n");
+
+    let b64 = Base64::encode_string(data);
+
+    for chunk in b64.as_bytes().chunks(CHUNK_SIZE) {
+        let line = str::from_utf8(chunk).expect("malformed Base64");
+        output.push_str(line);
+        output.push('\n');
+    }
+
+    output.push_str("-----END ");
+    output.push_str(label);
+    output.push_str("-----\n");
+    output
+}
+
+#[cfg(all(test, feature = "pem"))]
+mod tests {
+    use crate::ErrorKind;
+
+    #[test]
+    fn round_trip() {
+        let data = [0x42u8; 100];
+        let pem = super::encode(&data, "EXAMPLE");
+        assert!(pem.starts_with("-----BEGIN EXAMPLE-----\n"));
+        assert!(pem.ends_with("\n-----END EXAMPLE-----\n"));
+        assert!(pem.lines().all(|line| line.len() <= 64));
+        assert_eq!(super::decode(&pem, "EXAMPLE").unwrap(), data);
+    }
+
+    #[test]
+    fn label_mismatch() {
+        let pem = super::encode(&[1, 2, 3], "EXAMPLE");
+        assert_eq!(
+            super::decode(&pem, "OTHER").unwrap_err().kind(),
+            ErrorKind::Pem
+        );
+    }
+}
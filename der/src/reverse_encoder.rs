@@ -0,0 +1,184 @@
+//! DER encoder which writes messages back-to-front.
+
+use crate::{Encodable, Encoder, ErrorKind, Header, Length, Result, Tag};
+use core::convert::TryInto;
+
+/// DER encoder which writes messages from the end of its buffer toward the
+/// front.
+///
+/// Since the contents of a value are written before its header, the lengths
+/// of constructed values are known once their contents have been encoded.
+/// This avoids [`Encoder::sequence`]'s need to compute the length of every
+/// field of a message in advance, which happens again at every level of
+/// nesting and makes encoding deeply nested messages quadratic.
+///
+/// Values are encoded using [`Encodable::encode_reverse`], which by default
+/// computes the length of a value and encodes it using an [`Encoder`].
+/// [`Message`][`crate::Message`] types override it to encode their fields in
+/// reverse order followed by the `SEQUENCE` header.
+#[derive(Debug)]
+pub struct ReverseEncoder<'a> {
+    /// Buffer into which DER-encoded message is written
+    bytes: Option<&'a mut [u8]>,
+
+    /// Total number of bytes written to the end of the buffer so far
+    position: Length,
+}
+
+impl<'a> ReverseEncoder<'a> {
+    /// Create a new encoder with the given byte slice as a backing buffer.
+    pub fn new(bytes: &'a mut [u8]) -> Self {
+        Self {
+            bytes: Some(bytes),
+            position: Length::zero(),
+        }
+    }
+
+    /// Encode a value which impls the [`Encodable`] trait, prepending it to
+    /// the previously encoded values.
+    pub fn encode<T: Encodable + ?Sized>(&mut self, encodable: &T) -> Result<()> {
+        if self.is_failed() {
+            self.error(ErrorKind::Failed)?;
+        }
+
+        encodable.encode_reverse(self).map_err(|e| {
+            self.bytes.take();
+            e.nested(self.position)
+        })
+    }
+
+    /// Return an error with the given [`ErrorKind`], annotating it with
+    /// context about where the error occurred.
+    ///
+    /// Positions are counted from the end of the message.
+    pub fn error<T>(&mut self, kind: ErrorKind) -> Result<T> {
+        self.bytes.take();
+        Err(kind.at(self.position))
+    }
+
+    /// Did the encoding operation fail due to an error?
+    pub fn is_failed(&self) -> bool {
+        self.bytes.is_none()
+    }
+
+    /// Get the number of bytes written so far.
+    pub fn position(&self) -> Length {
+        self.position
+    }
+
+    /// Finish encoding to the buffer, returning a slice containing the data
+    /// written to the end of the buffer.
+    pub fn finish(self) -> Result<&'a [u8]> {
+        let position = self.position;
+
+        match self.bytes {
+            Some(bytes) => {
+                let start = bytes
+                    .len()
+                    .checked_sub(position.into())
+                    .ok_or_else(|| ErrorKind::Truncated.at(position))?;
+
+                Ok(&bytes[start..])
+            }
+            None => Err(ErrorKind::Failed.at(position)),
+        }
+    }
+
+    /// Encode a sequence of values which impl the [`Encodable`] trait.
+    ///
+    /// Values are encoded in reverse order, followed by a `SEQUENCE` header
+    /// containing the length of the encoded values.
+    pub fn sequence(&mut self, encodables: &[&dyn Encodable]) -> Result<()> {
+        let start = self.position;
+
+        for encodable in encodables.iter().rev() {
+            self.encode(*encodable)?;
+        }
+
+        let length = self.position.to_usize() - start.to_usize();
+        self.header(Header::new(Tag::Sequence, length)?)
+    }
+
+    /// Encode a [`Header`] in front of the previously encoded values.
+    pub fn header(&mut self, header: Header) -> Result<()> {
+        let len = header.encoded_len()?;
+        let mut encoder = Encoder::new(self.reserve(len)?);
+        header.encode(&mut encoder)?;
+        encoder.finish()?;
+        Ok(())
+    }
+
+    /// Reserve a portion of the internal buffer in front of the previously
+    /// encoded values, updating the internal cursor position and returning a
+    /// mutable slice.
+    pub fn reserve(&mut self, len: impl TryInto<Length>) -> Result<&mut [u8]> {
+        let len = len
+            .try_into()
+            .or_else(|_| self.error(ErrorKind::Overflow))?;
+
+        let position = (self.position + len).or_else(|e| self.error(e.kind()))?;
+
+        let buffer_len = match self.bytes.as_ref() {
+            Some(bytes) => bytes.len(),
+            None => return self.error(ErrorKind::Failed),
+        };
+
+        if position.to_usize() > buffer_len {
+            self.error(ErrorKind::Overlength)?;
+        }
+
+        let range = (buffer_len - position.to_usize())..(buffer_len - self.position.to_usize());
+        self.position = position;
+
+        // We ensure above that the buffer is untainted and there is sufficient
+        // space to perform this slicing operation.
+        let slice = &mut self.bytes.as_mut().expect("DER encoder tainted")[range];
+        Ok(slice)
+    }
+
+    /// Encode the provided byte slice in front of the previously encoded
+    /// values.
+    pub fn bytes(&mut self, slice: &[u8]) -> Result<()> {
+        self.reserve(slice.len())?.copy_from_slice(slice);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReverseEncoder;
+    use crate::{Encodable, ErrorKind, Length, OctetString};
+    use hex_literal::hex;
+
+    #[test]
+    fn sequence() {
+        let mut buffer = [0u8; 16];
+        let mut encoder = ReverseEncoder::new(&mut buffer);
+        let octets = OctetString::new(&[1, 2, 3]).unwrap();
+        encoder.sequence(&[&true, &octets, &5u8]).unwrap();
+        assert_eq!(
+            encoder.finish().unwrap(),
+            hex!("30 0b 01 01 ff 04 03 01 02 03 02 01 05")
+        );
+        assert_eq!(buffer[..3], [0, 0, 0]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn encode_to_vec_appends() {
+        let mut buffer = alloc::vec![0xAA];
+        assert_eq!(true.encode_to_vec(&mut buffer).unwrap(), Length::from(3u8));
+        assert_eq!(buffer, hex!("AA 01 01 FF"));
+    }
+
+    #[test]
+    fn overlength_message() {
+        let mut buffer = [0u8; 2];
+        let mut encoder = ReverseEncoder::new(&mut buffer);
+        let err = encoder.encode(&false).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Overlength);
+        assert_eq!(err.position(), Some(Length::zero()));
+        assert!(encoder.is_failed());
+        assert!(false.encode_to_slice(&mut buffer).is_err());
+    }
+}
@@ -0,0 +1,135 @@
+//! Serde support: serialize and deserialize types which impl
+//! [`serde::Serialize`] and [`serde::Deserialize`] as ASN.1 DER.
+//!
+//! # Data model
+//!
+//! Serde's data model is mapped onto ASN.1 as follows:
+//!
+//! | serde type                          | ASN.1 type                                      |
+//! |-------------------------------------|-------------------------------------------------|
+//! | `bool`                              | `BOOLEAN`                                       |
+//! | integers (up to 128-bit)            | `INTEGER`                                       |
+//! | `char`, `str`, `String`             | `UTF8String`                                    |
+//! | bytes (e.g. `serde_bytes`)          | `OCTET STRING`                                  |
+//! | `Option`                            | `OPTIONAL` (`None` is omitted)                  |
+//! | unit, unit struct                   | `NULL`                                          |
+//! | newtype struct                      | same as the inner value                         |
+//! | struct, tuple, tuple struct         | `SEQUENCE`                                      |
+//! | sequence (e.g. `Vec`)               | `SEQUENCE OF`                                   |
+//! | map                                 | `SEQUENCE OF SEQUENCE { key, value }`           |
+//! | enum                                | `CHOICE` with `[n] EXPLICIT` context tags       |
+//!
+//! Enum variants are tagged using their variant index as the context-specific
+//! tag number. The variant's value (`NULL` for unit variants, a `SEQUENCE` for
+//! tuple and struct variants) is explicitly tagged.
+//!
+//! Only the context-specific tags `[0]` to `[3]` are supported, so
+//! serializing a variant with a higher index produces an
+//! [`Error::Unsupported`] error.
+//!
+//! Floating point numbers have no DER representation in this crate and
+//! produce an [`Error::Unsupported`] error.
+//!
+//! ## `OPTIONAL` fields
+//!
+//! Since absent `OPTIONAL` fields are omitted from the encoding, the
+//! deserializer decides whether an `Option` field is present by checking
+//! whether the next element's tag matches the type of the field. This is
+//! done for fields of structs and tuples as well as the elements of
+//! sequences.
+//!
+//! As in ASN.1 itself, consecutive `OPTIONAL` fields must have distinct tags
+//! to be decoded unambiguously.
+//!
+//! Elements of a `SEQUENCE OF` can't be omitted, so serializing a `None`
+//! element (e.g. of a `Vec<Option<T>>`) produces an [`Error::Unsupported`]
+//! error.
+
+mod de;
+mod ser;
+
+pub use self::{
+    de::{from_bytes, Deserializer},
+    ser::{to_vec, Serializer},
+};
+
+use crate::Tag;
+use alloc::string::{String, ToString};
+use core::fmt;
+
+/// Result type for serde (de)serialization.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Serde (de)serialization errors.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// DER encoding or decoding error.
+    Der(crate::Error),
+
+    /// Error message reported by a `Serialize` or `Deserialize` impl.
+    Message(String),
+
+    /// Type which can't be represented in this crate's DER data model.
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Der(err) => write!(f, "{}", err),
+            Error::Message(msg) => f.write_str(msg),
+            Error::Unsupported(ty) => write!(f, "{} is unsupported in DER", ty),
+        }
+    }
+}
+
+impl From<crate::Error> for Error {
+    fn from(err: crate::Error) -> Error {
+        Error::Der(err)
+    }
+}
+
+impl From<crate::ErrorKind> for Error {
+    fn from(kind: crate::ErrorKind) -> Error {
+        Error::Der(kind.into())
+    }
+}
+
+impl ::serde::ser::StdError for Error {}
+
+impl ::serde::ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Error {
+        Error::Message(msg.to_string())
+    }
+}
+
+impl ::serde::de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Error {
+        Error::Message(msg.to_string())
+    }
+}
+
+/// Get the context-specific [`Tag`] used for the given enum variant index.
+fn variant_tag(index: u32) -> Result<Tag> {
+    match index {
+        0 => Ok(Tag::ContextSpecific0),
+        1 => Ok(Tag::ContextSpecific1),
+        2 => Ok(Tag::ContextSpecific2),
+        3 => Ok(Tag::ContextSpecific3),
+        _ => Err(Error::Unsupported(
+            "enum variant with an index above 3 (no context-specific tag)",
+        )),
+    }
+}
+
+/// Get the enum variant index for the given context-specific [`Tag`].
+fn variant_index(tag: Tag) -> Option<u32> {
+    match tag {
+        Tag::ContextSpecific0 => Some(0),
+        Tag::ContextSpecific1 => Some(1),
+        Tag::ContextSpecific2 => Some(2),
+        Tag::ContextSpecific3 => Some(3),
+        _ => None,
+    }
+}
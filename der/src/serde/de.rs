@@ -0,0 +1,577 @@
+//! Serde deserializer for ASN.1 DER.
+
+use super::{variant_index, Error, Result};
+use crate::{Any, Decoder, ErrorKind, Null, Tag};
+use core::convert::TryFrom;
+use serde::de::{self, Deserialize, DeserializeSeed, IntoDeserializer, Visitor};
+
+/// Deserialize an instance of `T` from the given ASN.1 DER.
+pub fn from_bytes<'de, T>(bytes: &'de [u8]) -> Result<T>
+where
+    T: Deserialize<'de>,
+{
+    let mut deserializer = Deserializer::new(bytes);
+    let value = T::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(value)
+}
+
+/// Serde deserializer for ASN.1 DER.
+///
+/// See the [module-level documentation][`crate::serde`] for how serde's data
+/// model is mapped to ASN.1 types.
+#[derive(Debug)]
+pub struct Deserializer<'de> {
+    /// Decoder for the input being deserialized
+    decoder: Decoder<'de>,
+
+    /// Handling of the `OPTIONAL` value (if any) currently being deserialized
+    optional: Optional,
+}
+
+/// State used to decide whether an `OPTIONAL` value is present.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Optional {
+    /// Not deserializing an `OPTIONAL` value
+    None,
+
+    /// An `OPTIONAL` value is present if the next tag matches its type
+    Probe,
+
+    /// The next tag did not match the type of the `OPTIONAL` value
+    Mismatch,
+
+    /// Deserialize the next `OPTIONAL` value as absent
+    Absent,
+}
+
+impl<'de> Deserializer<'de> {
+    /// Create a new deserializer for the given ASN.1 DER.
+    pub fn new(bytes: &'de [u8]) -> Self {
+        Self::from_decoder(Decoder::new(bytes))
+    }
+
+    /// Create a new deserializer which decodes using the given [`Decoder`].
+    ///
+    /// This can be used to deserialize with custom [`Limits`][`crate::Limits`].
+    pub fn from_decoder(decoder: Decoder<'de>) -> Self {
+        Self {
+            decoder,
+            optional: Optional::None,
+        }
+    }
+
+    /// Create a deserializer for the contents of a constructed value.
+    fn nested(any: Any<'de>) -> Self {
+        Self::from_decoder(any.decoder())
+    }
+
+    /// Finish deserializing, returning an error if there is trailing data.
+    pub fn end(self) -> Result<()> {
+        Ok(self.decoder.finish(())?)
+    }
+
+    /// Decode the next TLV-encoded value, which must have a tag accepted by
+    /// the provided predicate.
+    fn next_any(&mut self, accept: impl Fn(Tag) -> bool) -> Result<Any<'de>> {
+        if self.optional == Optional::Probe {
+            self.optional = Optional::None;
+
+            // Check the tag without consuming anything so the value can be
+            // retried as absent
+            let err = match self.decoder.peek().map(Tag::try_from) {
+                Some(Ok(tag)) if accept(tag) => None,
+                Some(Ok(actual)) => Some(ErrorKind::UnexpectedTag {
+                    expected: None,
+                    actual,
+                }),
+                Some(Err(err)) => Some(err.kind()),
+                None => Some(ErrorKind::Truncated),
+            };
+
+            if let Some(kind) = err {
+                self.optional = Optional::Mismatch;
+                return Err(kind.into());
+            }
+        }
+
+        let any = self.decoder.any()?;
+
+        if accept(any.tag()) {
+            Ok(any)
+        } else {
+            Err(ErrorKind::UnexpectedTag {
+                expected: None,
+                actual: any.tag(),
+            }
+            .into())
+        }
+    }
+
+    /// Decode the next value, which must have the given tag.
+    fn next_tagged(&mut self, tag: Tag) -> Result<Any<'de>> {
+        self.next_any(|actual| actual == tag)
+    }
+
+    /// Decode the next value as an `INTEGER`.
+    fn next_integer(&mut self) -> Result<i128> {
+        let any = self.next_tagged(Tag::Integer)?;
+        decode_integer(any.as_bytes())
+    }
+
+    /// Decode the next value as a string type.
+    fn next_str(&mut self) -> Result<&'de str> {
+        let any = self.next_any(|tag| {
+            matches!(tag, Tag::Utf8String | Tag::PrintableString | Tag::Ia5String)
+        })?;
+
+        Ok(core::str::from_utf8(any.as_bytes()).map_err(crate::Error::from)?)
+    }
+
+    /// Deserialize a value using the provided function, retrying with an
+    /// absent `OPTIONAL` value if the next element doesn't match its type.
+    fn optional<T>(&mut self, f: impl Fn(&mut Self) -> Result<T>) -> Result<T> {
+        match f(self) {
+            Err(_) if self.optional == Optional::Mismatch => {
+                self.optional = Optional::Absent;
+                f(self)
+            }
+            result => result,
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for &mut Deserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let any = self.next_any(|_| true)?;
+
+        match any.tag() {
+            Tag::Boolean => visitor.visit_bool(bool::try_from(any)?),
+            Tag::Integer => {
+                let value = decode_integer(any.as_bytes())?;
+
+                if let Ok(value) = i64::try_from(value) {
+                    visitor.visit_i64(value)
+                } else if let Ok(value) = u64::try_from(value) {
+                    visitor.visit_u64(value)
+                } else {
+                    visitor.visit_i128(value)
+                }
+            }
+            Tag::BitString => visitor.visit_borrowed_bytes(bit_string_bytes(any)?),
+            Tag::Null => {
+                Null::try_from(any)?;
+                visitor.visit_unit()
+            }
+            Tag::Sequence => SeqAccess::new(any, None).visit(visitor),
+            tag if variant_index(tag).is_some() => visitor.visit_enum(EnumAccess { any }),
+            Tag::Utf8String | Tag::PrintableString | Tag::Ia5String => visitor.visit_borrowed_str(
+                core::str::from_utf8(any.as_bytes()).map_err(crate::Error::from)?,
+            ),
+            _ => visitor.visit_borrowed_bytes(any.as_bytes()),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let any = self.next_tagged(Tag::Boolean)?;
+        visitor.visit_bool(bool::try_from(any)?)
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i8(integer_into(self.next_integer()?)?)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i16(integer_into(self.next_integer()?)?)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i32(integer_into(self.next_integer()?)?)
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i64(integer_into(self.next_integer()?)?)
+    }
+
+    fn deserialize_i128<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i128(self.next_integer()?)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u8(integer_into(self.next_integer()?)?)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u16(integer_into(self.next_integer()?)?)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u32(integer_into(self.next_integer()?)?)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u64(integer_into(self.next_integer()?)?)
+    }
+
+    fn deserialize_u128<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let any = self.next_tagged(Tag::Integer)?;
+
+        // Values above `i128::MAX` are encoded with a leading zero byte
+        match any.as_bytes() {
+            [0, rest @ ..] if rest.len() == 16 && rest[0] >= 0x80 => {
+                let mut bytes = [0u8; 16];
+                bytes.copy_from_slice(rest);
+                visitor.visit_u128(u128::from_be_bytes(bytes))
+            }
+            bytes => visitor.visit_u128(integer_into(decode_integer(bytes)?)?),
+        }
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(Error::Unsupported("floating point"))
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(Error::Unsupported("floating point"))
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let s = self.next_str()?;
+        let mut chars = s.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => Err(ErrorKind::Length {
+                tag: Tag::Utf8String,
+            }
+            .into()),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_borrowed_str(self.next_str()?)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let any = self.next_any(|tag| matches!(tag, Tag::OctetString | Tag::BitString))?;
+
+        if any.tag() == Tag::BitString {
+            visitor.visit_borrowed_bytes(bit_string_bytes(any)?)
+        } else {
+            visitor.visit_borrowed_bytes(any.as_bytes())
+        }
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.optional == Optional::Absent || self.decoder.is_finished() {
+            self.optional = Optional::None;
+            visitor.visit_none()
+        } else {
+            self.optional = Optional::Probe;
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        Null::try_from(self.next_tagged(Tag::Null)?)?;
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let any = self.next_tagged(Tag::Sequence)?;
+        SeqAccess::new(any, None).visit(visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        let any = self.next_tagged(Tag::Sequence)?;
+        SeqAccess::new(any, Some(len)).visit(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let any = self.next_tagged(Tag::Sequence)?;
+        let mut map = MapAccess {
+            deserializer: Deserializer::nested(any),
+            entry: None,
+        };
+
+        let value = visitor.visit_map(&mut map)?;
+        map.deserializer.end()?;
+        Ok(value)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_tuple(fields.len(), visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        let any = self.next_any(|tag| variant_index(tag).is_some())?;
+        visitor.visit_enum(EnumAccess { any })
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.next_any(|_| true)?;
+        visitor.visit_unit()
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+/// Access to the elements of a `SEQUENCE`.
+struct SeqAccess<'de> {
+    /// Deserializer for the contents of the `SEQUENCE`
+    deserializer: Deserializer<'de>,
+
+    /// Number of remaining elements for fixed-length `SEQUENCE` types
+    /// (i.e. structs and tuples), or `None` for `SEQUENCE OF`.
+    remaining: Option<usize>,
+}
+
+impl<'de> SeqAccess<'de> {
+    /// Create a new [`SeqAccess`] for the given `SEQUENCE`.
+    fn new(any: Any<'de>, len: Option<usize>) -> Self {
+        Self {
+            deserializer: Deserializer::nested(any),
+            remaining: len,
+        }
+    }
+
+    /// Visit the elements of the `SEQUENCE`, ensuring they're all consumed.
+    fn visit<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value> {
+        let value = visitor.visit_seq(&mut self)?;
+        self.deserializer.end()?;
+        Ok(value)
+    }
+
+    /// Is there another element to be deserialized?
+    fn has_next(&mut self) -> bool {
+        match &mut self.remaining {
+            Some(0) => false,
+            Some(remaining) => {
+                *remaining -= 1;
+                true
+            }
+            None => !self.deserializer.decoder.is_finished(),
+        }
+    }
+}
+
+impl<'de> de::SeqAccess<'de> for SeqAccess<'de> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        if self.has_next() {
+            seed.deserialize(&mut self.deserializer).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn next_element<T>(&mut self) -> Result<Option<T>>
+    where
+        T: Deserialize<'de>,
+    {
+        if self.has_next() {
+            self.deserializer
+                .optional(|deserializer| T::deserialize(deserializer))
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.remaining
+    }
+}
+
+/// Access to the entries of a map, encoded as `SEQUENCE OF SEQUENCE`.
+struct MapAccess<'de> {
+    /// Deserializer for the contents of the outer `SEQUENCE`
+    deserializer: Deserializer<'de>,
+
+    /// Deserializer for the current entry, after its key has been consumed
+    entry: Option<Deserializer<'de>>,
+}
+
+impl<'de> de::MapAccess<'de> for MapAccess<'de> {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        if self.deserializer.decoder.is_finished() {
+            return Ok(None);
+        }
+
+        let any = self.deserializer.next_tagged(Tag::Sequence)?;
+        let mut entry = Deserializer::nested(any);
+        let key = seed.deserialize(&mut entry)?;
+        self.entry = Some(entry);
+        Ok(Some(key))
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let mut entry = self
+            .entry
+            .take()
+            .ok_or_else(|| <Error as de::Error>::custom("map value without key"))?;
+
+        let value = seed.deserialize(&mut entry)?;
+        entry.end()?;
+        Ok(value)
+    }
+}
+
+/// Access to an enum variant encoded as an explicitly tagged `CHOICE`.
+struct EnumAccess<'de> {
+    /// Context-specific value containing the variant
+    any: Any<'de>,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumAccess<'de> {
+    type Error = Error;
+    type Variant = Deserializer<'de>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Deserializer<'de>)>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let index = variant_index(self.any.tag()).ok_or(ErrorKind::UnexpectedTag {
+            expected: None,
+            actual: self.any.tag(),
+        })?;
+
+        let index: de::value::U32Deserializer<Error> = index.into_deserializer();
+        let variant = seed.deserialize(index)?;
+        Ok((variant, Deserializer::nested(self.any)))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for Deserializer<'de> {
+    type Error = Error;
+
+    fn unit_variant(mut self) -> Result<()> {
+        <()>::deserialize(&mut self)?;
+        self.end()
+    }
+
+    fn newtype_variant_seed<T>(mut self, seed: T) -> Result<T::Value>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let value = seed.deserialize(&mut self)?;
+        self.end()?;
+        Ok(value)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(mut self, len: usize, visitor: V) -> Result<V::Value> {
+        let value = de::Deserializer::deserialize_tuple(&mut self, len, visitor)?;
+        self.end()?;
+        Ok(value)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        mut self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        let value = de::Deserializer::deserialize_tuple(&mut self, fields.len(), visitor)?;
+        self.end()?;
+        Ok(value)
+    }
+}
+
+/// Get the contents of an ASN.1 `BIT STRING` as bytes, which can't represent
+/// unused bits.
+fn bit_string_bytes(any: Any<'_>) -> Result<&[u8]> {
+    let bits = any.bit_string()?;
+
+    if bits.unused_bits() != 0 {
+        return Err(ErrorKind::Value {
+            tag: Tag::BitString,
+        }
+        .into());
+    }
+
+    Ok(bits.as_bytes())
+}
+
+/// Decode the contents of an ASN.1 `INTEGER` as an `i128`.
+fn decode_integer(bytes: &[u8]) -> Result<i128> {
+    match bytes {
+        [] => return Err(ErrorKind::Length { tag: Tag::Integer }.into()),
+        [0, next, ..] if *next < 0x80 => return Err(ErrorKind::Noncanonical.into()),
+        [0xFF, next, ..] if *next >= 0x80 => return Err(ErrorKind::Noncanonical.into()),
+        _ if bytes.len() > 16 => return Err(ErrorKind::Value { tag: Tag::Integer }.into()),
+        _ => (),
+    }
+
+    // Sign-extend into a 16-byte buffer
+    let fill = if bytes[0] >= 0x80 { 0xFF } else { 0x00 };
+    let mut buf = [fill; 16];
+    buf[(16 - bytes.len())..].copy_from_slice(bytes);
+    Ok(i128::from_be_bytes(buf))
+}
+
+/// Convert a decoded `INTEGER` into a smaller integer type.
+fn integer_into<T: TryFrom<i128>>(value: i128) -> Result<T> {
+    T::try_from(value).map_err(|_| ErrorKind::Value { tag: Tag::Integer }.into())
+}
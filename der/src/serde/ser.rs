@@ -0,0 +1,441 @@
+//! Serde serializer which produces ASN.1 DER.
+
+use super::{variant_tag, Error, Result};
+use crate::{Any, Encodable, Encoder, Null, OctetString, Tag, Utf8String};
+use alloc::vec::Vec;
+use serde::ser::{self, Serialize};
+
+/// Serialize the given value as ASN.1 DER.
+pub fn to_vec<T>(value: &T) -> Result<Vec<u8>>
+where
+    T: Serialize + ?Sized,
+{
+    let mut serializer = Serializer::new();
+    value.serialize(&mut serializer)?;
+    Ok(serializer.into_vec())
+}
+
+/// Serde serializer which produces ASN.1 DER.
+///
+/// See the [module-level documentation][`crate::serde`] for how serde's data
+/// model is mapped to ASN.1 types.
+#[derive(Clone, Debug, Default)]
+pub struct Serializer {
+    /// DER-encoded output
+    output: Vec<u8>,
+}
+
+impl Serializer {
+    /// Create a new serializer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the DER serialized so far.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.output
+    }
+
+    /// Finish serializing, returning the DER-encoded output.
+    pub fn into_vec(self) -> Vec<u8> {
+        self.output
+    }
+
+    /// Write a DER-encoded value.
+    fn encode(&mut self, value: &impl Encodable) -> Result<()> {
+        let start = self.output.len();
+        let len = value.encoded_len()?.to_usize();
+        self.output.resize(start + len, 0);
+
+        let mut encoder = Encoder::new(&mut self.output[start..]);
+        encoder.encode(value)?;
+        encoder.finish()?;
+        Ok(())
+    }
+
+    /// Write a TLV-encoded value with the given tag and contents.
+    fn tlv(&mut self, tag: Tag, value: &[u8]) -> Result<()> {
+        self.encode(&Any::new(tag, value)?)
+    }
+
+    /// Write an ASN.1 `INTEGER` from big endian two's complement bytes,
+    /// removing redundant leading sign bytes.
+    fn integer(&mut self, mut bytes: &[u8]) -> Result<()> {
+        while let [hi, lo, ..] = *bytes {
+            if (hi == 0 && lo < 0x80) || (hi == 0xFF && lo >= 0x80) {
+                bytes = &bytes[1..];
+            } else {
+                break;
+            }
+        }
+
+        self.tlv(Tag::Integer, bytes)
+    }
+
+    /// Write an enum variant, explicitly tagging the serialized value with
+    /// the context-specific tag for the given variant index.
+    fn variant<T>(&mut self, variant_index: u32, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        let tag = variant_tag(variant_index)?;
+        let mut inner = Serializer::new();
+        value.serialize(&mut inner)?;
+        self.tlv(tag, inner.as_bytes())
+    }
+
+    /// Begin serializing a compound value.
+    fn compound(&mut self, variant_index: Option<u32>) -> Result<Compound<'_>> {
+        let variant_tag = variant_index.map(variant_tag).transpose()?;
+
+        Ok(Compound {
+            parent: self,
+            contents: Serializer::new(),
+            variant_tag,
+            key: None,
+        })
+    }
+}
+
+impl<'a> ser::Serializer for &'a mut Serializer {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.encode(&v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        self.serialize_i128(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<()> {
+        self.serialize_i128(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<()> {
+        self.serialize_i128(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<()> {
+        self.serialize_i128(v.into())
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<()> {
+        self.integer(&v.to_be_bytes())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        self.serialize_u128(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        self.serialize_u128(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<()> {
+        self.serialize_u128(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<()> {
+        self.serialize_u128(v.into())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<()> {
+        // Leading zero byte keeps the value positive
+        let mut bytes = [0u8; 17];
+        bytes[1..].copy_from_slice(&v.to_be_bytes());
+        self.integer(&bytes)
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<()> {
+        Err(Error::Unsupported("floating point"))
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<()> {
+        Err(Error::Unsupported("floating point"))
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.serialize_str(v.encode_utf8(&mut [0u8; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.encode(&Utf8String::new(v)?)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.encode(&OctetString::new(v)?)
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        self.encode(&Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<()> {
+        self.variant(variant_index, &())
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.variant(variant_index, value)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Compound<'a>> {
+        self.compound(None)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Compound<'a>> {
+        self.compound(None)
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Compound<'a>> {
+        self.compound(None)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>> {
+        self.compound(Some(variant_index))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Compound<'a>> {
+        self.compound(None)
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Compound<'a>> {
+        self.compound(None)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>> {
+        self.compound(Some(variant_index))
+    }
+}
+
+/// Serializer for compound values, which are encoded as an ASN.1 `SEQUENCE`.
+#[derive(Debug)]
+pub struct Compound<'a> {
+    /// Serializer the finished `SEQUENCE` is written to
+    parent: &'a mut Serializer,
+
+    /// Serializer for the contents of the `SEQUENCE`
+    contents: Serializer,
+
+    /// Context-specific tag of the enum variant if this is a tuple or struct
+    /// variant
+    variant_tag: Option<Tag>,
+
+    /// Serialized key of a map entry which is awaiting its value
+    key: Option<Serializer>,
+}
+
+impl Compound<'_> {
+    /// Serialize an element of this compound value.
+    fn element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(&mut self.contents)
+    }
+
+    /// Finish serializing this compound value.
+    fn finish(self) -> Result<()> {
+        match self.variant_tag {
+            Some(tag) => {
+                let mut sequence = Serializer::new();
+                sequence.tlv(Tag::Sequence, self.contents.as_bytes())?;
+                self.parent.tlv(tag, sequence.as_bytes())
+            }
+            None => self.parent.tlv(Tag::Sequence, self.contents.as_bytes()),
+        }
+    }
+}
+
+impl ser::SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        let len = self.contents.output.len();
+        self.element(value)?;
+
+        // Every value other than `None` produces a TLV, and omitting an
+        // element of a `SEQUENCE OF` would shift the ones that follow it
+        if self.contents.output.len() == len {
+            return Err(Error::Unsupported("`None` element of a `SEQUENCE OF`"));
+        }
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        let mut entry = Serializer::new();
+        key.serialize(&mut entry)?;
+        self.key = Some(entry);
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        let mut entry = self
+            .key
+            .take()
+            .ok_or_else(|| <Error as ser::Error>::custom("map value without key"))?;
+
+        value.serialize(&mut entry)?;
+        self.contents.tlv(Tag::Sequence, entry.as_bytes())
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, _key: &'static str, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, _key: &'static str, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
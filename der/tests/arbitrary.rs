@@ -0,0 +1,171 @@
+//! Round-trip property tests for values generated using `Arbitrary`
+
+#![cfg(feature = "arbitrary")]
+
+use core::fmt::Debug;
+use der::{
+    arbitrary::{Arbitrary, Unstructured},
+    Any, BitString, Decodable, Encodable, GeneralizedTime, Ia5String, Null, OctetString,
+    PrintableString, Sequence, UtcTime, Utf8String,
+};
+use proptest::prelude::*;
+
+/// Large enough for any value whose length fits in a `Length`.
+const BUF_SIZE: usize = 0x10010;
+
+/// Generate a value of type `T` from the given input, then check it
+/// survives an encode/decode round trip and re-encodes identically.
+fn round_trip<'a, T>(data: &'a [u8], buf: &'a mut [u8])
+where
+    T: Arbitrary<'a> + Decodable<'a> + Encodable + Debug + PartialEq,
+{
+    let mut u = Unstructured::new(data);
+    let value = match T::arbitrary(&mut u) {
+        Ok(value) => value,
+        Err(_) => return,
+    };
+
+    let encoded = value.encode_to_slice(buf).unwrap();
+    let decoded = T::from_bytes(encoded).unwrap();
+    assert_eq!(decoded, value);
+
+    let mut buf2 = vec![0u8; encoded.len()];
+    assert_eq!(decoded.encode_to_slice(&mut buf2).unwrap(), encoded);
+}
+
+proptest! {
+    #[test]
+    fn any_value(data in prop::collection::vec(any::<u8>(), 0..1024)) {
+        round_trip::<Any<'_>>(&data, &mut vec![0; BUF_SIZE]);
+    }
+
+    #[test]
+    fn bit_string(data in prop::collection::vec(any::<u8>(), 0..1024)) {
+        round_trip::<BitString<'_>>(&data, &mut vec![0; BUF_SIZE]);
+    }
+
+    #[test]
+    fn boolean(data in prop::collection::vec(any::<u8>(), 0..8)) {
+        round_trip::<bool>(&data, &mut vec![0; BUF_SIZE]);
+    }
+
+    #[test]
+    fn generalized_time(data in prop::collection::vec(any::<u8>(), 0..16)) {
+        round_trip::<GeneralizedTime>(&data, &mut vec![0; BUF_SIZE]);
+    }
+
+    #[test]
+    fn ia5_string(data in prop::collection::vec(any::<u8>(), 0..1024)) {
+        round_trip::<Ia5String<'_>>(&data, &mut vec![0; BUF_SIZE]);
+    }
+
+    #[test]
+    fn integers(data in prop::collection::vec(any::<u8>(), 0..8)) {
+        round_trip::<i8>(&data, &mut vec![0; BUF_SIZE]);
+        round_trip::<i16>(&data, &mut vec![0; BUF_SIZE]);
+        round_trip::<i32>(&data, &mut vec![0; BUF_SIZE]);
+        round_trip::<i64>(&data, &mut vec![0; BUF_SIZE]);
+        round_trip::<u8>(&data, &mut vec![0; BUF_SIZE]);
+        round_trip::<u16>(&data, &mut vec![0; BUF_SIZE]);
+    }
+
+    #[test]
+    fn null(data in prop::collection::vec(any::<u8>(), 0..8)) {
+        round_trip::<Null>(&data, &mut vec![0; BUF_SIZE]);
+    }
+
+    #[test]
+    fn octet_string(data in prop::collection::vec(any::<u8>(), 0..1024)) {
+        round_trip::<OctetString<'_>>(&data, &mut vec![0; BUF_SIZE]);
+    }
+
+    #[test]
+    fn printable_string(data in prop::collection::vec(any::<u8>(), 0..1024)) {
+        round_trip::<PrintableString<'_>>(&data, &mut vec![0; BUF_SIZE]);
+    }
+
+    #[test]
+    fn sequence(data in prop::collection::vec(any::<u8>(), 0..1024)) {
+        round_trip::<Sequence<'_>>(&data, &mut vec![0; BUF_SIZE]);
+    }
+
+    #[test]
+    fn utc_time(data in prop::collection::vec(any::<u8>(), 0..16)) {
+        round_trip::<UtcTime>(&data, &mut vec![0; BUF_SIZE]);
+    }
+
+    #[test]
+    fn utf8_string(data in prop::collection::vec(any::<u8>(), 0..1024)) {
+        round_trip::<Utf8String<'_>>(&data, &mut vec![0; BUF_SIZE]);
+    }
+}
+
+#[cfg(feature = "oid")]
+proptest! {
+    #[test]
+    fn oid(data in prop::collection::vec(any::<u8>(), 0..64)) {
+        round_trip::<der::ObjectIdentifier>(&data, &mut vec![0; BUF_SIZE]);
+    }
+}
+
+#[cfg(feature = "derive")]
+mod derived {
+    use super::{round_trip, BUF_SIZE};
+    use der::{Choice, Message, OctetString, UtcTime, Utf8String};
+    use proptest::prelude::*;
+
+    /// Example message with constrained fields.
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Message)]
+    pub struct Record<'a> {
+        #[asn1(type = "OCTET STRING", size = "1..=4")]
+        pub id: &'a [u8],
+
+        #[asn1(range = "0..=2")]
+        pub version: u8,
+
+        pub name: Option<Utf8String<'a>>,
+
+        #[asn1(type = "OCTET STRING", encapsulates)]
+        pub time: Time<'a>,
+    }
+
+    /// Example `CHOICE`.
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Choice)]
+    pub enum Time<'a> {
+        #[asn1(type = "UTCTime")]
+        Utc(UtcTime),
+
+        #[asn1(type = "OCTET STRING", size = "8")]
+        Octets(&'a [u8]),
+    }
+
+    impl From<UtcTime> for Time<'_> {
+        fn from(time: UtcTime) -> Self {
+            Time::Utc(time)
+        }
+    }
+
+    impl<'a> From<&'a [u8]> for Time<'a> {
+        fn from(octets: &'a [u8]) -> Self {
+            Time::Octets(octets)
+        }
+    }
+
+    impl<'a> From<OctetString<'a>> for Time<'a> {
+        fn from(octets: OctetString<'a>) -> Self {
+            Time::Octets(octets.as_bytes())
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn record(data in prop::collection::vec(any::<u8>(), 0..256)) {
+            round_trip::<Record<'_>>(&data, &mut vec![0; BUF_SIZE]);
+        }
+
+        #[test]
+        fn time(data in prop::collection::vec(any::<u8>(), 0..64)) {
+            round_trip::<Time<'_>>(&data, &mut vec![0; BUF_SIZE]);
+        }
+    }
+}
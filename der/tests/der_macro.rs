@@ -0,0 +1,61 @@
+//! Tests for the `der!` macro
+
+#![cfg(feature = "derive")]
+
+use der::der;
+use hex_literal::hex;
+
+#[test]
+fn algorithm_identifier() {
+    const ALGORITHM_ID: [u8; 21] = der!(SEQUENCE {
+        OID 1.2.840.10045.2.1,
+        OID 1.2.840.10045.3.1.7
+    });
+
+    assert_eq!(
+        ALGORITHM_ID,
+        hex!("30 13 06 07 2A 86 48 CE 3D 02 01 06 08 2A 86 48 CE 3D 03 01 07")
+    );
+}
+
+#[test]
+fn primitive_types() {
+    assert_eq!(der!(BOOLEAN true), hex!("01 01 FF"));
+    assert_eq!(der!(INTEGER 0), hex!("02 01 00"));
+    assert_eq!(der!(INTEGER 128), hex!("02 02 00 80"));
+    assert_eq!(der!(INTEGER - 129), hex!("02 02 FF 7F"));
+    assert_eq!(der!(BIT STRING [0x01, 0x02]), hex!("03 03 00 01 02"));
+    assert_eq!(der!(OCTET STRING b"ab"), hex!("04 02 61 62"));
+    assert_eq!(der!(NULL), hex!("05 00"));
+    assert_eq!(der!(OBJECT IDENTIFIER "2.999.3"), hex!("06 03 88 37 03"));
+    assert_eq!(der!(UTF8String "é"), hex!("0C 02 C3 A9"));
+    assert_eq!(der!(PrintableString "A1"), hex!("13 02 41 31"));
+    assert_eq!(der!(IA5String "a@b"), hex!("16 03 61 40 62"));
+}
+
+#[test]
+fn context_specific() {
+    assert_eq!(der!([0] INTEGER 1), hex!("A0 03 02 01 01"));
+    assert_eq!(der!([1] IMPLICIT INTEGER 1), hex!("81 01 01"));
+    assert_eq!(der!([2] IMPLICIT SEQUENCE { NULL }), hex!("A2 02 05 00"));
+}
+
+#[test]
+fn long_form_length() {
+    let der = der!(OCTET STRING [
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+        25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+        48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70,
+        71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93,
+        94, 95, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112,
+        113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125, 126, 127
+    ]);
+
+    assert_eq!(der[..3], hex!("04 81 80"));
+    assert_eq!(der.len(), 131);
+}
+
+#[test]
+fn compile_errors() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
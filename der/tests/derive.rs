@@ -3,7 +3,10 @@
 
 #![cfg(feature = "derive")]
 
-use der::{Choice, Decodable, Encodable, Encoder, GeneralizedTime, UtcTime};
+use der::{
+    Choice, Decodable, Encodable, Encoder, ErrorKind, GeneralizedTime, Message, OctetString, Tag,
+    UtcTime, Utf8String,
+};
 use hex_literal::hex;
 use std::time::Duration;
 
@@ -75,3 +78,419 @@ fn encode_enum_variants() {
     general_time.encode(&mut encoder).unwrap();
     assert_eq!(GENERAL_TIMESTAMP, encoder.finish().unwrap());
 }
+
+/// Custom derive test case for the `size` and `range` constraint attributes.
+///
+/// ```text
+/// Constrained ::= SEQUENCE {
+///      id          OCTET STRING (SIZE (4)),
+///      version     INTEGER (0..2),
+///      name        UTF8String (SIZE (1..8)) OPTIONAL }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Message)]
+pub struct Constrained<'a> {
+    #[asn1(type = "OCTET STRING", size = "4")]
+    pub id: &'a [u8],
+
+    #[asn1(range = "0..2")]
+    pub version: u8,
+
+    #[asn1(size = "1..8")]
+    pub name: Option<Utf8String<'a>>,
+}
+
+/// Custom derive test case for `size` constraints on `CHOICE` alternatives.
+#[derive(Choice, Debug)]
+pub enum ConstrainedChoice<'a> {
+    #[asn1(type = "UTF8String", size = "..=4")]
+    Text(Utf8String<'a>),
+
+    #[asn1(type = "UTCTime")]
+    Time(UtcTime),
+}
+
+impl<'a> From<Utf8String<'a>> for ConstrainedChoice<'a> {
+    fn from(text: Utf8String<'a>) -> Self {
+        ConstrainedChoice::Text(text)
+    }
+}
+
+impl From<UtcTime> for ConstrainedChoice<'_> {
+    fn from(time: UtcTime) -> Self {
+        ConstrainedChoice::Time(time)
+    }
+}
+
+#[test]
+fn constrained_message_round_trip() {
+    let bytes = hex!("30 0e 04 04 01 02 03 04 02 01 02 0c 03 61 62 63");
+    let msg = Constrained::from_bytes(&bytes).unwrap();
+    assert_eq!(msg.id, &[1, 2, 3, 4]);
+    assert_eq!(msg.version, 2);
+    assert_eq!(msg.name.unwrap().as_str(), "abc");
+
+    let mut buf = [0u8; 128];
+    assert_eq!(msg.encode_to_slice(&mut buf).unwrap(), &bytes);
+}
+
+#[test]
+fn constrained_message_decode_violations() {
+    // `id` is too short
+    let err = Constrained::from_bytes(&hex!("30 05 04 03 01 02 03 02 01 00")).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::Constraint {
+            tag: Tag::OctetString
+        }
+    );
+
+    // `version` is out of range
+    let err = Constrained::from_bytes(&hex!("30 09 04 04 01 02 03 04 02 01 03")).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Constraint { tag: Tag::Integer });
+
+    // `name` is empty
+    let err = Constrained::from_bytes(&hex!("30 0b 04 04 01 02 03 04 02 01 00 0c 00")).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::Constraint {
+            tag: Tag::Utf8String
+        }
+    );
+}
+
+#[test]
+fn constrained_message_bounds_are_inclusive() {
+    let mut buf = [0u8; 128];
+
+    let upper = Constrained {
+        id: &[1, 2, 3, 4],
+        version: 2,
+        name: Some(Utf8String::new("12345678").unwrap()),
+    };
+    let der = upper.encode_to_slice(&mut buf).unwrap();
+    assert_eq!(Constrained::from_bytes(der).unwrap(), upper);
+
+    let lower = Constrained {
+        version: 0,
+        name: Some(Utf8String::new("1").unwrap()),
+        ..upper
+    };
+    let der = lower.encode_to_slice(&mut buf).unwrap();
+    assert_eq!(Constrained::from_bytes(der).unwrap(), lower);
+
+    // One past the upper bound of `name`
+    let err = Constrained::from_bytes(&hex!(
+        "30 14 04 04 01 02 03 04 02 01 00 0c 09 31 32 33 34 35 36 37 38 39"
+    ))
+    .unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::Constraint {
+            tag: Tag::Utf8String
+        }
+    );
+}
+
+#[test]
+fn constrained_message_encode_violations() {
+    let mut buf = [0u8; 128];
+    let valid = Constrained {
+        id: &[1, 2, 3, 4],
+        version: 0,
+        name: None,
+    };
+    assert!(valid.encode_to_slice(&mut buf).is_ok());
+
+    let invalid_id = Constrained {
+        id: &[1, 2, 3, 4, 5],
+        ..valid
+    };
+    assert!(invalid_id.encode_to_slice(&mut buf).is_err());
+
+    let invalid_version = Constrained {
+        version: 3,
+        ..valid
+    };
+    assert_eq!(
+        invalid_version
+            .encode_to_slice(&mut buf)
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Constraint { tag: Tag::Integer }
+    );
+
+    let invalid_name = Constrained {
+        name: Some(Utf8String::new("too long!").unwrap()),
+        ..valid
+    };
+    assert!(invalid_name.encode_to_slice(&mut buf).is_err());
+}
+
+#[test]
+fn constrained_choice() {
+    let mut buf = [0u8; 128];
+
+    let text = ConstrainedChoice::from_bytes(&hex!("0c 04 c3 a9 c3 a9")).unwrap();
+    assert!(text.encode_to_slice(&mut buf).is_ok());
+
+    let err = ConstrainedChoice::from_bytes(&hex!("0c 05 61 62 63 64 65")).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::Constraint {
+            tag: Tag::Utf8String
+        }
+    );
+
+    let text = ConstrainedChoice::Text(Utf8String::new("abcde").unwrap());
+    assert!(text.encode_to_slice(&mut buf).is_err());
+
+    assert!(ConstrainedChoice::from_bytes(UTC_TIMESTAMP).is_ok());
+}
+
+/// Custom derive test case for the `extensible` attribute.
+///
+/// ```text
+/// Extensible ::= SEQUENCE {
+///      version     INTEGER,
+///      ... }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Message)]
+#[asn1(extensible)]
+pub struct Extensible {
+    pub version: u8,
+}
+
+/// Custom derive test case for capturing unknown elements of an extensible
+/// `SEQUENCE` using the `extensions` attribute.
+#[derive(Clone, Debug, Eq, PartialEq, Message)]
+#[asn1(extensible)]
+pub struct WithExtensions<'a> {
+    pub version: u8,
+
+    #[asn1(extensions)]
+    pub extensions: Vec<&'a [u8]>,
+}
+
+/// `SEQUENCE` containing a version followed by two unknown elements.
+const EXTENDED_SEQUENCE: &[u8] = &hex!("30 08 02 01 01 05 00 01 01 ff");
+
+#[test]
+fn extensible_skips_unknown_elements() {
+    assert!(Version::from_bytes(EXTENDED_SEQUENCE).is_err());
+
+    let msg = Extensible::from_bytes(EXTENDED_SEQUENCE).unwrap();
+    assert_eq!(msg.version, 1);
+
+    let mut buf = [0u8; 128];
+    assert_eq!(
+        msg.encode_to_slice(&mut buf).unwrap(),
+        &hex!("30 03 02 01 01")
+    );
+
+    // Unknown elements must still be well-formed
+    assert!(Extensible::from_bytes(&hex!("30 05 02 01 01 05 01")).is_err());
+}
+
+/// `SEQUENCE` containing a version followed by elements with tags which
+/// [`Tag`] doesn't support: `[0] IMPLICIT` primitive, `[4]` constructed and
+/// `SET`.
+const EXTENDED_SEQUENCE_UNKNOWN_TAGS: &[u8] =
+    &hex!("30 10 02 01 01 80 01 ff a4 03 02 01 07 31 03 02 01 08");
+
+#[test]
+fn extensible_skips_unknown_tags() {
+    let msg = Extensible::from_bytes(EXTENDED_SEQUENCE_UNKNOWN_TAGS).unwrap();
+    assert_eq!(msg.version, 1);
+
+    let msg = WithExtensions::from_bytes(EXTENDED_SEQUENCE_UNKNOWN_TAGS).unwrap();
+    assert_eq!(msg.version, 1);
+    assert_eq!(
+        msg.extensions,
+        [
+            &hex!("80 01 ff")[..],
+            &hex!("a4 03 02 01 07")[..],
+            &hex!("31 03 02 01 08")[..]
+        ]
+    );
+
+    // Truncated unknown element
+    assert!(Extensible::from_bytes(&hex!("30 05 02 01 01 a4 03")).is_err());
+}
+
+/// Extensible `SEQUENCE` whose last known field is `OPTIONAL`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Message)]
+#[asn1(extensible)]
+pub struct ExtensibleOptional {
+    pub version: u8,
+    pub flag: Option<bool>,
+}
+
+#[test]
+fn extensible_skips_unknown_tags_after_optional() {
+    let msg = ExtensibleOptional::from_bytes(&hex!("30 06 02 01 01 80 01 ff")).unwrap();
+    assert_eq!(msg.version, 1);
+    assert_eq!(msg.flag, None);
+
+    let msg = ExtensibleOptional::from_bytes(EXTENDED_SEQUENCE_UNKNOWN_TAGS).unwrap();
+    assert_eq!(msg.version, 1);
+    assert_eq!(msg.flag, None);
+
+    let msg =
+        ExtensibleOptional::from_bytes(&hex!("30 0b 02 01 01 01 01 ff a4 03 02 01 07")).unwrap();
+    assert_eq!(msg.version, 1);
+    assert_eq!(msg.flag, Some(true));
+}
+
+#[test]
+fn extensible_captures_unknown_elements() {
+    let msg = WithExtensions::from_bytes(EXTENDED_SEQUENCE).unwrap();
+    assert_eq!(msg.version, 1);
+    assert_eq!(msg.extensions, [&hex!("05 00")[..], &hex!("01 01 ff")[..]]);
+
+    let msg = WithExtensions::from_bytes(&hex!("30 03 02 01 01")).unwrap();
+    assert!(msg.extensions.is_empty());
+
+    let mut buf = [0u8; 128];
+    assert_eq!(
+        msg.encode_to_slice(&mut buf).unwrap(),
+        &hex!("30 03 02 01 01")
+    );
+}
+
+/// Custom derive test case for the `default` attribute.
+///
+/// ```text
+/// Defaults ::= SEQUENCE {
+///      count       INTEGER (0..100),
+///      version     INTEGER DEFAULT 1,
+///      enabled     BOOLEAN DEFAULT TRUE }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Message)]
+pub struct Defaults {
+    #[asn1(range = "0..100")]
+    pub count: u8,
+
+    #[asn1(default = "DEFAULT_VERSION")]
+    pub version: i64,
+
+    #[asn1(default = "true")]
+    pub enabled: bool,
+}
+
+const DEFAULT_VERSION: i64 = 1;
+
+#[test]
+fn default_fields() {
+    let msg = Defaults::from_bytes(&hex!("30 03 02 01 07")).unwrap();
+    assert_eq!(
+        msg,
+        Defaults {
+            count: 7,
+            version: 1,
+            enabled: true,
+        }
+    );
+
+    // Fields equal to their default are omitted
+    let mut buf = [0u8; 128];
+    assert_eq!(
+        msg.encode_to_slice(&mut buf).unwrap(),
+        &hex!("30 03 02 01 07")
+    );
+
+    let msg = Defaults {
+        count: 7,
+        version: -2,
+        enabled: false,
+    };
+    let der = hex!("30 09 02 01 07 02 01 fe 01 01 00");
+    assert_eq!(msg.encode_to_slice(&mut buf).unwrap(), &der);
+    assert_eq!(Defaults::from_bytes(&der).unwrap(), msg);
+
+    // DER forbids explicitly encoding a default value
+    let err = Defaults::from_bytes(&hex!("30 06 02 01 07 01 01 ff")).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Noncanonical);
+}
+
+/// Non-extensible counterpart of [`Extensible`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Message)]
+pub struct Version {
+    pub version: u8,
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn document_round_trip() {
+    let bytes = hex!("30 0e 04 04 01 02 03 04 02 01 02 0c 03 61 62 63");
+    let doc = der::Document::<Constrained<'static>>::from_der(&bytes).unwrap();
+    assert_eq!(doc.decode().version, 2);
+
+    let msg = Constrained::from_bytes(&bytes).unwrap();
+    assert_eq!(
+        der::Document::<Constrained<'static>>::from_msg(&msg).unwrap(),
+        doc
+    );
+
+    assert!(der::Document::<Version>::from_der(&bytes).is_err());
+}
+
+/// Message with fields encapsulated inside of `OCTET STRING` and `BIT STRING`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Message)]
+pub struct Encapsulating<'a> {
+    #[asn1(type = "OCTET STRING", encapsulates)]
+    pub version: Version,
+
+    #[asn1(type = "BIT STRING", encapsulates)]
+    pub octets: OctetString<'a>,
+}
+
+/// Message with an `OPTIONAL` field encapsulated inside of an `OCTET STRING`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Message)]
+pub struct OptionalEncapsulating {
+    #[asn1(type = "OCTET STRING", encapsulates)]
+    pub version: Option<Version>,
+
+    pub flag: bool,
+}
+
+#[test]
+fn optional_encapsulated_field_round_trip() {
+    let mut buf = [0u8; 128];
+
+    let bytes = hex!("30 0a 04 05 30 03 02 01 05 01 01 ff");
+    let msg = OptionalEncapsulating::from_bytes(&bytes).unwrap();
+    assert_eq!(msg.version, Some(Version { version: 5 }));
+    assert!(msg.flag);
+    assert_eq!(msg.encode_to_slice(&mut buf).unwrap(), &bytes);
+
+    let bytes = hex!("30 03 01 01 00");
+    let msg = OptionalEncapsulating::from_bytes(&bytes).unwrap();
+    assert_eq!(msg.version, None);
+    assert!(!msg.flag);
+    assert_eq!(msg.encode_to_slice(&mut buf).unwrap(), &bytes);
+}
+
+#[test]
+fn encapsulated_fields_round_trip() {
+    let bytes = hex!("30 0e 04 05 30 03 02 01 05 03 05 00 04 02 01 02");
+    let msg = Encapsulating::from_bytes(&bytes).unwrap();
+    assert_eq!(msg.version, Version { version: 5 });
+    assert_eq!(msg.octets.as_bytes(), &[1, 2]);
+
+    let mut buf = [0u8; 128];
+    assert_eq!(msg.encode_to_slice(&mut buf).unwrap(), &bytes);
+
+    let mut encoder = Encoder::new(&mut buf);
+    encoder.encode(&msg).unwrap();
+    assert_eq!(encoder.finish().unwrap(), &bytes);
+
+    let err =
+        Encapsulating::from_bytes(&hex!("30 0b 04 02 05 00 03 05 00 04 02 01 02")).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::UnexpectedTag {
+            expected: Some(Tag::Sequence),
+            actual: Tag::Null
+        }
+    );
+}
@@ -0,0 +1,150 @@
+//! Tests for rendering DER as JSON and converting it back (JER)
+
+#![cfg(feature = "jer")]
+
+use der::{jer, Any, Decodable, ErrorKind};
+use hex_literal::hex;
+
+const ED25519_PRIV_DER: &[u8] = include_bytes!("../../pkcs8/tests/examples/ed25519-priv.der");
+const ED25519_ENCPRIV_DER: &[u8] =
+    include_bytes!("../../pkcs8/tests/examples/ed25519-encpriv-aes256-sha256.der");
+const P256_PRIV_DER: &[u8] = include_bytes!("../../pkcs8/tests/examples/p256-priv.der");
+const RSA2048_PUB_DER: &[u8] = include_bytes!("../../pkcs8/tests/examples/rsa2048-pub.der");
+
+/// Render the given DER as JSON, and check it converts back to the same DER.
+fn round_trip(der: &[u8]) -> String {
+    let json = jer::to_string(&Any::from_bytes(der).unwrap()).unwrap();
+    assert_eq!(jer::from_str(&json).unwrap(), der);
+
+    let pretty = jer::to_string_pretty(&Any::from_bytes(der).unwrap()).unwrap();
+    assert_eq!(jer::from_str(&pretty).unwrap(), der);
+
+    json
+}
+
+#[test]
+fn pkcs8_examples() {
+    for der in &[
+        ED25519_PRIV_DER,
+        ED25519_ENCPRIV_DER,
+        P256_PRIV_DER,
+        RSA2048_PUB_DER,
+    ] {
+        round_trip(der);
+    }
+}
+
+#[test]
+fn ed25519_private_key() {
+    assert_eq!(
+        jer::to_string_pretty(&Any::from_bytes(ED25519_PRIV_DER).unwrap()).unwrap(),
+        r#"[
+  0,
+  [
+    {"OBJECT IDENTIFIER": "1.3.101.112"}
+  ],
+  {"OCTET STRING": "042017ED9C73E9DB649EC189A612831C5FC570238207C1AA9DFBD2C53E3FF5E5EA85"}
+]"#
+    );
+}
+
+#[test]
+fn all_types() {
+    let json = r#"[
+        true,
+        false,
+        -129,
+        18446744073709551616,
+        null,
+        {"BIT STRING": {"value": "A0", "length": 3}},
+        {"BIT STRING": {"length": 0, "value": ""}},
+        {"OCTET STRING": "deadBEEF"},
+        {"OBJECT IDENTIFIER": "1.2.840.10045.3.1.7"},
+        {"UTF8String": "\"café\"\n😀"},
+        {"PrintableString": "Test User 1"},
+        {"IA5String": "test1@rsa.com"},
+        {"UTCTime": "1991-05-06T23:45:40Z"},
+        {"GeneralizedTime": "2021-05-06T23:45:40Z"},
+        {"[0]": [0]},
+        {"[3]": []}
+    ]"#;
+
+    let der = jer::from_str(json).unwrap();
+
+    assert_eq!(
+        der,
+        [
+            &hex!("30 7F")[..],
+            &hex!("01 01 FF"),
+            &hex!("01 01 00"),
+            &hex!("02 02 FF 7F"),
+            &hex!("02 09 01 00 00 00 00 00 00 00 00"),
+            &hex!("05 00"),
+            &hex!("03 02 05 A0"),
+            &hex!("03 01 00"),
+            &hex!("04 04 DE AD BE EF"),
+            &hex!("06 08 2A 86 48 CE 3D 03 01 07"),
+            &hex!("0C 0C 22 63 61 66 C3 A9 22 0A F0 9F 98 80"),
+            &hex!("13 0B 54 65 73 74 20 55 73 65 72 20 31"),
+            &hex!("16 0D 74 65 73 74 31 40 72 73 61 2E 63 6F 6D"),
+            &hex!("17 0D 39 31 30 35 30 36 32 33 34 35 34 30 5A"),
+            &hex!("18 0F 32 30 32 31 30 35 30 36 32 33 34 35 34 30 5A"),
+            &hex!("A0 03 02 01 00"),
+            &hex!("A3 00"),
+        ]
+        .concat()
+    );
+
+    assert_eq!(
+        round_trip(&der),
+        concat!(
+            r#"[true,false,-129,18446744073709551616,null,"#,
+            r#"{"BIT STRING":{"value":"A0","length":3}},"#,
+            r#"{"BIT STRING":{"value":"","length":0}},"#,
+            r#"{"OCTET STRING":"DEADBEEF"},"#,
+            r#"{"OBJECT IDENTIFIER":"1.2.840.10045.3.1.7"},"#,
+            r#"{"UTF8String":"\"café\"\n😀"},"#,
+            r#"{"PrintableString":"Test User 1"},"#,
+            r#"{"IA5String":"test1@rsa.com"},"#,
+            r#"{"UTCTime":"1991-05-06T23:45:40Z"},"#,
+            r#"{"GeneralizedTime":"2021-05-06T23:45:40Z"},"#,
+            r#"{"[0]":[0]},{"[3]":[]}]"#
+        )
+    );
+}
+
+#[test]
+fn malformed_json() {
+    let cases: &[(&str, usize)] = &[
+        ("", 0),
+        ("[1,]", 3),
+        ("[1] 2", 4),
+        ("01", 0),
+        ("1.5", 1),
+        ("\"string\"", 0),
+        (r#"{"REAL": 1}"#, 1),
+        (r#"{"OCTET STRING": "ABC"}"#, 17),
+        (r#"{"OBJECT IDENTIFIER": "1.2"}"#, 22),
+        (r#"{"PrintableString": "a@b"}"#, 20),
+        (r#"{"UTCTime": "2050-01-01T00:00:00Z"}"#, 12),
+        (r#"{"GeneralizedTime": "2021-02-29T00:00:00Z"}"#, 20),
+        (r#"{"BIT STRING": {"value": "A1", "length": 3}}"#, 15),
+        (r#"{"BIT STRING": {"value": "A0"}}"#, 15),
+        (r#"{"UTF8String": "\ud83d"}"#, 16),
+    ];
+
+    for &(json, position) in cases {
+        assert_eq!(
+            jer::from_str(json).unwrap_err().kind(),
+            ErrorKind::Jer { position },
+            "{}",
+            json
+        );
+    }
+}
+
+#[test]
+fn nesting_limit() {
+    let json = format!("{}{}", "[".repeat(64), "]".repeat(64));
+    assert!(jer::from_str(&json).is_err());
+}
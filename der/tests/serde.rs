@@ -0,0 +1,208 @@
+//! Tests for serde support
+
+#![cfg(feature = "serde")]
+
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Example message type mixing `OPTIONAL` fields with required ones.
+///
+/// ```text
+/// Example ::= SEQUENCE {
+///     version        INTEGER,
+///     name           UTF8String OPTIONAL,
+///     flag           BOOLEAN OPTIONAL,
+///     data           OCTET STRING,
+///     items          SEQUENCE OF INTEGER,
+///     trailer        NULL OPTIONAL }
+/// ```
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct Example<'a> {
+    version: u32,
+    name: Option<&'a str>,
+    flag: Option<bool>,
+    #[serde(with = "serde_bytes")]
+    data: &'a [u8],
+    items: Vec<i64>,
+    trailer: Option<()>,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+enum Choice {
+    Unit,
+    Newtype(u8),
+    Tuple(bool, u16),
+    Struct { a: String },
+}
+
+#[test]
+fn round_trip_struct() {
+    let example = Example {
+        version: 2,
+        name: None,
+        flag: Some(true),
+        data: &[0xDE, 0xAD],
+        items: vec![1, -1, 256],
+        trailer: None,
+    };
+
+    let der = der::serde::to_vec(&example).unwrap();
+    assert_eq!(
+        der,
+        hex!("30 16 020102 0101FF 0402DEAD 300A 020101 0201FF 02020100").to_vec()
+    );
+
+    let decoded: Example<'_> = der::serde::from_bytes(&der).unwrap();
+    assert_eq!(example, decoded);
+}
+
+#[test]
+fn round_trip_all_optional_fields_present() {
+    let example = Example {
+        version: 0,
+        name: Some("hello"),
+        flag: Some(false),
+        data: &[],
+        items: vec![],
+        trailer: Some(()),
+    };
+
+    let der = der::serde::to_vec(&example).unwrap();
+    let decoded: Example<'_> = der::serde::from_bytes(&der).unwrap();
+    assert_eq!(example, decoded);
+}
+
+#[test]
+fn round_trip_enum() {
+    let variants = [
+        (Choice::Unit, &hex!("A0 02 0500")[..]),
+        (Choice::Newtype(128), &hex!("A1 04 02020080")[..]),
+        (
+            Choice::Tuple(true, 1),
+            &hex!("A2 08 3006 0101FF 020101")[..],
+        ),
+        (
+            Choice::Struct { a: "x".to_owned() },
+            &hex!("A3 05 3003 0C0178")[..],
+        ),
+    ];
+
+    for (choice, expected) in &variants {
+        let der = der::serde::to_vec(choice).unwrap();
+        assert_eq!(&der, expected);
+        assert_eq!(&der::serde::from_bytes::<Choice>(&der).unwrap(), choice);
+    }
+}
+
+#[test]
+fn reject_enum_variants_without_context_tag() {
+    #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+    enum Many {
+        A,
+        B,
+        C,
+        D(u8),
+        E,
+    }
+
+    let der = der::serde::to_vec(&Many::D(7)).unwrap();
+    assert_eq!(der, hex!("A3 03 020107"));
+    assert_eq!(der::serde::from_bytes::<Many>(&der).unwrap(), Many::D(7));
+
+    assert_eq!(
+        der::serde::to_vec(&Many::E),
+        Err(der::serde::Error::Unsupported(
+            "enum variant with an index above 3 (no context-specific tag)"
+        ))
+    );
+}
+
+#[test]
+fn reject_bit_string_with_unused_bits_as_bytes() {
+    /// Deserialized using `deserialize_any`
+    #[derive(Debug, Deserialize, Eq, PartialEq)]
+    #[serde(untagged)]
+    enum Untagged {
+        Bytes(#[serde(with = "serde_bytes")] Vec<u8>),
+    }
+
+    assert_eq!(
+        der::serde::from_bytes::<Untagged>(&hex!("03 02 00 FF")).unwrap(),
+        Untagged::Bytes(vec![0xFF])
+    );
+    assert!(der::serde::from_bytes::<Untagged>(&hex!("03 02 01 FE")).is_err());
+}
+
+#[test]
+fn reject_none_in_sequence_of() {
+    assert_eq!(
+        der::serde::to_vec(&vec![Some(1u8), None, Some(2)]),
+        Err(der::serde::Error::Unsupported(
+            "`None` element of a `SEQUENCE OF`"
+        ))
+    );
+
+    let der = der::serde::to_vec(&vec![Some(1u8), Some(2)]).unwrap();
+    assert_eq!(der, hex!("30 06 020101 020102"));
+    assert_eq!(
+        der::serde::from_bytes::<Vec<Option<u8>>>(&der).unwrap(),
+        vec![Some(1), Some(2)]
+    );
+}
+
+#[test]
+fn round_trip_map() {
+    let mut map = BTreeMap::new();
+    map.insert(1u8, "one".to_owned());
+    map.insert(2u8, "two".to_owned());
+
+    let der = der::serde::to_vec(&map).unwrap();
+    assert_eq!(
+        der,
+        hex!("30 14 3008 020101 0C036F6E65 3008 020102 0C0374776F").to_vec()
+    );
+
+    let decoded: BTreeMap<u8, String> = der::serde::from_bytes(&der).unwrap();
+    assert_eq!(map, decoded);
+}
+
+#[test]
+fn integers() {
+    for &n in &[0i64, 127, 128, 255, 256, -1, -128, -129, i64::MIN, i64::MAX] {
+        let der = der::serde::to_vec(&n).unwrap();
+        assert_eq!(der::serde::from_bytes::<i64>(&der).unwrap(), n);
+    }
+
+    assert_eq!(
+        der::serde::to_vec(&u64::MAX).unwrap(),
+        hex!("02 09 00FFFFFFFFFFFFFFFF")
+    );
+    assert_eq!(
+        der::serde::to_vec(&u128::MAX).unwrap()[..3],
+        hex!("02 11 00")
+    );
+    assert_eq!(
+        der::serde::from_bytes::<u128>(&der::serde::to_vec(&u128::MAX).unwrap()).unwrap(),
+        u128::MAX
+    );
+
+    // Out of range for the target type
+    assert!(der::serde::from_bytes::<u8>(&hex!("02 02 0100")).is_err());
+
+    // Non-canonical encoding
+    assert!(der::serde::from_bytes::<u8>(&hex!("02 02 0001")).is_err());
+}
+
+#[test]
+fn reject_trailing_data() {
+    assert!(der::serde::from_bytes::<bool>(&hex!("0101FF 0500")).is_err());
+}
+
+#[test]
+fn reject_floats() {
+    assert_eq!(
+        der::serde::to_vec(&1.0f64),
+        Err(der::serde::Error::Unsupported("floating point"))
+    );
+}
@@ -0,0 +1,5 @@
+use der::der;
+
+const NAME: [u8; 7] = der!(PrintableString "a@b.c");
+
+fn main() {}
@@ -0,0 +1,5 @@
+use der::der;
+
+const OID: [u8; 4] = der!(OID 1.2.03);
+
+fn main() {}
@@ -0,0 +1,5 @@
+use der::der;
+
+const TAGGED: [u8; 4] = der!([31] EXPLICIT BOOLEAN true);
+
+fn main() {}
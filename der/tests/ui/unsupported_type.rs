@@ -0,0 +1,5 @@
+use der::der;
+
+const REAL: [u8; 3] = der!(REAL 1);
+
+fn main() {}
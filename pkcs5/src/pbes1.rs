@@ -4,7 +4,10 @@
 
 use crate::{AlgorithmIdentifier, Error, ObjectIdentifier};
 use core::convert::{TryFrom, TryInto};
-use der::{sequence, Any, Encodable, Encoder, ErrorKind, Header, Length, OctetString, Result, Tag};
+use der::{
+    oid_map, sequence, Any, Encodable, Encoder, ErrorKind, Header, Length, OctetString, OidMap,
+    Result, Tag,
+};
 
 /// `pbeWithMD2AndDES-CBC` Object Identifier (OID).
 pub const PBE_WITH_MD2_AND_DES_CBC_OID: ObjectIdentifier =
@@ -78,7 +81,7 @@ impl<'a> TryFrom<AlgorithmIdentifier<'a>> for Parameters {
     fn try_from(alg: AlgorithmIdentifier<'a>) -> Result<Self> {
         // Ensure that we have a supported PBES1 algorithm identifier
         let encryption = EncryptionScheme::try_from(alg.oid).map_err(|_| ErrorKind::Value {
-            tag: der::Tag::ObjectIdentifier,
+            tag: Tag::ObjectIdentifier,
         })?;
 
         alg.parameters_any()?.sequence(|params| {
@@ -88,7 +91,7 @@ impl<'a> TryFrom<AlgorithmIdentifier<'a>> for Parameters {
                     .as_bytes()
                     .try_into()
                     .map_err(|_| ErrorKind::Value {
-                        tag: der::Tag::OctetString,
+                        tag: Tag::OctetString,
                     })?;
 
             let iteration_count = params.decode()?;
@@ -168,19 +171,26 @@ pub enum EncryptionScheme {
     PbeWithSha1AndRc2Cbc,
 }
 
+oid_map! {
+    /// PBES1 encryption schemes indexed by OID.
+    const ENCRYPTION_SCHEMES: OidMap<EncryptionScheme> = {
+        PBE_WITH_MD2_AND_DES_CBC_OID => EncryptionScheme::PbeWithMd2AndDesCbc,
+        PBE_WITH_MD2_AND_RC2_CBC_OID => EncryptionScheme::PbeWithMd2AndRc2Cbc,
+        PBE_WITH_MD5_AND_DES_CBC_OID => EncryptionScheme::PbeWithMd5AndDesCbc,
+        PBE_WITH_MD5_AND_RC2_CBC_OID => EncryptionScheme::PbeWithMd5AndRc2Cbc,
+        PBE_WITH_SHA1_AND_DES_CBC_OID => EncryptionScheme::PbeWithSha1AndDesCbc,
+        PBE_WITH_SHA1_AND_RC2_CBC_OID => EncryptionScheme::PbeWithSha1AndRc2Cbc,
+    };
+}
+
 impl TryFrom<ObjectIdentifier> for EncryptionScheme {
     type Error = Error;
 
     fn try_from(oid: ObjectIdentifier) -> Result<Self> {
-        match oid {
-            PBE_WITH_MD2_AND_DES_CBC_OID => Ok(Self::PbeWithMd2AndDesCbc),
-            PBE_WITH_MD2_AND_RC2_CBC_OID => Ok(Self::PbeWithMd2AndRc2Cbc),
-            PBE_WITH_MD5_AND_DES_CBC_OID => Ok(Self::PbeWithMd5AndDesCbc),
-            PBE_WITH_MD5_AND_RC2_CBC_OID => Ok(Self::PbeWithMd5AndRc2Cbc),
-            PBE_WITH_SHA1_AND_DES_CBC_OID => Ok(Self::PbeWithSha1AndDesCbc),
-            PBE_WITH_SHA1_AND_RC2_CBC_OID => Ok(Self::PbeWithSha1AndRc2Cbc),
-            _ => Err(ErrorKind::UnknownOid { oid }.into()),
-        }
+        ENCRYPTION_SCHEMES
+            .get(&oid)
+            .copied()
+            .ok_or_else(|| ErrorKind::UnknownOid { oid }.into())
     }
 }
 
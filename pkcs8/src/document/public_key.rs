@@ -1,6 +1,6 @@
 //! SPKI public key document.
 
-use crate::{error, Error, Result, SubjectPublicKeyInfo};
+use crate::{Error, Result, SpkiDocument, SubjectPublicKeyInfo};
 use alloc::{borrow::ToOwned, vec::Vec};
 use core::{
     convert::{TryFrom, TryInto},
@@ -19,6 +19,9 @@ use {crate::pem, alloc::string::String, core::str::FromStr};
 /// This type provides storage for [`SubjectPublicKeyInfo`] encoded as ASN.1
 /// DER with the invariant that the contained-document is "well-formed", i.e.
 /// it will parse successfully according to this crate's parsing rules.
+///
+/// Crates which only deal with public keys can use [`SpkiDocument`] from the
+/// [`spki`] crate instead. The two types can be converted to each other.
 #[derive(Clone)]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 pub struct PublicKeyDocument(Vec<u8>);
@@ -95,18 +98,37 @@ impl AsRef<[u8]> for PublicKeyDocument {
     }
 }
 
-impl From<SubjectPublicKeyInfo<'_>> for PublicKeyDocument {
-    fn from(spki: SubjectPublicKeyInfo<'_>) -> PublicKeyDocument {
-        PublicKeyDocument::from(&spki)
+impl TryFrom<SubjectPublicKeyInfo<'_>> for PublicKeyDocument {
+    type Error = Error;
+
+    fn try_from(spki: SubjectPublicKeyInfo<'_>) -> Result<PublicKeyDocument> {
+        PublicKeyDocument::try_from(&spki)
+    }
+}
+
+impl TryFrom<&SubjectPublicKeyInfo<'_>> for PublicKeyDocument {
+    type Error = Error;
+
+    /// Encode a [`SubjectPublicKeyInfo`], which fails if it can't be decoded
+    /// again (e.g. because of a malformed EC point).
+    fn try_from(spki: &SubjectPublicKeyInfo<'_>) -> Result<PublicKeyDocument> {
+        spki.to_vec().map_err(|_| Error::Encode)?.try_into()
     }
 }
 
-impl From<&SubjectPublicKeyInfo<'_>> for PublicKeyDocument {
-    fn from(spki: &SubjectPublicKeyInfo<'_>) -> PublicKeyDocument {
-        spki.to_vec()
-            .ok()
-            .and_then(|buf| buf.try_into().ok())
-            .expect(error::DER_ENCODING_MSG)
+impl TryFrom<SpkiDocument> for PublicKeyDocument {
+    type Error = Error;
+
+    fn try_from(doc: SpkiDocument) -> Result<PublicKeyDocument> {
+        doc.into_der().try_into()
+    }
+}
+
+impl TryFrom<PublicKeyDocument> for SpkiDocument {
+    type Error = Error;
+
+    fn try_from(doc: PublicKeyDocument) -> Result<SpkiDocument> {
+        Ok(doc.0.try_into()?)
     }
 }
 
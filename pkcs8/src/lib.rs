@@ -20,6 +20,8 @@
 //! - [`EncryptedPrivateKeyDocument`]: (with `pkcs5` feature) heap-backed encrypted key.
 //! - [`PrivateKeyDocument`]: heap-backed storage for serialized [`PrivateKeyInfo`].
 //! - [`PublicKeyDocument`]: heap-backed storage for serialized [`SubjectPublicKeyInfo`].
+//! - [`SpkiDocument`]: heap-backed storage for serialized [`SubjectPublicKeyInfo`]
+//!   (re-exported from the [`spki`] crate, convertible to/from [`PublicKeyDocument`]).
 //!
 //! When the `pem` feature is enabled, it also supports decoding/encoding
 //! documents from "PEM encoding" format as defined in RFC 7468.
@@ -62,7 +64,7 @@
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
     html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
-    html_root_url = "https://docs.rs/pkcs8/0.5.5"
+    html_root_url = "https://docs.rs/pkcs8/0.6.0"
 )]
 #![forbid(unsafe_code)]
 #![warn(missing_docs, rust_2018_idioms)]
@@ -80,6 +82,9 @@ pub use encrypted_private_key_info::EncryptedPrivateKeyInfo;
 pub use pkcs5;
 pub use spki::{AlgorithmIdentifier, SubjectPublicKeyInfo};
 
+#[cfg(feature = "alloc")]
+pub use spki::SpkiDocument;
+
 #[cfg(all(feature = "alloc", feature = "pkcs5"))]
 pub use crate::document::encrypted_private_key::EncryptedPrivateKeyDocument;
 #[cfg(feature = "alloc")]
@@ -7,8 +7,8 @@ use pkcs8::SubjectPublicKeyInfo;
 #[cfg(feature = "alloc")]
 use der::Encodable;
 
-#[cfg(any(feature = "pem", feature = "std"))]
-use pkcs8::PublicKeyDocument;
+#[cfg(feature = "alloc")]
+use pkcs8::{PublicKeyDocument, SpkiDocument};
 
 /// Elliptic Curve (P-256) `SubjectPublicKeyInfo` encoded as ASN.1 DER
 const EC_P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-pub.der");
@@ -42,7 +42,7 @@ fn decode_ec_p256_der() {
         "1.2.840.10045.3.1.7".parse().unwrap()
     );
 
-    assert_eq!(spki.subject_public_key, &hex!("041CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7FC481A44492304D7EF66AC117BEFE83A8D08F155F2B52F9F618DD447029048E0F")[..]);
+    assert_eq!(spki.subject_public_key.as_bytes(), &hex!("041CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7FC481A44492304D7EF66AC117BEFE83A8D08F155F2B52F9F618DD447029048E0F")[..]);
 }
 
 #[test]
@@ -52,7 +52,7 @@ fn decode_ed25519_der() {
     assert_eq!(spki.algorithm.oid, "1.3.101.112".parse().unwrap());
     assert_eq!(spki.algorithm.parameters, None);
     assert_eq!(
-        spki.subject_public_key,
+        spki.subject_public_key.as_bytes(),
         &hex!("4D29167F3F1912A6F7ADFA293A051A15C05EC67B8F17267B1C5550DCE853BD0D")[..]
     );
 }
@@ -63,7 +63,7 @@ fn decode_rsa_2048_der() {
 
     assert_eq!(spki.algorithm.oid, "1.2.840.113549.1.1.1".parse().unwrap());
     assert!(spki.algorithm.parameters.unwrap().is_null());
-    assert_eq!(spki.subject_public_key, &hex!("3082010A0282010100B6C42C515F10A6AAF282C63EDBE24243A170F3FA2633BD4833637F47CA4F6F36E03A5D29EFC3191AC80F390D874B39E30F414FCEC1FCA0ED81E547EDC2CD382C76F61C9018973DB9FA537972A7C701F6B77E0982DFC15FC01927EE5E7CD94B4F599FF07013A7C8281BDF22DCBC9AD7CABB7C4311C982F58EDB7213AD4558B332266D743AED8192D1884CADB8B14739A8DADA66DC970806D9C7AC450CB13D0D7C575FB198534FC61BC41BC0F0574E0E0130C7BBBFBDFDC9F6A6E2E3E2AFF1CBEAC89BA57884528D55CFB08327A1E8C89F4E003CF2888E933241D9D695BCBBACDC90B44E3E095FA37058EA25B13F5E295CBEAC6DE838AB8C50AF61E298975B872F0203010001")[..]);
+    assert_eq!(spki.subject_public_key.as_bytes(), &hex!("3082010A0282010100B6C42C515F10A6AAF282C63EDBE24243A170F3FA2633BD4833637F47CA4F6F36E03A5D29EFC3191AC80F390D874B39E30F414FCEC1FCA0ED81E547EDC2CD382C76F61C9018973DB9FA537972A7C701F6B77E0982DFC15FC01927EE5E7CD94B4F599FF07013A7C8281BDF22DCBC9AD7CABB7C4311C982F58EDB7213AD4558B332266D743AED8192D1884CADB8B14739A8DADA66DC970806D9C7AC450CB13D0D7C575FB198534FC61BC41BC0F0574E0E0130C7BBBFBDFDC9F6A6E2E3E2AFF1CBEAC89BA57884528D55CFB08327A1E8C89F4E003CF2888E933241D9D695BCBBACDC90B44E3E095FA37058EA25B13F5E295CBEAC6DE838AB8C50AF61E298975B872F0203010001")[..]);
 }
 
 #[test]
@@ -123,11 +123,20 @@ fn encode_rsa_2048_der() {
     assert_eq!(RSA_2048_DER_EXAMPLE, pk_encoded.as_slice());
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn convert_spki_document() {
+    let spki_doc = SpkiDocument::from_der(RSA_2048_DER_EXAMPLE).unwrap();
+    let pkcs8_doc = PublicKeyDocument::try_from(spki_doc.clone()).unwrap();
+    assert_eq!(pkcs8_doc.as_ref(), RSA_2048_DER_EXAMPLE);
+    assert_eq!(SpkiDocument::try_from(pkcs8_doc).unwrap(), spki_doc);
+}
+
 #[test]
 #[cfg(feature = "pem")]
 fn encode_ec_p256_pem() {
     let pk = SubjectPublicKeyInfo::try_from(EC_P256_DER_EXAMPLE).unwrap();
-    let pk_encoded = PublicKeyDocument::from(pk).to_pem();
+    let pk_encoded = PublicKeyDocument::try_from(pk).unwrap().to_pem();
     assert_eq!(EC_P256_PEM_EXAMPLE.trim_end(), pk_encoded);
 }
 
@@ -135,7 +144,7 @@ fn encode_ec_p256_pem() {
 #[cfg(feature = "pem")]
 fn encode_ed25519_pem() {
     let pk = SubjectPublicKeyInfo::try_from(ED25519_DER_EXAMPLE).unwrap();
-    let pk_encoded = PublicKeyDocument::from(pk).to_pem();
+    let pk_encoded = PublicKeyDocument::try_from(pk).unwrap().to_pem();
     assert_eq!(ED25519_PEM_EXAMPLE.trim_end(), pk_encoded);
 }
 
@@ -143,7 +152,7 @@ fn encode_ed25519_pem() {
 #[cfg(feature = "pem")]
 fn encode_rsa_2048_pem() {
     let pk = SubjectPublicKeyInfo::try_from(RSA_2048_DER_EXAMPLE).unwrap();
-    let pk_encoded = PublicKeyDocument::from(pk).to_pem();
+    let pk_encoded = PublicKeyDocument::try_from(pk).unwrap().to_pem();
     assert_eq!(RSA_2048_PEM_EXAMPLE.trim_end(), pk_encoded);
 }
 
@@ -160,3 +169,20 @@ fn read_pem_file() {
     let pkcs8_doc = PublicKeyDocument::read_pem_file("tests/examples/p256-pub.pem").unwrap();
     assert_eq!(pkcs8_doc.as_ref(), EC_P256_DER_EXAMPLE);
 }
+
+#[test]
+#[cfg(feature = "alloc")]
+fn reject_malformed_ec_point() {
+    let spki = SubjectPublicKeyInfo {
+        algorithm: pkcs8::AlgorithmIdentifier {
+            oid: spki::EC_PUBLIC_KEY_OID,
+            parameters: Some(spki::AlgorithmParameters::Oid(spki::SECP256R1_OID)),
+        },
+        subject_public_key: der::BitString::new(&[0x04; 12]).unwrap(),
+    };
+
+    assert_eq!(
+        PublicKeyDocument::try_from(&spki).unwrap_err(),
+        pkcs8::Error::Encode
+    );
+}
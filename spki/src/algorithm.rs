@@ -36,7 +36,7 @@ impl<'a> AlgorithmIdentifier<'a> {
 
         params.any().ok_or_else(|| {
             der::ErrorKind::UnexpectedTag {
-                expected: Some(der::Tag::Sequence),
+                expected: Some(Tag::Sequence),
                 actual: params.tag(),
             }
             .into()
@@ -51,7 +51,7 @@ impl<'a> AlgorithmIdentifier<'a> {
 
         params.oid().ok_or_else(|| {
             der::ErrorKind::UnexpectedTag {
-                expected: Some(der::Tag::Sequence),
+                expected: Some(Tag::Sequence),
                 actual: params.tag(),
             }
             .into()
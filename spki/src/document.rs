@@ -0,0 +1,19 @@
+//! SPKI public key document.
+
+use crate::SubjectPublicKeyInfo;
+
+/// Owned SPKI public key document.
+///
+/// Provides heap-backed storage for a [`SubjectPublicKeyInfo`] encoded as
+/// ASN.1 DER, which is checked to be well-formed when the document is
+/// constructed. The [`SubjectPublicKeyInfo`] can be accessed using
+/// [`Document::decode`][`der::Document::decode`].
+///
+/// PEM-encoded documents (`pem` feature) can be identified by the leading
+/// delimiter:
+///
+/// ```text
+/// -----BEGIN PUBLIC KEY-----
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub type SpkiDocument = der::Document<SubjectPublicKeyInfo<'static>>;
@@ -0,0 +1,417 @@
+//! Elliptic curve domain parameters.
+
+use crate::{AlgorithmParameters, NamedCurve};
+use core::convert::{TryFrom, TryInto};
+use der::{
+    Any, BitString, Choice, Encodable, Encoder, Error, ErrorKind, Length, ObjectIdentifier, Result,
+    Tag,
+};
+
+/// `prime-field` Object Identifier (OID).
+const PRIME_FIELD_OID: ObjectIdentifier = ObjectIdentifier::parse("1.2.840.10045.1.1");
+
+/// Elliptic curve domain parameters, i.e. the `parameters` of an
+/// `id-ecPublicKey` [`AlgorithmIdentifier`][`crate::AlgorithmIdentifier`].
+///
+/// Described in [RFC 5480 Section 2.1.1] and [SEC 1 Appendix C.2]:
+///
+/// ```text
+/// ECParameters ::= CHOICE {
+///   namedCurve         OBJECT IDENTIFIER
+///   implicitCurve      NULL
+///   specifiedCurve     SpecifiedECDomain
+/// }
+/// ```
+///
+/// Explicitly specified curves are only accepted if their domain parameters
+/// match one of the known [`NamedCurve`]s. They can be rejected entirely
+/// (as RFC 5480 requires) using [`ExplicitCurvePolicy::Reject`].
+///
+/// [RFC 5480 Section 2.1.1]: https://tools.ietf.org/html/rfc5480#section-2.1.1
+/// [SEC 1 Appendix C.2]: https://www.secg.org/sec1-v2.pdf
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum EcParameters<'a> {
+    /// Curve identified by an [`ObjectIdentifier`] (`namedCurve`).
+    NamedCurve(ObjectIdentifier),
+
+    /// Curve inherited from the issuer's certificate (`implicitCurve`).
+    ImplicitCurve,
+
+    /// Curve with explicitly specified domain parameters (`specifiedCurve`).
+    SpecifiedCurve(SpecifiedCurve<'a>),
+}
+
+impl<'a> EcParameters<'a> {
+    /// Convert the `parameters` of an `id-ecPublicKey` `AlgorithmIdentifier`
+    /// into [`EcParameters`], handling explicitly specified curves according
+    /// to the given policy.
+    pub fn from_parameters(
+        params: AlgorithmParameters<'a>,
+        policy: ExplicitCurvePolicy,
+    ) -> Result<Self> {
+        match params {
+            AlgorithmParameters::Oid(oid) => Ok(Self::NamedCurve(oid)),
+            AlgorithmParameters::Null => Ok(Self::ImplicitCurve),
+            AlgorithmParameters::Any(any) => match policy {
+                ExplicitCurvePolicy::MatchNamed => any.try_into().map(Self::SpecifiedCurve),
+                ExplicitCurvePolicy::Reject => Err(ErrorKind::UnexpectedTag {
+                    expected: Some(Tag::ObjectIdentifier),
+                    actual: any.tag(),
+                }
+                .into()),
+            },
+        }
+    }
+
+    /// Get the known [`NamedCurve`] these parameters describe, if any.
+    ///
+    /// This includes explicitly specified curves, which always match a
+    /// [`NamedCurve`].
+    pub fn named_curve(self) -> Option<NamedCurve> {
+        match self {
+            Self::NamedCurve(oid) => oid.try_into().ok(),
+            Self::ImplicitCurve => None,
+            Self::SpecifiedCurve(curve) => Some(curve.named_curve()),
+        }
+    }
+
+    /// Are these explicitly specified domain parameters?
+    pub fn is_specified_curve(self) -> bool {
+        matches!(self, Self::SpecifiedCurve(_))
+    }
+
+    /// Get the ASN.1 DER [`Tag`] for these parameters.
+    pub fn tag(self) -> Tag {
+        match self {
+            Self::NamedCurve(_) => Tag::ObjectIdentifier,
+            Self::ImplicitCurve => Tag::Null,
+            Self::SpecifiedCurve(_) => Tag::Sequence,
+        }
+    }
+}
+
+impl<'a> From<NamedCurve> for EcParameters<'a> {
+    fn from(curve: NamedCurve) -> EcParameters<'a> {
+        Self::NamedCurve(curve.oid())
+    }
+}
+
+impl<'a> From<EcParameters<'a>> for AlgorithmParameters<'a> {
+    fn from(params: EcParameters<'a>) -> AlgorithmParameters<'a> {
+        match params {
+            EcParameters::NamedCurve(oid) => Self::Oid(oid),
+            EcParameters::ImplicitCurve => Self::Null,
+            EcParameters::SpecifiedCurve(curve) => Self::Any(curve.domain),
+        }
+    }
+}
+
+impl<'a> TryFrom<AlgorithmParameters<'a>> for EcParameters<'a> {
+    type Error = Error;
+
+    fn try_from(params: AlgorithmParameters<'a>) -> Result<Self> {
+        Self::from_parameters(params, ExplicitCurvePolicy::MatchNamed)
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for EcParameters<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        AlgorithmParameters::try_from(any)?.try_into()
+    }
+}
+
+impl<'a> Choice<'a> for EcParameters<'a> {
+    fn can_decode(tag: Tag) -> bool {
+        matches!(tag, Tag::ObjectIdentifier | Tag::Null | Tag::Sequence)
+    }
+}
+
+impl<'a> Encodable for EcParameters<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        AlgorithmParameters::from(*self).encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        AlgorithmParameters::from(*self).encode(encoder)
+    }
+}
+
+/// Policy for handling explicitly specified curves (`specifiedCurve`)
+/// when decoding [`EcParameters`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ExplicitCurvePolicy {
+    /// Accept explicitly specified curves whose domain parameters match one
+    /// of the known [`NamedCurve`]s.
+    MatchNamed,
+
+    /// Reject explicitly specified curves entirely.
+    Reject,
+}
+
+/// Explicitly specified curve domain parameters (`SpecifiedECDomain`) which
+/// have been validated to match a known [`NamedCurve`].
+///
+/// The generator (`base`) may be given in any of the point formats accepted
+/// by [`EcPoint`][`crate::EcPoint`], i.e. uncompressed, compressed or hybrid, while `INTEGER`
+/// fields must be minimally encoded.
+///
+/// Only curves over prime fields are supported:
+///
+/// ```text
+/// SpecifiedECDomain ::= SEQUENCE {
+///   version   SpecifiedECDomainVersion(ecdpVer1 | ecdpVer2 | ecdpVer3, ...),
+///   fieldID   FieldID {{FieldTypes}},
+///   curve     Curve,
+///   base      ECPoint,
+///   order     INTEGER,
+///   cofactor  INTEGER OPTIONAL,
+///   hash      HashAlgorithm OPTIONAL,
+///   ...
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SpecifiedCurve<'a> {
+    /// Known curve matching these domain parameters
+    named_curve: NamedCurve,
+
+    /// DER-encoded `SpecifiedECDomain`
+    domain: Any<'a>,
+}
+
+impl<'a> SpecifiedCurve<'a> {
+    /// Get the [`NamedCurve`] matching these domain parameters.
+    pub fn named_curve(self) -> NamedCurve {
+        self.named_curve
+    }
+
+    /// Get the DER-encoded `SpecifiedECDomain`.
+    pub fn domain(self) -> Any<'a> {
+        self.domain
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for SpecifiedCurve<'a> {
+    type Error = Error;
+
+    fn try_from(domain: Any<'a>) -> Result<Self> {
+        let named_curve = domain.sequence(|decoder| {
+            let version = decoder.uint8()?;
+
+            if !(1..=3).contains(&version) {
+                return Err(ErrorKind::Value { tag: Tag::Integer }.into());
+            }
+
+            let prime = decoder.any()?.sequence(|field_id| {
+                let field_type = field_id.oid()?;
+
+                if field_type != PRIME_FIELD_OID {
+                    return Err(ErrorKind::UnknownOid { oid: field_type }.into());
+                }
+
+                uint(field_id.any()?)
+            })?;
+
+            let (a, b) = decoder.any()?.sequence(|curve| {
+                let a = curve.octet_string()?.as_bytes();
+                let b = curve.octet_string()?.as_bytes();
+                let _seed = curve.optional::<BitString<'_>>()?;
+                Ok((a, b))
+            })?;
+
+            let base = decoder.octet_string()?.as_bytes();
+            let order = uint(decoder.any()?)?;
+            let mut next = decoder.optional::<Any<'_>>()?;
+
+            let cofactor = match next {
+                Some(any) if any.tag() == Tag::Integer => {
+                    next = decoder.optional()?;
+                    Some(uint(any)?)
+                }
+                _ => None,
+            };
+
+            // Optional `hash` used to generate the curve, which doesn't
+            // affect its domain parameters
+            if let Some(hash) = next {
+                hash.tag().assert_eq(Tag::Sequence)?;
+            }
+
+            CURVE_DOMAINS
+                .iter()
+                .find(|domain| {
+                    eq_hex(prime, domain.p)
+                        && eq_hex(a, domain.a)
+                        && eq_hex(b, domain.b)
+                        && domain.is_generator(base)
+                        && eq_hex(order, domain.n)
+                        && cofactor.iter().all(|h| eq_hex(h, "01"))
+                })
+                .map(|domain| domain.named_curve)
+                .ok_or_else(|| ErrorKind::Value { tag: Tag::Sequence }.into())
+        })?;
+
+        Ok(Self {
+            named_curve,
+            domain,
+        })
+    }
+}
+
+/// Domain parameters of a known curve as hexadecimal strings.
+///
+/// All known curves have a cofactor of 1.
+struct CurveDomain {
+    /// Curve these are the domain parameters of
+    named_curve: NamedCurve,
+
+    /// Prime `p` specifying the base field
+    p: &'static str,
+
+    /// Coefficient `a` of the curve equation
+    a: &'static str,
+
+    /// Coefficient `b` of the curve equation
+    b: &'static str,
+
+    /// X coordinate of the generator `G`
+    gx: &'static str,
+
+    /// Y coordinate of the generator `G`
+    gy: &'static str,
+
+    /// Order `n` of the generator
+    n: &'static str,
+}
+
+impl CurveDomain {
+    /// Is the given SEC1-encoded point the generator of this curve?
+    fn is_generator(&self, point: &[u8]) -> bool {
+        let size = self.p.len() / 2;
+
+        let y_is_odd = self
+            .gy
+            .ends_with(&['1', '3', '5', '7', '9', 'b', 'd', 'f'][..]);
+
+        match point.split_first() {
+            // Uncompressed point
+            Some((0x04, coords)) if coords.len() == size * 2 => {
+                let (x, y) = coords.split_at(size);
+                eq_hex(x, self.gx) && eq_hex(y, self.gy)
+            }
+            // Compressed point, tagged with the parity of the Y coordinate
+            Some((&tag, x)) if (tag == 0x02 || tag == 0x03) && x.len() == size => {
+                tag == 0x02 + y_is_odd as u8 && eq_hex(x, self.gx)
+            }
+            // Hybrid point, which is uncompressed but also tagged with the
+            // parity of the Y coordinate (as accepted by `EcPoint`)
+            Some((&tag, coords)) if (tag == 0x06 || tag == 0x07) && coords.len() == size * 2 => {
+                let (x, y) = coords.split_at(size);
+                tag == 0x06 + y_is_odd as u8 && eq_hex(x, self.gx) && eq_hex(y, self.gy)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Domain parameters of the known curves, as specified in [SEC 2].
+///
+/// [SEC 2]: https://www.secg.org/sec2-v2.pdf
+const CURVE_DOMAINS: &[CurveDomain] = &[
+    CurveDomain {
+        named_curve: NamedCurve::P256,
+        p: "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+        a: "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc",
+        b: "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+        gx: "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+        gy: "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+        n: "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+    },
+    CurveDomain {
+        named_curve: NamedCurve::P384,
+        p: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe\
+            ffffffff0000000000000000ffffffff",
+        a: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe\
+            ffffffff0000000000000000fffffffc",
+        b: "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875a\
+            c656398d8a2ed19d2a85c8edd3ec2aef",
+        gx: "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a38\
+             5502f25dbf55296c3a545e3872760ab7",
+        gy: "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c0\
+             0a60b1ce1d7e819d7a431d7c90ea0e5f",
+        n: "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf\
+            581a0db248b0a77aecec196accc52973",
+    },
+    CurveDomain {
+        named_curve: NamedCurve::P521,
+        p: "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff\
+            ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff\
+            ffff",
+        a: "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff\
+            ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff\
+            fffc",
+        b: "0051953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef1\
+            09e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b50\
+            3f00",
+        gx: "00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d\
+             3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5\
+             bd66",
+        gy: "011839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e\
+             662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd1\
+             6650",
+        n: "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff\
+            fffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e9138\
+            6409",
+    },
+    CurveDomain {
+        named_curve: NamedCurve::Secp256k1,
+        p: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+        a: "00",
+        b: "07",
+        gx: "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        gy: "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+        n: "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+    },
+];
+
+/// Get the big endian bytes of a non-negative ASN.1 `INTEGER`.
+///
+/// Rejects negative and non-minimally encoded integers, i.e. ones with a
+/// leading zero byte which isn't needed to clear the sign bit.
+fn uint(any: Any<'_>) -> Result<&[u8]> {
+    any.tag().assert_eq(Tag::Integer)?;
+
+    match any.as_bytes() {
+        [0, next, ..] if next & 0x80 == 0 => Err(ErrorKind::Value { tag: Tag::Integer }.into()),
+        [first, ..] if first & 0x80 == 0 => Ok(any.as_bytes()),
+        _ => Err(ErrorKind::Value { tag: Tag::Integer }.into()),
+    }
+}
+
+/// Compare big endian bytes to a hexadecimal string, ignoring leading zeros
+/// (as both field elements and integers may be encoded with them).
+fn eq_hex(bytes: &[u8], hex: &str) -> bool {
+    let bytes = trim_zeros(bytes);
+    let hex = hex.trim_start_matches("00");
+
+    hex.len() == bytes.len() * 2
+        && bytes
+            .iter()
+            .zip(hex.as_bytes().chunks(2))
+            .all(|(&byte, digits)| {
+                core::str::from_utf8(digits)
+                    .ok()
+                    .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+                    == Some(byte)
+            })
+}
+
+/// Strip leading zero bytes.
+fn trim_zeros(mut bytes: &[u8]) -> &[u8] {
+    while let Some((0, rest)) = bytes.split_first() {
+        bytes = rest;
+    }
+
+    bytes
+}
@@ -0,0 +1,148 @@
+//! Elliptic curve point encoding.
+
+use crate::NamedCurve;
+use core::convert::TryFrom;
+use der::{Error, ErrorKind, Result, Tag};
+
+/// Elliptic curve point encoding formats as described in
+/// [SEC 1 Section 2.3.3], identified by the leading tag byte of an encoded
+/// point.
+///
+/// [SEC 1 Section 2.3.3]: https://www.secg.org/sec1-v2.pdf
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum PointFormat {
+    /// Compressed point: `02` or `03` (depending on the parity of the
+    /// Y coordinate) followed by the X coordinate.
+    Compressed,
+
+    /// Uncompressed point: `04` followed by the X and Y coordinates.
+    Uncompressed,
+
+    /// Hybrid point: `06` or `07` (depending on the parity of the
+    /// Y coordinate) followed by the X and Y coordinates.
+    Hybrid,
+}
+
+impl PointFormat {
+    /// Get the length of a point in this format with coordinates of the
+    /// given size in bytes.
+    pub fn encoded_len(self, field_size: usize) -> usize {
+        match self {
+            Self::Compressed => 1 + field_size,
+            Self::Uncompressed | Self::Hybrid => 1 + field_size * 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for PointFormat {
+    type Error = Error;
+
+    fn try_from(tag: u8) -> Result<Self> {
+        match tag {
+            0x02 | 0x03 => Ok(Self::Compressed),
+            0x04 => Ok(Self::Uncompressed),
+            0x06 | 0x07 => Ok(Self::Hybrid),
+            _ => Err(ErrorKind::Value {
+                tag: Tag::BitString,
+            }
+            .into()),
+        }
+    }
+}
+
+/// Elliptic curve point encoded as described in [SEC 1 Section 2.3.3],
+/// i.e. the `subjectPublicKey` of an `id-ecPublicKey` public key.
+///
+/// Points are checked to have a valid tag and the correct length for their
+/// curve, but not to be on the curve.
+///
+/// [SEC 1 Section 2.3.3]: https://www.secg.org/sec1-v2.pdf
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct EcPoint<'a> {
+    /// Curve the point is on
+    curve: NamedCurve,
+
+    /// Encoded point
+    bytes: &'a [u8],
+}
+
+impl<'a> EcPoint<'a> {
+    /// Parse an encoded point on the given curve.
+    ///
+    /// Rejects the point at infinity (`00`), as it isn't a valid public key.
+    pub fn new(bytes: &'a [u8], curve: NamedCurve) -> Result<Self> {
+        let tag = *bytes.first().ok_or(ErrorKind::Length {
+            tag: Tag::BitString,
+        })?;
+
+        let format = PointFormat::try_from(tag)?;
+
+        if bytes.len() != format.encoded_len(curve.field_size()) {
+            return Err(ErrorKind::Length {
+                tag: Tag::BitString,
+            }
+            .into());
+        }
+
+        let point = Self { curve, bytes };
+
+        // The tag of hybrid points duplicates the parity of the Y coordinate
+        if format == PointFormat::Hybrid && point.y_is_odd() != (tag == 0x07) {
+            return Err(ErrorKind::Value {
+                tag: Tag::BitString,
+            }
+            .into());
+        }
+
+        Ok(point)
+    }
+
+    /// Get the curve this point is on.
+    pub fn curve(self) -> NamedCurve {
+        self.curve
+    }
+
+    /// Get the encoding format of this point.
+    pub fn format(self) -> PointFormat {
+        PointFormat::try_from(self.bytes[0]).expect("malformed EcPoint")
+    }
+
+    /// Is this point compressed?
+    pub fn is_compressed(self) -> bool {
+        self.format() == PointFormat::Compressed
+    }
+
+    /// Borrow the encoded point.
+    pub fn as_bytes(self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Get the X coordinate.
+    pub fn x(self) -> &'a [u8] {
+        &self.bytes[1..(1 + self.curve.field_size())]
+    }
+
+    /// Get the Y coordinate, unless the point is compressed.
+    pub fn y(self) -> Option<&'a [u8]> {
+        match self.format() {
+            PointFormat::Compressed => None,
+            PointFormat::Uncompressed | PointFormat::Hybrid => {
+                Some(&self.bytes[(1 + self.curve.field_size())..])
+            }
+        }
+    }
+
+    /// Is the Y coordinate odd?
+    pub fn y_is_odd(self) -> bool {
+        match self.y() {
+            Some(y) => y[y.len() - 1] & 1 == 1,
+            None => self.bytes[0] == 0x03,
+        }
+    }
+}
+
+impl AsRef<[u8]> for EcPoint<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.bytes
+    }
+}
@@ -0,0 +1,279 @@
+//! SPKI fingerprints and public key pins.
+
+use crate::{AlgorithmParameters, SubjectPublicKeyInfo};
+use base64ct::{Base64, Encoding};
+use core::fmt;
+use der::{Any, Encodable, Encoder, ErrorKind, Header, Length, Null, Result, Tag};
+use sha1::Sha1;
+use sha2::{digest::Output, Digest, Sha256, Sha384};
+
+#[cfg(feature = "alloc")]
+use {
+    alloc::string::{String, ToString},
+    core::fmt::Write,
+};
+
+/// Maximum size of a [`Fingerprint`] in bytes (i.e. the SHA-384 output size).
+const MAX_SIZE: usize = 48;
+
+/// Maximum size of the Base64 encoding of a [`Fingerprint`] in bytes.
+#[cfg(feature = "alloc")]
+const BASE64_MAX_SIZE: usize = 64;
+
+/// Digest algorithms which can be used to compute a [`Fingerprint`].
+#[cfg_attr(docsrs, doc(cfg(feature = "fingerprint")))]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum FingerprintAlgorithm {
+    /// SHA-1: only for compatibility with legacy systems.
+    Sha1,
+
+    /// SHA-256: used by HPKP and curl's `--pinnedpubkey`.
+    Sha256,
+
+    /// SHA-384.
+    Sha384,
+}
+
+impl FingerprintAlgorithm {
+    /// Size of the fingerprints computed by this algorithm in bytes.
+    pub fn output_size(self) -> usize {
+        match self {
+            Self::Sha1 => 20,
+            Self::Sha256 => 32,
+            Self::Sha384 => 48,
+        }
+    }
+
+    /// Name of this algorithm as used in public key pins, e.g. `sha256`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Sha1 => "sha1",
+            Self::Sha256 => "sha256",
+            Self::Sha384 => "sha384",
+        }
+    }
+}
+
+/// Fingerprint of a [`SubjectPublicKeyInfo`], i.e. a digest of its
+/// DER encoding.
+///
+/// Use [`Fingerprint::to_hex`] or [`Fingerprint::to_colon_hex`] to display
+/// the fingerprint, or [`Fingerprint::to_base64`] to obtain a public key
+/// pin.
+#[cfg_attr(docsrs, doc(cfg(feature = "fingerprint")))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Fingerprint {
+    /// Digest algorithm used to compute this fingerprint
+    algorithm: FingerprintAlgorithm,
+
+    /// Digest output, padded with zeroes to [`MAX_SIZE`]
+    bytes: [u8; MAX_SIZE],
+}
+
+impl Fingerprint {
+    /// Compute the fingerprint of the given DER-encoded data.
+    pub fn new(algorithm: FingerprintAlgorithm, der: &[u8]) -> Self {
+        let mut bytes = [0u8; MAX_SIZE];
+        let output = &mut bytes[..algorithm.output_size()];
+
+        match algorithm {
+            FingerprintAlgorithm::Sha1 => output.copy_from_slice(&Sha1::digest(der)),
+            FingerprintAlgorithm::Sha256 => output.copy_from_slice(&Sha256::digest(der)),
+            FingerprintAlgorithm::Sha384 => output.copy_from_slice(&Sha384::digest(der)),
+        }
+
+        Self { algorithm, bytes }
+    }
+
+    /// Compute the fingerprint of the DER encoding of the given
+    /// [`SubjectPublicKeyInfo`] without buffering the encoding.
+    fn from_spki(algorithm: FingerprintAlgorithm, spki: &SubjectPublicKeyInfo<'_>) -> Result<Self> {
+        let mut bytes = [0u8; MAX_SIZE];
+        let output = &mut bytes[..algorithm.output_size()];
+
+        match algorithm {
+            FingerprintAlgorithm::Sha1 => output.copy_from_slice(&digest_spki::<Sha1>(spki)?),
+            FingerprintAlgorithm::Sha256 => output.copy_from_slice(&digest_spki::<Sha256>(spki)?),
+            FingerprintAlgorithm::Sha384 => output.copy_from_slice(&digest_spki::<Sha384>(spki)?),
+        }
+
+        Ok(Self { algorithm, bytes })
+    }
+
+    /// Get the digest algorithm used to compute this fingerprint.
+    pub fn algorithm(&self) -> FingerprintAlgorithm {
+        self.algorithm
+    }
+
+    /// Borrow the raw digest output.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.algorithm.output_size()]
+    }
+
+    /// Encode this fingerprint as lower case hexadecimal,
+    /// e.g. `0fa3...`.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_hex(&self) -> String {
+        let mut hex = String::with_capacity(self.as_bytes().len() * 2);
+        write!(hex, "{:x}", self).expect("formatting failed");
+        hex
+    }
+
+    /// Encode this fingerprint as colon-separated upper case hexadecimal,
+    /// e.g. `0F:A3:...`, as displayed by OpenSSL and most browsers.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_colon_hex(&self) -> String {
+        let mut hex = String::with_capacity(self.as_bytes().len() * 3);
+
+        for (i, byte) in self.as_bytes().iter().enumerate() {
+            if i > 0 {
+                hex.push(':');
+            }
+
+            write!(hex, "{:02X}", byte).expect("formatting failed");
+        }
+
+        hex
+    }
+
+    /// Encode this fingerprint as Base64 into the provided buffer, which
+    /// must be at least 64 bytes to fit any fingerprint.
+    ///
+    /// For SHA-256 fingerprints this is the `pin-sha256` value used by
+    /// [HPKP (RFC 7469)][RFC 7469].
+    ///
+    /// [RFC 7469]: https://tools.ietf.org/html/rfc7469#section-2.4
+    pub fn encode_base64<'o>(&self, out: &'o mut [u8]) -> Result<&'o str> {
+        Base64::encode(self.as_bytes(), out).map_err(|_| ErrorKind::Overlength.into())
+    }
+
+    /// Encode this fingerprint as Base64.
+    ///
+    /// See [`Fingerprint::encode_base64`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_base64(&self) -> String {
+        let mut buf = [0u8; BASE64_MAX_SIZE];
+        self.encode_base64(&mut buf)
+            .expect("buffer fits any fingerprint")
+            .to_string()
+    }
+
+    /// Encode this fingerprint as a public key pin in the format accepted by
+    /// curl's `--pinnedpubkey` option, i.e. `sha256//<base64>`.
+    ///
+    /// Returns [`None`] for fingerprints which weren't computed using SHA-256,
+    /// as curl only supports SHA-256 pins.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_pin(&self) -> Option<String> {
+        if self.algorithm != FingerprintAlgorithm::Sha256 {
+            return None;
+        }
+
+        let mut pin = String::from("sha256//");
+        pin.push_str(&self.to_base64());
+        Some(pin)
+    }
+}
+
+impl AsRef<[u8]> for Fingerprint {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl fmt::LowerHex for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.as_bytes() {
+            write!(f, "{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::UpperHex for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.as_bytes() {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> SubjectPublicKeyInfo<'a> {
+    /// Compute the [`Fingerprint`] of the DER encoding of this
+    /// [`SubjectPublicKeyInfo`] using the given digest algorithm.
+    #[cfg_attr(docsrs, doc(cfg(feature = "fingerprint")))]
+    pub fn fingerprint(&self, algorithm: FingerprintAlgorithm) -> Result<Fingerprint> {
+        Fingerprint::from_spki(algorithm, self)
+    }
+
+    /// Compute the Base64-encoded SHA-256 fingerprint of this
+    /// [`SubjectPublicKeyInfo`], i.e. its [HPKP] `pin-sha256` value.
+    ///
+    /// Prefix it with `sha256//` for use with curl's `--pinnedpubkey`.
+    ///
+    /// [HPKP]: https://tools.ietf.org/html/rfc7469#section-2.4
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "fingerprint", feature = "alloc"))))]
+    pub fn pin_sha256(&self) -> Result<String> {
+        self.fingerprint(FingerprintAlgorithm::Sha256)
+            .map(|fingerprint| fingerprint.to_base64())
+    }
+}
+
+/// Digest the DER encoding of a [`SubjectPublicKeyInfo`] incrementally,
+/// hashing the contents of its fields in place rather than encoding it into
+/// a buffer.
+fn digest_spki<D: Digest>(spki: &SubjectPublicKeyInfo<'_>) -> Result<Output<D>> {
+    // Fail like encoding does
+    spki.check_public_key()?;
+
+    let algorithm = &spki.algorithm;
+    let public_key = &spki.subject_public_key;
+    let mut digest = D::new();
+
+    let spki_len = (algorithm.encoded_len()? + public_key.encoded_len()?)?;
+    digest_header(&mut digest, Tag::Sequence, spki_len)?;
+
+    let algorithm_len = (algorithm.oid.encoded_len()? + algorithm.parameters.encoded_len()?)?;
+    digest_header(&mut digest, Tag::Sequence, algorithm_len)?;
+    digest_any(&mut digest, Any::from(&algorithm.oid))?;
+
+    match algorithm.parameters {
+        Some(AlgorithmParameters::Any(any)) => digest_any(&mut digest, any)?,
+        Some(AlgorithmParameters::Null) => digest_any(&mut digest, Null.into())?,
+        Some(AlgorithmParameters::Oid(oid)) => digest_any(&mut digest, Any::from(&oid))?,
+        None => (),
+    }
+
+    // `BIT STRING` contents are prefixed with the number of unused bits
+    digest_header(&mut digest, Tag::BitString, (public_key.len() + 1u16)?)?;
+    digest.update([0]);
+    digest.update(public_key.as_bytes());
+
+    Ok(digest.finalize())
+}
+
+/// Digest the encoding of an [`Any`] value.
+fn digest_any<D: Digest>(digest: &mut D, any: Any<'_>) -> Result<()> {
+    digest_header(digest, any.tag(), any.len())?;
+    digest.update(any.as_bytes());
+    Ok(())
+}
+
+/// Digest the encoding of a [`Header`].
+fn digest_header<D: Digest>(digest: &mut D, tag: Tag, length: Length) -> Result<()> {
+    // Tags are encoded as a single byte, and lengths as at most 3 bytes
+    let mut buf = [0u8; 4];
+    let mut encoder = Encoder::new(&mut buf);
+    encoder.encode(&Header::new(tag, length)?)?;
+    digest.update(encoder.finish()?);
+    Ok(())
+}
@@ -3,27 +3,71 @@
 //!
 //! Described in [RFC 5280 Section 4.1].
 //!
+//! # Owned documents
+//!
+//! When the `alloc` feature is enabled, [`SpkiDocument`] provides heap-backed
+//! storage for a serialized [`SubjectPublicKeyInfo`], which can be read from
+//! and written to files with the `std` feature. The `pem` feature adds
+//! support for the "PEM encoding" format described in [RFC 7468], i.e.
+//! `-----BEGIN PUBLIC KEY-----`.
+//!
+//! # Fingerprints
+//!
+//! When the `fingerprint` feature is enabled,
+//! [`SubjectPublicKeyInfo::fingerprint`] computes SHA-1, SHA-256 or SHA-384
+//! fingerprints over the DER encoding of a public key, which can be output
+//! as hex, colon-separated hex, or a Base64 `pin-sha256` as used by
+//! [HPKP (RFC 7469)] and curl's `--pinnedpubkey`. Fingerprints are computed
+//! without a heap, while the methods returning a `String` require the `alloc`
+//! feature.
+//!
 //! # Minimum Supported Rust Version
 //!
 //! This crate requires **Rust 1.47** at a minimum.
 //!
 //! [X.509]: https://en.wikipedia.org/wiki/X.509
 //! [RFC 5280 Section 4.1]: https://tools.ietf.org/html/rfc5280#section-4.1
+//! [RFC 7468]: https://tools.ietf.org/html/rfc7468
+//! [HPKP (RFC 7469)]: https://tools.ietf.org/html/rfc7469
 
 #![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
     html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
-    html_root_url = "https://docs.rs/spki/0.2.1"
+    html_root_url = "https://docs.rs/spki/0.3.0"
 )]
 #![forbid(unsafe_code)]
 #![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
 
+#[cfg(all(feature = "alloc", feature = "fingerprint"))]
+extern crate alloc;
+
 mod algorithm;
+#[cfg(feature = "alloc")]
+mod document;
+mod ec_parameters;
+mod ec_point;
+#[cfg(feature = "fingerprint")]
+mod fingerprint;
+mod public_key_algorithm;
 mod spki;
 
 pub use crate::{
     algorithm::{AlgorithmIdentifier, AlgorithmParameters},
+    ec_parameters::{EcParameters, ExplicitCurvePolicy, SpecifiedCurve},
+    ec_point::{EcPoint, PointFormat},
+    public_key_algorithm::{
+        NamedCurve, PublicKeyAlgorithm, EC_PUBLIC_KEY_OID, ED25519_OID, ED448_OID, RSASSA_PSS_OID,
+        RSA_ENCRYPTION_OID, SECP256K1_OID, SECP256R1_OID, SECP384R1_OID, SECP521R1_OID, X25519_OID,
+        X448_OID,
+    },
     spki::SubjectPublicKeyInfo,
 };
 pub use der::{self, ObjectIdentifier};
+
+#[cfg(feature = "alloc")]
+pub use crate::document::SpkiDocument;
+
+#[cfg(feature = "fingerprint")]
+pub use crate::fingerprint::{Fingerprint, FingerprintAlgorithm};
@@ -0,0 +1,265 @@
+//! Registry of well-known public key algorithms.
+
+use crate::{AlgorithmIdentifier, AlgorithmParameters};
+use core::convert::{TryFrom, TryInto};
+use der::{
+    oid_map, Any, Encodable, Encoder, Error, ErrorKind, Length, ObjectIdentifier, OidMap, Result,
+    Tag,
+};
+
+/// `rsaEncryption` Object Identifier (OID).
+pub const RSA_ENCRYPTION_OID: ObjectIdentifier = ObjectIdentifier::parse("1.2.840.113549.1.1.1");
+
+/// `id-RSASSA-PSS` Object Identifier (OID).
+pub const RSASSA_PSS_OID: ObjectIdentifier = ObjectIdentifier::parse("1.2.840.113549.1.1.10");
+
+/// `id-ecPublicKey` Object Identifier (OID).
+pub const EC_PUBLIC_KEY_OID: ObjectIdentifier = ObjectIdentifier::parse("1.2.840.10045.2.1");
+
+/// `id-X25519` Object Identifier (OID).
+pub const X25519_OID: ObjectIdentifier = ObjectIdentifier::parse("1.3.101.110");
+
+/// `id-X448` Object Identifier (OID).
+pub const X448_OID: ObjectIdentifier = ObjectIdentifier::parse("1.3.101.111");
+
+/// `id-Ed25519` Object Identifier (OID).
+pub const ED25519_OID: ObjectIdentifier = ObjectIdentifier::parse("1.3.101.112");
+
+/// `id-Ed448` Object Identifier (OID).
+pub const ED448_OID: ObjectIdentifier = ObjectIdentifier::parse("1.3.101.113");
+
+/// `secp256r1` (a.k.a. NIST P-256) Object Identifier (OID).
+pub const SECP256R1_OID: ObjectIdentifier = ObjectIdentifier::parse("1.2.840.10045.3.1.7");
+
+/// `secp384r1` (a.k.a. NIST P-384) Object Identifier (OID).
+pub const SECP384R1_OID: ObjectIdentifier = ObjectIdentifier::parse("1.3.132.0.34");
+
+/// `secp521r1` (a.k.a. NIST P-521) Object Identifier (OID).
+pub const SECP521R1_OID: ObjectIdentifier = ObjectIdentifier::parse("1.3.132.0.35");
+
+/// `secp256k1` Object Identifier (OID).
+pub const SECP256K1_OID: ObjectIdentifier = ObjectIdentifier::parse("1.3.132.0.10");
+
+/// Well-known public key algorithms, i.e. the `algorithm` of a
+/// [`SubjectPublicKeyInfo`][`crate::SubjectPublicKeyInfo`].
+///
+/// Converting an [`AlgorithmIdentifier`] into a [`PublicKeyAlgorithm`]
+/// strictly validates its `parameters` as specified for each algorithm:
+///
+/// - [RFC 3279 Section 2.3.1]: `rsaEncryption` parameters MUST be `NULL`
+/// - [RFC 4055 Section 1.2]: `id-RSASSA-PSS` parameters MUST be absent or
+///   `RSASSA-PSS-params`
+/// - [RFC 5480 Section 2.1.1]: `id-ecPublicKey` parameters MUST be a
+///   `namedCurve` OID (see [`EcParameters`][`crate::EcParameters`] for
+///   handling the other `ECParameters` forms)
+/// - [RFC 8410 Section 3]: parameters for X25519, X448, Ed25519 and Ed448
+///   MUST be absent
+///
+/// [RFC 3279 Section 2.3.1]: https://tools.ietf.org/html/rfc3279#section-2.3.1
+/// [RFC 4055 Section 1.2]: https://tools.ietf.org/html/rfc4055#section-1.2
+/// [RFC 5480 Section 2.1.1]: https://tools.ietf.org/html/rfc5480#section-2.1.1
+/// [RFC 8410 Section 3]: https://tools.ietf.org/html/rfc8410#section-3
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum PublicKeyAlgorithm<'a> {
+    /// RSA (`rsaEncryption`).
+    Rsa,
+
+    /// RSASSA-PSS (`id-RSASSA-PSS`), with the DER-encoded
+    /// `RSASSA-PSS-params` restricting how the key may be used (if any).
+    RsaPss(Option<Any<'a>>),
+
+    /// Elliptic curve (`id-ecPublicKey`) key on the given named curve.
+    Ec(NamedCurve),
+
+    /// Ed25519 (`id-Ed25519`).
+    Ed25519,
+
+    /// Ed448 (`id-Ed448`).
+    Ed448,
+
+    /// X25519 (`id-X25519`).
+    X25519,
+
+    /// X448 (`id-X448`).
+    X448,
+}
+
+impl<'a> PublicKeyAlgorithm<'a> {
+    /// Get the [`ObjectIdentifier`] (a.k.a OID) for this algorithm.
+    pub fn oid(self) -> ObjectIdentifier {
+        match self {
+            Self::Rsa => RSA_ENCRYPTION_OID,
+            Self::RsaPss(_) => RSASSA_PSS_OID,
+            Self::Ec(_) => EC_PUBLIC_KEY_OID,
+            Self::Ed25519 => ED25519_OID,
+            Self::Ed448 => ED448_OID,
+            Self::X25519 => X25519_OID,
+            Self::X448 => X448_OID,
+        }
+    }
+
+    /// Get the `parameters` of the [`AlgorithmIdentifier`] for this
+    /// algorithm.
+    pub fn parameters(self) -> Option<AlgorithmParameters<'a>> {
+        match self {
+            Self::Rsa => Some(AlgorithmParameters::Null),
+            Self::RsaPss(params) => params.map(AlgorithmParameters::Any),
+            Self::Ec(curve) => Some(AlgorithmParameters::Oid(curve.oid())),
+            Self::Ed25519 | Self::Ed448 | Self::X25519 | Self::X448 => None,
+        }
+    }
+
+    /// Get the [`AlgorithmIdentifier`] for this algorithm.
+    pub fn algorithm_identifier(self) -> AlgorithmIdentifier<'a> {
+        AlgorithmIdentifier {
+            oid: self.oid(),
+            parameters: self.parameters(),
+        }
+    }
+}
+
+impl<'a> TryFrom<AlgorithmIdentifier<'a>> for PublicKeyAlgorithm<'a> {
+    type Error = Error;
+
+    fn try_from(alg: AlgorithmIdentifier<'a>) -> Result<Self> {
+        let params = alg.parameters;
+
+        match alg.oid {
+            RSA_ENCRYPTION_OID => match params {
+                Some(AlgorithmParameters::Null) => Ok(Self::Rsa),
+                other => Err(unexpected_parameters(other, Tag::Null)),
+            },
+            RSASSA_PSS_OID => match params {
+                None => Ok(Self::RsaPss(None)),
+                Some(AlgorithmParameters::Any(any)) if any.tag() == Tag::Sequence => {
+                    Ok(Self::RsaPss(Some(any)))
+                }
+                other => Err(unexpected_parameters(other, Tag::Sequence)),
+            },
+            EC_PUBLIC_KEY_OID => match params {
+                Some(AlgorithmParameters::Oid(oid)) => oid.try_into().map(Self::Ec),
+                other => Err(unexpected_parameters(other, Tag::ObjectIdentifier)),
+            },
+            ED25519_OID => no_parameters(params, Self::Ed25519),
+            ED448_OID => no_parameters(params, Self::Ed448),
+            X25519_OID => no_parameters(params, Self::X25519),
+            X448_OID => no_parameters(params, Self::X448),
+            oid => Err(ErrorKind::UnknownOid { oid }.into()),
+        }
+    }
+}
+
+impl<'a> From<PublicKeyAlgorithm<'a>> for AlgorithmIdentifier<'a> {
+    fn from(alg: PublicKeyAlgorithm<'a>) -> AlgorithmIdentifier<'a> {
+        alg.algorithm_identifier()
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for PublicKeyAlgorithm<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        AlgorithmIdentifier::try_from(any)?.try_into()
+    }
+}
+
+impl<'a> Encodable for PublicKeyAlgorithm<'a> {
+    fn encoded_len(&self) -> Result<Length> {
+        self.algorithm_identifier().encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.algorithm_identifier().encode(encoder)
+    }
+}
+
+/// Named elliptic curves supported by [`PublicKeyAlgorithm::Ec`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum NamedCurve {
+    /// NIST P-256 (`secp256r1`).
+    P256,
+
+    /// NIST P-384 (`secp384r1`).
+    P384,
+
+    /// NIST P-521 (`secp521r1`).
+    P521,
+
+    /// `secp256k1`.
+    Secp256k1,
+}
+
+oid_map! {
+    /// Named curves indexed by OID.
+    const NAMED_CURVES: OidMap<NamedCurve> = {
+        SECP256R1_OID => NamedCurve::P256,
+        SECP384R1_OID => NamedCurve::P384,
+        SECP521R1_OID => NamedCurve::P521,
+        SECP256K1_OID => NamedCurve::Secp256k1,
+    };
+}
+
+impl NamedCurve {
+    /// Get the [`ObjectIdentifier`] (a.k.a OID) for this curve.
+    pub fn oid(self) -> ObjectIdentifier {
+        match self {
+            Self::P256 => SECP256R1_OID,
+            Self::P384 => SECP384R1_OID,
+            Self::P521 => SECP521R1_OID,
+            Self::Secp256k1 => SECP256K1_OID,
+        }
+    }
+
+    /// Size of a field element (i.e. a point coordinate) of this curve in
+    /// bytes.
+    pub fn field_size(self) -> usize {
+        match self {
+            Self::P256 | Self::Secp256k1 => 32,
+            Self::P384 => 48,
+            Self::P521 => 66,
+        }
+    }
+}
+
+impl TryFrom<ObjectIdentifier> for NamedCurve {
+    type Error = Error;
+
+    fn try_from(oid: ObjectIdentifier) -> Result<Self> {
+        NAMED_CURVES
+            .get(&oid)
+            .copied()
+            .ok_or_else(|| ErrorKind::UnknownOid { oid }.into())
+    }
+}
+
+/// Ensure the `parameters` of an algorithm which doesn't have any are absent.
+fn no_parameters<'a>(
+    params: Option<AlgorithmParameters<'a>>,
+    alg: PublicKeyAlgorithm<'a>,
+) -> Result<PublicKeyAlgorithm<'a>> {
+    match params {
+        None => Ok(alg),
+        Some(params) => Err(ErrorKind::UnexpectedTag {
+            expected: None,
+            actual: params.tag(),
+        }
+        .into()),
+    }
+}
+
+/// Error for `parameters` which are absent or of the wrong type.
+///
+/// Absent `parameters` are reported as an invalid `AlgorithmIdentifier`
+/// value, as there is no tag to report.
+fn unexpected_parameters(params: Option<AlgorithmParameters<'_>>, expected: Tag) -> Error {
+    match params {
+        None => ErrorKind::Value { tag: Tag::Sequence }.into(),
+        Some(params) => ErrorKind::UnexpectedTag {
+            expected: Some(expected),
+            actual: params.tag(),
+        }
+        .into(),
+    }
+}
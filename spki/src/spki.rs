@@ -1,14 +1,20 @@
 //! X.509 `SubjectPublicKeyInfo`
 
-use crate::AlgorithmIdentifier;
+use crate::{AlgorithmIdentifier, EcParameters, EcPoint, NamedCurve, EC_PUBLIC_KEY_OID};
 use core::convert::TryFrom;
-use der::{Decodable, Encodable, Error, Message, Result};
+use der::{
+    BitString, Borrowable, Decodable, Encodable, Error, ErrorKind, Message, PemLabel, Result, Tag,
+};
 
 /// X.509 `SubjectPublicKeyInfo` (SPKI).
 ///
 /// ASN.1 structure containing an [`AlgorithmIdentifier`] and public key
 /// data in an algorithm specific format.
 ///
+/// When decoding or encoding, the public key is checked to be a whole number
+/// of bytes, and `id-ecPublicKey` keys on a known [`NamedCurve`] are checked
+/// to contain a well-formed [`EcPoint`].
+///
 /// Described in RFC 5280 Section 4.1.2.7:
 /// <https://tools.ietf.org/html/rfc5280#section-4.1.2.7>
 ///
@@ -23,7 +29,66 @@ pub struct SubjectPublicKeyInfo<'a> {
     pub algorithm: AlgorithmIdentifier<'a>,
 
     /// Public key data
-    pub subject_public_key: &'a [u8],
+    pub subject_public_key: BitString<'a>,
+}
+
+impl<'a> SubjectPublicKeyInfo<'a> {
+    /// Get the elliptic curve point of an `id-ecPublicKey` public key on a
+    /// known [`NamedCurve`].
+    pub fn ec_point(&self) -> Result<EcPoint<'a>> {
+        if self.algorithm.oid != EC_PUBLIC_KEY_OID {
+            return Err(ErrorKind::UnknownOid {
+                oid: self.algorithm.oid,
+            }
+            .into());
+        }
+
+        let params = self
+            .algorithm
+            .parameters
+            .ok_or(ErrorKind::Value { tag: Tag::Sequence })?;
+
+        let curve = match EcParameters::try_from(params)? {
+            EcParameters::NamedCurve(oid) => NamedCurve::try_from(oid)?,
+            EcParameters::ImplicitCurve => {
+                return Err(ErrorKind::UnexpectedTag {
+                    expected: Some(Tag::ObjectIdentifier),
+                    actual: Tag::Null,
+                }
+                .into())
+            }
+            EcParameters::SpecifiedCurve(curve) => curve.named_curve(),
+        };
+
+        EcPoint::new(self.subject_public_key.as_bytes(), curve)
+    }
+
+    /// Reject public keys with unused bits, and malformed EC points on known
+    /// curves.
+    ///
+    /// The contents of other keys (including EC keys with parameters which
+    /// don't identify a known curve) aren't checked.
+    pub(crate) fn check_public_key(&self) -> Result<()> {
+        if self.subject_public_key.unused_bits() != 0 {
+            return Err(ErrorKind::Value {
+                tag: Tag::BitString,
+            }
+            .into());
+        }
+
+        let curve = self
+            .algorithm
+            .parameters
+            .filter(|_| self.algorithm.oid == EC_PUBLIC_KEY_OID)
+            .and_then(|params| EcParameters::try_from(params).ok())
+            .and_then(EcParameters::named_curve);
+
+        if let Some(curve) = curve {
+            EcPoint::new(self.subject_public_key.as_bytes(), curve)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a> TryFrom<&'a [u8]> for SubjectPublicKeyInfo<'a> {
@@ -38,12 +103,15 @@ impl<'a> TryFrom<der::Any<'a>> for SubjectPublicKeyInfo<'a> {
     type Error = Error;
 
     fn try_from(any: der::Any<'a>) -> Result<SubjectPublicKeyInfo<'a>> {
-        any.sequence(|decoder| {
+        let spki = any.sequence(|decoder| {
             Ok(Self {
                 algorithm: decoder.decode()?,
-                subject_public_key: decoder.bit_string()?.as_bytes(),
+                subject_public_key: decoder.bit_string()?,
             })
-        })
+        })?;
+
+        spki.check_public_key()?;
+        Ok(spki)
     }
 }
 
@@ -52,9 +120,16 @@ impl<'a> Message<'a> for SubjectPublicKeyInfo<'a> {
     where
         F: FnOnce(&[&dyn Encodable]) -> Result<T>,
     {
-        f(&[
-            &self.algorithm,
-            &der::BitString::new(self.subject_public_key)?,
-        ])
+        // Ensure the encoding can be decoded again
+        self.check_public_key()?;
+        f(&[&self.algorithm, &self.subject_public_key])
     }
 }
+
+impl<'a> Borrowable<'a> for SubjectPublicKeyInfo<'static> {
+    type Borrowed = SubjectPublicKeyInfo<'a>;
+}
+
+impl PemLabel for SubjectPublicKeyInfo<'_> {
+    const TYPE_LABEL: &'static str = "PUBLIC KEY";
+}
@@ -0,0 +1,91 @@
+//! `SpkiDocument` tests
+
+#![cfg(feature = "alloc")]
+
+use core::convert::TryFrom;
+use der::BitString;
+use spki::{
+    AlgorithmIdentifier, AlgorithmParameters, SpkiDocument, SubjectPublicKeyInfo, ED25519_OID,
+};
+
+/// Ed25519 `SubjectPublicKeyInfo` encoded as ASN.1 DER
+const ED25519_DER_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-pub.der");
+
+/// Ed25519 public key encoded as PEM
+#[cfg(feature = "pem")]
+const ED25519_PEM_EXAMPLE: &str = include_str!("examples/ed25519-pub.pem");
+
+/// Elliptic Curve (P-256) public key encoded as PEM
+#[cfg(feature = "pem")]
+const EC_P256_PEM_EXAMPLE: &str = include_str!("examples/p256-pub.pem");
+
+#[test]
+fn decode_ed25519_der() {
+    let doc = SpkiDocument::from_der(ED25519_DER_EXAMPLE).unwrap();
+    assert_eq!(doc.as_der(), ED25519_DER_EXAMPLE);
+
+    let spki = doc.decode();
+    assert_eq!(spki.algorithm.oid, ED25519_OID);
+    assert_eq!(spki.subject_public_key.as_bytes().len(), 32);
+}
+
+#[test]
+fn encode_ed25519_der() {
+    let spki = SubjectPublicKeyInfo::try_from(ED25519_DER_EXAMPLE).unwrap();
+    let doc = SpkiDocument::from_msg(&spki).unwrap();
+    assert_eq!(doc.as_der(), ED25519_DER_EXAMPLE);
+}
+
+#[test]
+fn reject_malformed_der() {
+    assert!(SpkiDocument::from_der(&ED25519_DER_EXAMPLE[..20]).is_err());
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn pem_round_trip() {
+    for &pem in &[ED25519_PEM_EXAMPLE, EC_P256_PEM_EXAMPLE] {
+        let doc: SpkiDocument = pem.parse().unwrap();
+        assert_eq!(doc.to_pem(), pem);
+    }
+
+    let doc = SpkiDocument::from_pem(ED25519_PEM_EXAMPLE).unwrap();
+    assert_eq!(doc.as_der(), ED25519_DER_EXAMPLE);
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn reject_private_key_pem() {
+    let pem = ED25519_PEM_EXAMPLE.replace("PUBLIC KEY", "PRIVATE KEY");
+    assert!(SpkiDocument::from_pem(&pem).is_err());
+}
+
+#[test]
+#[cfg(all(feature = "pem", feature = "std"))]
+fn file_round_trip() {
+    let doc = SpkiDocument::from_der(ED25519_DER_EXAMPLE).unwrap();
+    let dir = std::env::temp_dir();
+
+    let der_path = dir.join("spki-document-test.der");
+    doc.write_der_file(&der_path).unwrap();
+    assert_eq!(SpkiDocument::read_der_file(&der_path).unwrap(), doc);
+    std::fs::remove_file(&der_path).unwrap();
+
+    let pem_path = dir.join("spki-document-test.pem");
+    doc.write_pem_file(&pem_path).unwrap();
+    assert_eq!(SpkiDocument::read_pem_file(&pem_path).unwrap(), doc);
+    std::fs::remove_file(&pem_path).unwrap();
+}
+
+#[test]
+fn reject_malformed_ec_point() {
+    let spki = SubjectPublicKeyInfo {
+        algorithm: AlgorithmIdentifier {
+            oid: spki::EC_PUBLIC_KEY_OID,
+            parameters: Some(AlgorithmParameters::Oid(spki::SECP256R1_OID)),
+        },
+        subject_public_key: BitString::new(&[0x04; 12]).unwrap(),
+    };
+
+    assert!(SpkiDocument::from_msg(&spki).is_err());
+}
@@ -0,0 +1,173 @@
+//! `EcParameters` tests
+
+use core::convert::TryFrom;
+use der::{Any, Decodable, Encodable, ErrorKind, Tag};
+use spki::{
+    AlgorithmIdentifier, AlgorithmParameters, EcParameters, ExplicitCurvePolicy, NamedCurve,
+    SubjectPublicKeyInfo, SECP256R1_OID,
+};
+
+/// Elliptic Curve (P-256) `SubjectPublicKeyInfo` encoded as ASN.1 DER
+const EC_P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-pub.der");
+
+/// Explicit `SpecifiedECDomain` examples generated by OpenSSL, with the
+/// generator in uncompressed and compressed form
+const EXPLICIT_EXAMPLES: &[(NamedCurve, &[u8])] = &[
+    (
+        NamedCurve::P256,
+        include_bytes!("examples/p256-explicit.der"),
+    ),
+    (
+        NamedCurve::P256,
+        include_bytes!("examples/p256-explicit-compressed.der"),
+    ),
+    (
+        NamedCurve::P384,
+        include_bytes!("examples/p384-explicit.der"),
+    ),
+    (
+        NamedCurve::P384,
+        include_bytes!("examples/p384-explicit-compressed.der"),
+    ),
+    (
+        NamedCurve::P521,
+        include_bytes!("examples/p521-explicit.der"),
+    ),
+    (
+        NamedCurve::P521,
+        include_bytes!("examples/p521-explicit-compressed.der"),
+    ),
+    (
+        NamedCurve::Secp256k1,
+        include_bytes!("examples/secp256k1-explicit.der"),
+    ),
+    (
+        NamedCurve::Secp256k1,
+        include_bytes!("examples/secp256k1-explicit-compressed.der"),
+    ),
+];
+
+#[test]
+fn decode_named_curve() {
+    let spki = SubjectPublicKeyInfo::try_from(EC_P256_DER_EXAMPLE).unwrap();
+    let params = EcParameters::try_from(spki.algorithm.parameters.unwrap()).unwrap();
+
+    assert_eq!(params, EcParameters::NamedCurve(SECP256R1_OID));
+    assert_eq!(params, NamedCurve::P256.into());
+    assert_eq!(params.named_curve(), Some(NamedCurve::P256));
+    assert!(!params.is_specified_curve());
+}
+
+#[test]
+fn decode_implicit_curve() {
+    let params = EcParameters::from_bytes(&[0x05, 0x00]).unwrap();
+    assert_eq!(params, EcParameters::ImplicitCurve);
+    assert_eq!(params.named_curve(), None);
+}
+
+#[test]
+fn decode_specified_curves() {
+    for &(curve, der) in EXPLICIT_EXAMPLES {
+        let params = EcParameters::from_bytes(der).unwrap();
+        assert!(params.is_specified_curve());
+        assert_eq!(params.named_curve(), Some(curve));
+
+        let mut buf = [0u8; 512];
+        assert_eq!(params.encode_to_slice(&mut buf).unwrap(), der);
+    }
+}
+
+#[test]
+fn specified_curve_in_algorithm_identifier() {
+    let domain = Any::from_bytes(EXPLICIT_EXAMPLES[0].1).unwrap();
+    let alg = AlgorithmIdentifier {
+        oid: spki::EC_PUBLIC_KEY_OID,
+        parameters: Some(AlgorithmParameters::Any(domain)),
+    };
+
+    let params = EcParameters::try_from(alg.parameters.unwrap()).unwrap();
+    assert_eq!(params.named_curve(), Some(NamedCurve::P256));
+    assert_eq!(AlgorithmParameters::from(params), alg.parameters.unwrap());
+}
+
+#[test]
+fn reject_specified_curve_by_policy() {
+    let domain = Any::from_bytes(EXPLICIT_EXAMPLES[0].1).unwrap();
+    let err = EcParameters::from_parameters(
+        AlgorithmParameters::Any(domain),
+        ExplicitCurvePolicy::Reject,
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        err.kind(),
+        ErrorKind::UnexpectedTag {
+            expected: Some(Tag::ObjectIdentifier),
+            actual: Tag::Sequence
+        }
+    );
+
+    // Named curves are still accepted
+    let params = EcParameters::from_parameters(
+        AlgorithmParameters::Oid(SECP256R1_OID),
+        ExplicitCurvePolicy::Reject,
+    )
+    .unwrap();
+
+    assert_eq!(params.named_curve(), Some(NamedCurve::P256));
+}
+
+#[test]
+fn reject_unknown_specified_curve() {
+    // Tamper with the last byte of the order, which precedes the cofactor
+    let mut der = EXPLICIT_EXAMPLES[0].1.to_vec();
+    let order_end = der.len() - 4;
+    der[order_end] ^= 1;
+
+    let err = EcParameters::from_bytes(&der).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Sequence });
+}
+
+#[test]
+fn reject_wrong_cofactor() {
+    let mut der = EXPLICIT_EXAMPLES[0].1.to_vec();
+    *der.last_mut().unwrap() = 2;
+
+    let err = EcParameters::from_bytes(&der).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Sequence });
+}
+
+#[test]
+fn decode_hybrid_generator() {
+    // Replace the uncompressed `base` point with a hybrid one, which is
+    // tagged with the parity of the (odd) Y coordinate of the P-256 generator
+    let mut der = EXPLICIT_EXAMPLES[0].1.to_vec();
+    let base = der
+        .windows(3)
+        .position(|window| window == [0x04, 0x41, 0x04])
+        .unwrap()
+        + 2;
+
+    der[base] = 0x07;
+    let params = EcParameters::from_bytes(&der).unwrap();
+    assert_eq!(params.named_curve(), Some(NamedCurve::P256));
+
+    der[base] = 0x06;
+    let err = EcParameters::from_bytes(&der).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Sequence });
+}
+
+#[test]
+fn reject_non_minimal_integer() {
+    // Encode the trailing cofactor as `02 02 00 01` instead of `02 01 01`
+    let mut der = EXPLICIT_EXAMPLES[0].1.to_vec();
+    assert_eq!(der[..3], [0x30, 0x81, 0xf7]);
+    assert_eq!(der[der.len() - 3..], [0x02, 0x01, 0x01]);
+
+    der[2] += 1;
+    der.truncate(der.len() - 2);
+    der.extend_from_slice(&[0x02, 0x00, 0x01]);
+
+    let err = EcParameters::from_bytes(&der).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Integer });
+}
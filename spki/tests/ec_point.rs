@@ -0,0 +1,260 @@
+//! `EcPoint` tests
+
+use core::convert::TryFrom;
+use der::{BitString, Encodable, ErrorKind, Result, Tag};
+use hex_literal::hex;
+use spki::{
+    AlgorithmIdentifier, AlgorithmParameters, EcPoint, NamedCurve, PointFormat,
+    SubjectPublicKeyInfo,
+};
+
+/// Elliptic Curve (P-256) `SubjectPublicKeyInfo` encoded as ASN.1 DER
+const EC_P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-pub.der");
+
+/// Ed25519 `SubjectPublicKeyInfo` encoded as ASN.1 DER
+const ED25519_DER_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-pub.der");
+
+/// X coordinate of the P-256 example public key
+const EC_P256_X: [u8; 32] =
+    hex!("1CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7F");
+
+/// Y coordinate of the P-256 example public key
+const EC_P256_Y: [u8; 32] =
+    hex!("C481A44492304D7EF66AC117BEFE83A8D08F155F2B52F9F618DD447029048E0F");
+
+/// Encode a `SubjectPublicKeyInfo` for an EC key with the given curve and
+/// point, then decode it again.
+fn round_trip(curve_oid: &str, point: &[u8]) -> Result<()> {
+    let spki = SubjectPublicKeyInfo {
+        algorithm: AlgorithmIdentifier {
+            oid: spki::EC_PUBLIC_KEY_OID,
+            parameters: Some(AlgorithmParameters::Oid(curve_oid.parse().unwrap())),
+        },
+        subject_public_key: BitString::new(point)?,
+    };
+
+    let mut buf = [0u8; 128];
+    let der = spki.encode_to_slice(&mut buf)?;
+    assert_eq!(SubjectPublicKeyInfo::try_from(der)?, spki);
+    Ok(())
+}
+
+/// Concatenate a point tag with its coordinates.
+fn point(tag: u8, coords: &[&[u8]]) -> Vec<u8> {
+    let mut point = vec![tag];
+
+    for coord in coords {
+        point.extend_from_slice(coord);
+    }
+
+    point
+}
+
+#[test]
+fn decode_uncompressed() {
+    let spki = SubjectPublicKeyInfo::try_from(EC_P256_DER_EXAMPLE).unwrap();
+    let point = spki.ec_point().unwrap();
+
+    assert_eq!(point.curve(), NamedCurve::P256);
+    assert_eq!(point.format(), PointFormat::Uncompressed);
+    assert!(!point.is_compressed());
+    assert_eq!(point.x(), EC_P256_X);
+    assert_eq!(point.y(), Some(&EC_P256_Y[..]));
+    assert!(point.y_is_odd());
+}
+
+#[test]
+fn decode_compressed() {
+    let bytes = point(0x03, &[&EC_P256_X]);
+    round_trip("1.2.840.10045.3.1.7", &bytes).unwrap();
+
+    let point = EcPoint::new(&bytes, NamedCurve::P256).unwrap();
+    assert_eq!(point.format(), PointFormat::Compressed);
+    assert_eq!(point.x(), EC_P256_X);
+    assert_eq!(point.y(), None);
+    assert!(point.y_is_odd());
+}
+
+#[test]
+fn decode_hybrid() {
+    let bytes = point(0x07, &[&EC_P256_X, &EC_P256_Y]);
+    round_trip("1.2.840.10045.3.1.7", &bytes).unwrap();
+
+    let point = EcPoint::new(&bytes, NamedCurve::P256).unwrap();
+    assert_eq!(point.format(), PointFormat::Hybrid);
+    assert_eq!(point.y(), Some(&EC_P256_Y[..]));
+}
+
+#[test]
+fn reject_hybrid_with_wrong_parity() {
+    let bytes = point(0x06, &[&EC_P256_X, &EC_P256_Y]);
+    let err = round_trip("1.2.840.10045.3.1.7", &bytes).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::Value {
+            tag: Tag::BitString
+        }
+    );
+}
+
+#[test]
+fn reject_wrong_length() {
+    // Uncompressed P-256 point on P-384
+    let err = round_trip("1.3.132.0.34", &point(0x04, &[&EC_P256_X, &EC_P256_Y])).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::Length {
+            tag: Tag::BitString
+        }
+    );
+
+    // Compressed tag with both coordinates
+    let err = round_trip(
+        "1.2.840.10045.3.1.7",
+        &point(0x02, &[&EC_P256_X, &EC_P256_Y]),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::Length {
+            tag: Tag::BitString
+        }
+    );
+
+    let err = round_trip("1.2.840.10045.3.1.7", &[]).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::Length {
+            tag: Tag::BitString
+        }
+    );
+}
+
+#[test]
+fn reject_invalid_tag() {
+    for &tag in &[0x00, 0x01, 0x05, 0x08] {
+        let err = round_trip(
+            "1.2.840.10045.3.1.7",
+            &point(tag, &[&EC_P256_X, &EC_P256_Y]),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Value {
+                tag: Tag::BitString
+            }
+        );
+    }
+}
+
+#[test]
+fn reject_malformed_point_when_encoding() {
+    let spki = SubjectPublicKeyInfo {
+        algorithm: AlgorithmIdentifier {
+            oid: spki::EC_PUBLIC_KEY_OID,
+            parameters: Some(AlgorithmParameters::Oid(spki::SECP256R1_OID)),
+        },
+        subject_public_key: BitString::new(&[0x04; 12]).unwrap(),
+    };
+
+    let mut buf = [0u8; 128];
+    let err = spki.encode_to_slice(&mut buf).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::Length {
+            tag: Tag::BitString
+        }
+    );
+    assert!(spki.encoded_len().is_err());
+}
+
+#[test]
+fn unknown_curve_not_validated() {
+    // brainpoolP256r1
+    round_trip("1.3.36.3.3.2.8.1.1.7", &[0x42; 12]).unwrap();
+}
+
+#[test]
+fn reject_unused_bits() {
+    // brainpoolP256r1
+    let mut spki = SubjectPublicKeyInfo {
+        algorithm: AlgorithmIdentifier {
+            oid: spki::EC_PUBLIC_KEY_OID,
+            parameters: Some(AlgorithmParameters::Oid(
+                "1.3.36.3.3.2.8.1.1.7".parse().unwrap(),
+            )),
+        },
+        subject_public_key: BitString::new(&[0x42; 12]).unwrap(),
+    };
+
+    // Mark the least significant bit of the key as unused when decoding...
+    let mut buf = [0u8; 128];
+    let mut der = spki.encode_to_slice(&mut buf).unwrap().to_vec();
+    let unused_bits = der.len() - 13;
+    assert_eq!(der[unused_bits], 0);
+    der[unused_bits] = 1;
+
+    let err = SubjectPublicKeyInfo::try_from(der.as_slice()).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::Value {
+            tag: Tag::BitString
+        }
+    );
+
+    // ...and when encoding
+    spki.subject_public_key = BitString::with_unused_bits(&[0x42; 12], 1).unwrap();
+    let err = spki.encode_to_slice(&mut buf).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::Value {
+            tag: Tag::BitString
+        }
+    );
+}
+
+#[test]
+fn ec_point_of_non_ec_key() {
+    let spki = SubjectPublicKeyInfo::try_from(ED25519_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        spki.ec_point().unwrap_err().kind(),
+        ErrorKind::UnknownOid {
+            oid: spki::ED25519_OID
+        }
+    );
+}
+
+#[test]
+fn ec_point_of_implicit_curve() {
+    let spki = SubjectPublicKeyInfo {
+        algorithm: AlgorithmIdentifier {
+            oid: spki::EC_PUBLIC_KEY_OID,
+            parameters: Some(AlgorithmParameters::Null),
+        },
+        subject_public_key: BitString::new(&[0x04]).unwrap(),
+    };
+
+    assert_eq!(
+        spki.ec_point().unwrap_err().kind(),
+        ErrorKind::UnexpectedTag {
+            expected: Some(Tag::ObjectIdentifier),
+            actual: Tag::Null
+        }
+    );
+}
+
+#[test]
+fn ec_point_without_parameters() {
+    let spki = SubjectPublicKeyInfo {
+        algorithm: AlgorithmIdentifier {
+            oid: spki::EC_PUBLIC_KEY_OID,
+            parameters: None,
+        },
+        subject_public_key: BitString::new(&[0x04]).unwrap(),
+    };
+
+    assert_eq!(
+        spki.ec_point().unwrap_err().kind(),
+        ErrorKind::Value { tag: Tag::Sequence }
+    );
+}
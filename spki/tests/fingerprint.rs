@@ -0,0 +1,131 @@
+//! SPKI fingerprint tests
+
+#![cfg(feature = "fingerprint")]
+
+use core::convert::TryFrom;
+use der::{Any, Decodable, Encodable};
+use hex_literal::hex;
+use spki::{
+    AlgorithmIdentifier, AlgorithmParameters, Fingerprint, FingerprintAlgorithm,
+    SubjectPublicKeyInfo,
+};
+
+/// Elliptic Curve (P-256) `SubjectPublicKeyInfo` encoded as ASN.1 DER
+const EC_P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-pub.der");
+
+/// Ed25519 `SubjectPublicKeyInfo` encoded as ASN.1 DER
+const ED25519_DER_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-pub.der");
+
+/// Explicit P-256 `SpecifiedECDomain` encoded as ASN.1 DER
+const EC_P256_EXPLICIT_PARAMS: &[u8] = include_bytes!("examples/p256-explicit.der");
+
+#[test]
+fn ed25519_fingerprints() {
+    let spki = SubjectPublicKeyInfo::try_from(ED25519_DER_EXAMPLE).unwrap();
+
+    let sha1 = spki.fingerprint(FingerprintAlgorithm::Sha1).unwrap();
+    assert_eq!(sha1.algorithm(), FingerprintAlgorithm::Sha1);
+    assert_eq!(
+        sha1.as_bytes(),
+        hex!("63c1882056add5c33b7bb8086a24ee0562b7ed60")
+    );
+
+    let sha256 = spki.fingerprint(FingerprintAlgorithm::Sha256).unwrap();
+    assert_eq!(
+        sha256.as_bytes(),
+        hex!("55dd4c74b0e48534e2f4e173ceceb50df8f27a7ac2aa8991cc7ae914e030bced")
+    );
+
+    let sha384 = spki.fingerprint(FingerprintAlgorithm::Sha384).unwrap();
+    assert_eq!(
+        sha384.as_bytes(),
+        &hex!("4aa6493529ae8c229150648cac782f39116817f688f7b7dbfbe15dc8c635d45d3175ceeb1838412150e78f4de5580571")[..]
+    );
+}
+
+#[test]
+fn fingerprints_match_digest_of_encoding() {
+    let p256 = SubjectPublicKeyInfo::try_from(EC_P256_DER_EXAMPLE).unwrap();
+    let ed25519 = SubjectPublicKeyInfo::try_from(ED25519_DER_EXAMPLE).unwrap();
+
+    // Explicit curve parameters, which have a multi-byte length
+    let p256_explicit = SubjectPublicKeyInfo {
+        algorithm: AlgorithmIdentifier {
+            oid: spki::EC_PUBLIC_KEY_OID,
+            parameters: Some(AlgorithmParameters::Any(
+                Any::from_bytes(EC_P256_EXPLICIT_PARAMS).unwrap(),
+            )),
+        },
+        subject_public_key: p256.subject_public_key,
+    };
+
+    for spki in &[p256, ed25519, p256_explicit] {
+        let mut buf = [0u8; 1024];
+        let der = spki.encode_to_slice(&mut buf).unwrap();
+
+        for &algorithm in &[
+            FingerprintAlgorithm::Sha1,
+            FingerprintAlgorithm::Sha256,
+            FingerprintAlgorithm::Sha384,
+        ] {
+            assert_eq!(
+                spki.fingerprint(algorithm).unwrap(),
+                Fingerprint::new(algorithm, der)
+            );
+        }
+    }
+}
+
+#[test]
+fn encode_base64() {
+    let spki = SubjectPublicKeyInfo::try_from(EC_P256_DER_EXAMPLE).unwrap();
+    let fingerprint = spki.fingerprint(FingerprintAlgorithm::Sha256).unwrap();
+
+    let mut buf = [0u8; 64];
+    assert_eq!(
+        fingerprint.encode_base64(&mut buf).unwrap(),
+        "uZaNVu2NaqP7Q7FfoB41XXo6AgOxQIs/0nM2N8TRZCw="
+    );
+    assert!(fingerprint.encode_base64(&mut buf[..43]).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn ec_p256_formats() {
+    let spki = SubjectPublicKeyInfo::try_from(EC_P256_DER_EXAMPLE).unwrap();
+    let fingerprint = spki.fingerprint(FingerprintAlgorithm::Sha1).unwrap();
+
+    assert_eq!(
+        fingerprint.to_hex(),
+        "f8cb08271cb0e3197ea9df1781723551e803919e"
+    );
+    assert_eq!(
+        format!("{:X}", fingerprint),
+        "F8CB08271CB0E3197EA9DF1781723551E803919E"
+    );
+    assert_eq!(
+        fingerprint.to_colon_hex(),
+        "F8:CB:08:27:1C:B0:E3:19:7E:A9:DF:17:81:72:35:51:E8:03:91:9E"
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn ec_p256_pins() {
+    let spki = SubjectPublicKeyInfo::try_from(EC_P256_DER_EXAMPLE).unwrap();
+    let fingerprint = spki.fingerprint(FingerprintAlgorithm::Sha256).unwrap();
+
+    assert_eq!(
+        fingerprint.to_base64(),
+        "uZaNVu2NaqP7Q7FfoB41XXo6AgOxQIs/0nM2N8TRZCw="
+    );
+    assert_eq!(
+        fingerprint.to_pin().unwrap(),
+        "sha256//uZaNVu2NaqP7Q7FfoB41XXo6AgOxQIs/0nM2N8TRZCw="
+    );
+    assert_eq!(spki.pin_sha256().unwrap(), fingerprint.to_base64());
+
+    // Pins are only defined for SHA-256
+    let sha384 = spki.fingerprint(FingerprintAlgorithm::Sha384).unwrap();
+    assert_eq!(sha384.to_pin(), None);
+}
@@ -0,0 +1,106 @@
+//! `PublicKeyAlgorithm` tests
+
+use core::convert::{TryFrom, TryInto};
+use der::{Any, Decodable, Encodable, ErrorKind, Tag};
+use spki::{AlgorithmIdentifier, AlgorithmParameters, NamedCurve, PublicKeyAlgorithm};
+
+/// Round trip the given algorithm through its DER encoding.
+fn round_trip(alg: PublicKeyAlgorithm<'_>) {
+    let mut buf = [0u8; 64];
+    let der = alg.encode_to_slice(&mut buf).unwrap();
+    let decoded = AlgorithmIdentifier::from_bytes(der).unwrap();
+    assert_eq!(decoded, alg.algorithm_identifier());
+    assert_eq!(PublicKeyAlgorithm::try_from(decoded).unwrap(), alg);
+}
+
+#[test]
+fn round_trip_all() {
+    round_trip(PublicKeyAlgorithm::Rsa);
+    round_trip(PublicKeyAlgorithm::RsaPss(None));
+    round_trip(PublicKeyAlgorithm::Ec(NamedCurve::P256));
+    round_trip(PublicKeyAlgorithm::Ec(NamedCurve::P384));
+    round_trip(PublicKeyAlgorithm::Ec(NamedCurve::P521));
+    round_trip(PublicKeyAlgorithm::Ec(NamedCurve::Secp256k1));
+    round_trip(PublicKeyAlgorithm::Ed25519);
+    round_trip(PublicKeyAlgorithm::Ed448);
+    round_trip(PublicKeyAlgorithm::X25519);
+    round_trip(PublicKeyAlgorithm::X448);
+}
+
+#[test]
+fn rsa_pss_with_params() {
+    // Empty `RSASSA-PSS-params`, i.e. all defaults
+    let params = Any::from_bytes(&[0x30, 0x00]).unwrap();
+    round_trip(PublicKeyAlgorithm::RsaPss(Some(params)));
+}
+
+#[test]
+fn reject_rsa_without_null() {
+    let alg = AlgorithmIdentifier {
+        oid: spki::RSA_ENCRYPTION_OID,
+        parameters: None,
+    };
+
+    let err = PublicKeyAlgorithm::try_from(alg).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Sequence });
+}
+
+#[test]
+fn reject_ec_with_wrong_parameters() {
+    let alg = AlgorithmIdentifier {
+        oid: spki::EC_PUBLIC_KEY_OID,
+        parameters: Some(AlgorithmParameters::Null),
+    };
+
+    let err = PublicKeyAlgorithm::try_from(alg).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::UnexpectedTag {
+            expected: Some(Tag::ObjectIdentifier),
+            actual: Tag::Null
+        }
+    );
+}
+
+#[test]
+fn reject_unknown_curve() {
+    // brainpoolP256r1
+    let curve = "1.3.36.3.3.2.8.1.1.7".parse().unwrap();
+    let alg = AlgorithmIdentifier {
+        oid: spki::EC_PUBLIC_KEY_OID,
+        parameters: Some(AlgorithmParameters::Oid(curve)),
+    };
+
+    let err = PublicKeyAlgorithm::try_from(alg).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnknownOid { oid: curve });
+}
+
+#[test]
+fn reject_ed25519_with_parameters() {
+    let alg = AlgorithmIdentifier {
+        oid: spki::ED25519_OID,
+        parameters: Some(AlgorithmParameters::Null),
+    };
+
+    let err = PublicKeyAlgorithm::try_from(alg).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::UnexpectedTag {
+            expected: None,
+            actual: Tag::Null
+        }
+    );
+}
+
+#[test]
+fn reject_unknown_algorithm() {
+    // id-dsa
+    let oid = "1.2.840.10040.4.1".parse().unwrap();
+    let result: Result<PublicKeyAlgorithm<'_>, _> = AlgorithmIdentifier {
+        oid,
+        parameters: None,
+    }
+    .try_into();
+
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::UnknownOid { oid });
+}